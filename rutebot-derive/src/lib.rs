@@ -0,0 +1,95 @@
+//! Derive macros for [rutebot](https://docs.rs/rutebot). Use them through the reexports in `rutebot`.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, Error, Field, Lit, Type,
+};
+
+/// Implements `rutebot::callback_data::CallbackData` for a type which implements
+/// `Serialize` and `Deserialize`. Values are encoded compactly, so fields are identified by position.
+/// Skipped fields still take their place as `null` which only `Option` can be decoded from,
+/// that's why `#[serde(skip_serializing_if)]` is rejected on other fields.
+#[proc_macro_derive(CallbackData)]
+pub fn derive_callback_data(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match check_fields(&input) {
+        Ok(()) => impl_callback_data(&input).into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn impl_callback_data(input: &DeriveInput) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let mut where_clause = input
+        .generics
+        .where_clause
+        .clone()
+        .unwrap_or_else(|| parse_quote!(where));
+    where_clause.predicates.push(parse_quote! {
+        Self: ::rutebot::callback_data::__private::Serialize
+            + ::rutebot::callback_data::__private::DeserializeOwned
+    });
+    quote! {
+        impl #impl_generics ::rutebot::callback_data::CallbackData for #name #ty_generics #where_clause {
+            fn encode(&self) -> ::std::result::Result<::std::string::String, ::rutebot::error::Error> {
+                ::rutebot::callback_data::__private::encode(self)
+            }
+
+            fn decode(data: &str) -> ::std::result::Result<Self, ::rutebot::error::Error> {
+                ::rutebot::callback_data::__private::decode(data)
+            }
+        }
+    }
+}
+
+fn check_fields(input: &DeriveInput) -> Result<(), Error> {
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data.variants.iter().flat_map(|x| &x.fields).collect(),
+        Data::Union(_) => {
+            return Err(Error::new(
+                input.span(),
+                "CallbackData can't be derived for unions",
+            ))
+        }
+    };
+    fields.into_iter().try_for_each(check_field)
+}
+
+fn check_field(field: &Field) -> Result<(), Error> {
+    for attr in field.attrs.iter().filter(|x| x.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip_serializing") {
+                return Err(meta.error(
+                    "skipped field would shift positions of the next ones, use `skip` instead",
+                ));
+            }
+            if meta.path.is_ident("skip_serializing_if") && !is_option(&field.ty) {
+                return Err(meta.error(
+                    "`skip_serializing_if` is supported only on `Option` fields, \
+                     because skipped fields are decoded from `null`",
+                ));
+            }
+            if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<Lit>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                meta.input.parse::<proc_macro2::Group>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(())
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|x| x.ident == "Option"),
+        _ => false,
+    }
+}
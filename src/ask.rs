@@ -0,0 +1,386 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::sync::{oneshot, Notify};
+
+use crate::{
+    client::Rutebot,
+    error::Error,
+    requests::{AnswerCallbackQuery, ForceReply, InlineKeyboard, ReplyMarkup, SendMessage},
+    responses::{CallbackQuery, Message, Update},
+};
+
+/// Question which is sent to the user with [`Asker`]
+#[derive(Debug, Clone)]
+pub struct Question<'a> {
+    /// Identifier of the chat to ask in
+    pub chat_id: i64,
+
+    /// Identifier of the user whose reply is awaited
+    pub user_id: i64,
+
+    /// Text of the question
+    pub text: &'a str,
+
+    /// Show reply interface to the user as if they manually selected the question and tapped 'Reply'.
+    /// Ignored for questions with an inline keyboard
+    pub force_reply: bool,
+
+    /// How long to wait for the reply. Defaults to 5 minutes
+    pub timeout: Duration,
+}
+
+impl<'a> Question<'a> {
+    pub fn new(chat_id: i64, user_id: i64, text: &'a str) -> Self {
+        Self {
+            chat_id,
+            user_id,
+            text,
+            force_reply: true,
+            timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+enum Awaited {
+    Message,
+    /// Message id is unknown until the question is sent. Meanwhile presses of the user in the chat
+    /// are held back in `early`, because one of them may turn out to be the answer
+    CallbackQuery {
+        message_id: Option<i64>,
+        early: Vec<Update>,
+    },
+}
+
+struct Waiter {
+    id: u64,
+    chat_id: i64,
+    user_id: i64,
+    awaited: Awaited,
+    sender: oneshot::Sender<Update>,
+}
+
+impl Waiter {
+    fn matches(&self, update: &Update) -> bool {
+        match self.awaited {
+            Awaited::Message => update.message.as_ref().is_some_and(|message| {
+                message.chat.id == self.chat_id
+                    && message.from.as_ref().map(|x| x.id) == Some(self.user_id)
+            }),
+            Awaited::CallbackQuery {
+                message_id: Some(message_id),
+                ..
+            } => self
+                .callback_message_id(update)
+                .is_some_and(|x| x == message_id),
+            Awaited::CallbackQuery {
+                message_id: None, ..
+            } => false,
+        }
+    }
+
+    /// Hold back the update if it may turn out to be the answer. Returns it back otherwise
+    fn hold(&mut self, update: Update) -> Option<Update> {
+        let is_candidate = self.callback_message_id(&update).is_some();
+        match &mut self.awaited {
+            Awaited::CallbackQuery {
+                message_id: None,
+                early,
+            } if is_candidate => {
+                early.push(update);
+                None
+            }
+            _ => Some(update),
+        }
+    }
+
+    fn take_early(&mut self) -> Vec<Update> {
+        match &mut self.awaited {
+            Awaited::CallbackQuery { early, .. } => std::mem::take(early),
+            Awaited::Message => Vec::new(),
+        }
+    }
+
+    /// Id of the message with the pressed button if the update is a press of the awaited user in the awaited chat
+    fn callback_message_id(&self, update: &Update) -> Option<i64> {
+        let query = update.callback_query.as_ref()?;
+        let message = query.message.as_ref()?;
+        if query.from.id == self.user_id && message.chat.id == self.chat_id {
+            Some(message.message_id)
+        } else {
+            None
+        }
+    }
+}
+
+/// Allows to write linear code which asks the user something and waits for the answer.
+///
+/// All incoming updates should be passed through [`Asker::feed`], which hands awaited replies
+/// over to the waiting questions. Handlers which ask questions must run concurrently with the
+/// loop that feeds updates, e.g. in spawned tasks.
+///
+/// Until a question with an inline keyboard is sent, the id of its message is unknown. Presses
+/// of the user in that chat fed during this time are held back. Once the question is sent, the one
+/// on it is taken as the answer and the rest are released: they are returned from
+/// [`Asker::released`] and [`Asker::take_released`] and should be handled like the updates
+/// returned from `feed`. [`Dispatcher`](crate::dispatcher::Dispatcher) does this itself.
+///
+/// ## Example
+/// ```no_run
+/// # use futures_util::StreamExt;
+/// # use rutebot::{ask::{Asker, Question}, client::Rutebot, requests::SendMessage};
+/// # async fn run() -> Result<(), rutebot::error::Error> {
+/// let rutebot = Rutebot::new("token");
+/// let asker = Asker::new(rutebot.clone());
+/// let mut updates = Box::pin(rutebot.incoming_updates(None, None));
+/// while let Some(update) = updates.next().await.transpose()? {
+///     if let Some(update) = asker.feed(update) {
+///         let (asker, rutebot) = (asker.clone(), rutebot.clone());
+///         tokio::spawn(async move {
+///             let message = update.message?;
+///             let user = message.from?;
+///             let question = Question::new(message.chat.id, user.id, "What's your name?");
+///             let answer = asker.ask(question).await.ok()?;
+///             let greeting = format!("Hello, {}", answer.text?);
+///             let request = SendMessage::new(message.chat.id, &greeting);
+///             rutebot.prepare_api_request(request).send().await.ok()
+///         });
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Asker {
+    rutebot: Rutebot,
+    waiters: Arc<Mutex<Waiters>>,
+    released: Arc<Notify>,
+}
+
+#[derive(Default)]
+struct Waiters {
+    last_id: u64,
+    list: Vec<Waiter>,
+    released: VecDeque<Update>,
+}
+
+impl Asker {
+    pub fn new(rutebot: Rutebot) -> Self {
+        Self {
+            rutebot,
+            waiters: Arc::new(Mutex::new(Waiters::default())),
+            released: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Pass an incoming update to the waiting questions. Returns the update back if it is not
+    /// a reply to any of them and is not held back until a question with an inline keyboard is sent
+    pub fn feed(&self, update: Update) -> Option<Update> {
+        let mut waiters = self.waiters.lock().unwrap();
+        let (closed, open) = std::mem::take(&mut waiters.list)
+            .into_iter()
+            .partition::<Vec<_>, _>(|x| x.sender.is_closed());
+        waiters.list = open;
+        for mut waiter in closed {
+            self.release(&mut waiters, waiter.take_early());
+        }
+
+        if let Some(idx) = waiters.list.iter().position(|x| x.matches(&update)) {
+            return waiters.list.remove(idx).sender.send(update).err();
+        }
+        let mut update = update;
+        for waiter in waiters.list.iter_mut() {
+            update = waiter.hold(update)?;
+        }
+        Some(update)
+    }
+
+    /// Wait for the next update which was held back by [`Asker::feed`] and turned out not to be
+    /// an answer
+    pub async fn released(&self) -> Update {
+        loop {
+            let notified = self.released.notified();
+            if let Some(update) = self.waiters.lock().unwrap().released.pop_front() {
+                return update;
+            }
+            notified.await;
+        }
+    }
+
+    /// Take the updates which were held back by [`Asker::feed`] and turned out not to be answers
+    pub fn take_released(&self) -> Vec<Update> {
+        self.waiters.lock().unwrap().released.drain(..).collect()
+    }
+
+    /// Wait for the next message from the user in the chat without sending anything.
+    /// The wait starts immediately rather than when the returned future is first polled,
+    /// so it can be created before sending a question of your own
+    pub fn wait_message(
+        &self,
+        chat_id: i64,
+        user_id: i64,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<Message, Error>> {
+        let (_, receiver) = self.wait(chat_id, user_id, Awaited::Message);
+        async move {
+            let update = Self::receive(receiver, timeout).await?;
+            Ok(update.message.expect("Awaited update must contain message"))
+        }
+    }
+
+    /// Wait until the user presses a button of the inline keyboard attached to the message.
+    /// The wait starts immediately rather than when the returned future is first polled.
+    /// Unlike [`Asker::ask_with_keyboard`] the callback query is not answered
+    pub fn wait_callback_query(
+        &self,
+        chat_id: i64,
+        user_id: i64,
+        message_id: i64,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<CallbackQuery, Error>> {
+        let awaited = Awaited::CallbackQuery {
+            message_id: Some(message_id),
+            early: Vec::new(),
+        };
+        let (_, receiver) = self.wait(chat_id, user_id, awaited);
+        async move {
+            let update = Self::receive(receiver, timeout).await?;
+            Ok(update
+                .callback_query
+                .expect("Awaited update must contain callback query"))
+        }
+    }
+
+    /// Send the question and wait for the next message from the user in that chat
+    pub async fn ask(&self, question: Question<'_>) -> Result<Message, Error> {
+        let reply = self.wait_message(question.chat_id, question.user_id, question.timeout);
+        let reply_markup = if question.force_reply {
+            Some(ReplyMarkup::ForceReply(ForceReply {
+                force_reply: true,
+                selective: true,
+            }))
+        } else {
+            None
+        };
+        let request = SendMessage {
+            reply_markup,
+            ..SendMessage::new(question.chat_id, question.text)
+        };
+        self.rutebot.prepare_api_request(request).send().await?;
+        reply.await
+    }
+
+    /// Send the question with an inline keyboard and wait until the user presses one of its buttons.
+    /// The callback query is answered automatically
+    pub async fn ask_with_keyboard<'a>(
+        &self,
+        question: Question<'a>,
+        keyboard: InlineKeyboard<'a>,
+    ) -> Result<CallbackQuery, Error> {
+        let awaited = Awaited::CallbackQuery {
+            message_id: None,
+            early: Vec::new(),
+        };
+        let (id, receiver) = self.wait(question.chat_id, question.user_id, awaited);
+        let request = SendMessage {
+            reply_markup: Some(ReplyMarkup::InlineKeyboard(keyboard)),
+            ..SendMessage::new(question.chat_id, question.text)
+        };
+        let sent = match self.rutebot.prepare_api_request(request).send().await {
+            Ok(sent) => sent,
+            Err(err) => {
+                self.cancel(id);
+                return Err(err);
+            }
+        };
+        self.set_message_id(id, sent.message_id);
+
+        let update = Self::receive(receiver, question.timeout).await?;
+        let query = update
+            .callback_query
+            .expect("Awaited update must contain callback query");
+        let answer = AnswerCallbackQuery::new_silent(&query.id);
+        // The answer only hides the progress indicator on the button,
+        // so failing to send it must not lose the pressed button
+        let _ = self.rutebot.prepare_api_request(answer).send().await;
+        Ok(query)
+    }
+
+    fn wait(
+        &self,
+        chat_id: i64,
+        user_id: i64,
+        awaited: Awaited,
+    ) -> (u64, oneshot::Receiver<Update>) {
+        let (sender, receiver) = oneshot::channel();
+        let mut waiters = self.waiters.lock().unwrap();
+        waiters.last_id += 1;
+        let id = waiters.last_id;
+        waiters.list.push(Waiter {
+            id,
+            chat_id,
+            user_id,
+            awaited,
+            sender,
+        });
+        (id, receiver)
+    }
+
+    /// Set id of the sent question, take the answer from the presses held back before it was known
+    /// and release the rest
+    fn set_message_id(&self, id: u64, message_id: i64) {
+        let mut waiters = self.waiters.lock().unwrap();
+        let idx = match waiters.list.iter().position(|x| x.id == id) {
+            Some(idx) => idx,
+            None => return,
+        };
+        let waiter = &mut waiters.list[idx];
+        let early = waiter.take_early();
+        if let Awaited::CallbackQuery {
+            message_id: awaited_id,
+            ..
+        } = &mut waiter.awaited
+        {
+            *awaited_id = Some(message_id);
+        }
+        let (answers, mut rest) = early
+            .into_iter()
+            .partition::<Vec<_>, _>(|x| waiter.matches(x));
+        if let Some(answer) = answers.into_iter().next() {
+            if let Err(answer) = waiters.list.remove(idx).sender.send(answer) {
+                rest.push(answer);
+            }
+        }
+        self.release(&mut waiters, rest);
+    }
+
+    /// Remove the waiter of a question which failed to be sent and release its held back presses
+    fn cancel(&self, id: u64) {
+        let mut waiters = self.waiters.lock().unwrap();
+        if let Some(idx) = waiters.list.iter().position(|x| x.id == id) {
+            let early = waiters.list.remove(idx).take_early();
+            self.release(&mut waiters, early);
+        }
+    }
+
+    fn release(&self, waiters: &mut Waiters, updates: Vec<Update>) {
+        if !updates.is_empty() {
+            waiters.released.extend(updates);
+            self.released.notify_one();
+        }
+    }
+
+    async fn receive(
+        receiver: oneshot::Receiver<Update>,
+        timeout: Duration,
+    ) -> Result<Update, Error> {
+        match tokio::time::timeout(timeout, receiver).await {
+            Ok(Ok(update)) => Ok(update),
+            _ => Err(Error::Timeout),
+        }
+    }
+}
@@ -0,0 +1,518 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::future::{ready, BoxFuture, FutureExt};
+use serde::{
+    de::DeserializeOwned,
+    ser::{self, Serialize},
+};
+use serde_json::{Map, Value};
+
+use crate::error::Error;
+
+/// Maximum size of `callback_data` in bytes allowed by telegram
+pub const MAX_CALLBACK_DATA_LEN: usize = 64;
+
+const STORED_PREFIX: char = '#';
+
+/// Data which can be attached to an inline keyboard button and received back in
+/// [`crate::responses::CallbackQuery::data`].
+///
+/// Derive it together with `Serialize` and `Deserialize`. Structs are encoded compactly as arrays
+/// of their field values without field names, so reordering fields of a struct invalidates
+/// data that was already sent. Internally tagged enums and `#[serde(flatten)]` are not supported,
+/// and `#[serde(skip_serializing_if)]` may be used only on `Option` fields.
+///
+/// ## Example
+/// ```
+/// # use rutebot::callback_data::CallbackData;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize, CallbackData, Debug, PartialEq)]
+/// struct Vote {
+///     poll_id: i64,
+///     option: u8,
+/// }
+///
+/// let data = Vote { poll_id: 42, option: 1 }.encode().unwrap();
+/// assert_eq!(data, "[42,1]");
+/// assert_eq!(Vote::decode(&data).unwrap(), Vote { poll_id: 42, option: 1 });
+/// ```
+pub trait CallbackData: Sized {
+    /// Encode value into a string. Returns an error if the result does not fit into
+    /// `MAX_CALLBACK_DATA_LEN` bytes, use [`CallbackDataCodec`] if your data can be larger.
+    fn encode(&self) -> Result<String, Error>;
+
+    /// Decode value from the string received from telegram. Be aware that a bad client can send
+    /// arbitrary data, so decoding errors should be handled gracefully.
+    fn decode(data: &str) -> Result<Self, Error>;
+}
+
+pub use rutebot_derive::CallbackData;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde::{de::DeserializeOwned, Serialize};
+
+    use super::{decode_compact, encode_compact, MAX_CALLBACK_DATA_LEN};
+    use crate::error::Error;
+
+    pub fn encode<T: Serialize + DeserializeOwned>(value: &T) -> Result<String, Error> {
+        let encoded = encode_compact(value)?;
+        if encoded.len() > MAX_CALLBACK_DATA_LEN {
+            Err(Error::CallbackData(format!(
+                "encoded data is {} bytes long, but only {} bytes are allowed",
+                encoded.len(),
+                MAX_CALLBACK_DATA_LEN
+            )))
+        } else {
+            Ok(encoded)
+        }
+    }
+
+    pub fn decode<T: DeserializeOwned>(data: &str) -> Result<T, Error> {
+        decode_compact(data)
+    }
+}
+
+/// Storage for callback data which does not fit into `MAX_CALLBACK_DATA_LEN` bytes.
+/// Payloads are saved under short generated keys and only the key is sent to telegram.
+pub trait CallbackDataStore: Send + Sync {
+    /// Save payload under the key
+    fn save<'a>(&'a self, key: &'a str, payload: String) -> BoxFuture<'a, Result<(), Error>>;
+
+    /// Load payload saved under the key. Returns `None` if there is no such key
+    fn load<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<String>, Error>>;
+}
+
+/// In-memory `CallbackDataStore` which keeps at most `capacity` payloads.
+/// When capacity is exceeded the oldest payloads are evicted.
+pub struct InMemoryCallbackDataStore {
+    capacity: usize,
+    inner: Mutex<(HashMap<String, String>, VecDeque<String>)>,
+}
+
+impl InMemoryCallbackDataStore {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new((HashMap::new(), VecDeque::new())),
+        }
+    }
+}
+
+impl CallbackDataStore for InMemoryCallbackDataStore {
+    fn save<'a>(&'a self, key: &'a str, payload: String) -> BoxFuture<'a, Result<(), Error>> {
+        let mut guard = self.inner.lock().unwrap();
+        let (payloads, order) = &mut *guard;
+        if payloads.insert(key.to_string(), payload).is_none() {
+            order.push_back(key.to_string());
+        }
+        while order.len() > self.capacity {
+            if let Some(oldest) = order.pop_front() {
+                payloads.remove(&oldest);
+            }
+        }
+        ready(Ok(())).boxed()
+    }
+
+    fn load<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<Option<String>, Error>> {
+        let payload = self.inner.lock().unwrap().0.get(key).cloned();
+        ready(Ok(payload)).boxed()
+    }
+}
+
+/// Encodes and decodes `CallbackData`. Values that fit into `MAX_CALLBACK_DATA_LEN` bytes are
+/// sent as is, larger ones are saved to the `CallbackDataStore` and only a short key is sent.
+pub struct CallbackDataCodec<S: CallbackDataStore> {
+    store: S,
+    counter: AtomicU64,
+}
+
+impl<S: CallbackDataStore> CallbackDataCodec<S> {
+    pub fn new(store: S) -> Self {
+        Self {
+            store,
+            counter: AtomicU64::new(0),
+        }
+    }
+
+    /// Encode value, falling back to the store if it is too large
+    pub async fn encode<T: Serialize + DeserializeOwned>(
+        &self,
+        value: &T,
+    ) -> Result<String, Error> {
+        let encoded = encode_compact(value)?;
+        if encoded.len() <= MAX_CALLBACK_DATA_LEN {
+            return Ok(encoded);
+        }
+        let key = self.next_key();
+        self.store.save(&key, encoded).await?;
+        Ok(format!("{}{}", STORED_PREFIX, key))
+    }
+
+    /// Decode value either directly or by loading it from the store
+    pub async fn decode<T: DeserializeOwned>(&self, data: &str) -> Result<T, Error> {
+        match data.strip_prefix(STORED_PREFIX) {
+            Some(key) => {
+                let payload = self.store.load(key).await?.ok_or_else(|| {
+                    Error::CallbackData(format!("there is no stored data for key {:?}", key))
+                })?;
+                decode_compact(&payload)
+            }
+            None => decode_compact(data),
+        }
+    }
+
+    fn next_key(&self) -> String {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_nanos() as u64);
+        let counter = self.counter.fetch_add(1, Ordering::Relaxed);
+        format!("{:x}{:x}", nanos, counter)
+    }
+}
+
+fn encode_compact<T: Serialize + DeserializeOwned>(value: &T) -> Result<String, Error> {
+    let encoded = value
+        .serialize(CompactSerializer)
+        .map(|x| x.to_string())
+        .map_err(Error::Serde)?;
+    // Skipped fields are encoded as `null` to keep positions of the rest, so non-`Option` skipped
+    // fields can't be decoded back. The derive rejects them at compile time, the codec accepts
+    // any serde type, so debug builds check that the value is decodable before it is sent
+    #[cfg(debug_assertions)]
+    if let Err(x) = serde_json::from_str::<T>(&encoded) {
+        return Err(Error::CallbackData(format!(
+            "encoded data {:?} can't be decoded back, note that `skip_serializing_if` \
+             is supported only on `Option` fields: {}",
+            encoded, x
+        )));
+    }
+    Ok(encoded)
+}
+
+fn decode_compact<T: DeserializeOwned>(data: &str) -> Result<T, Error> {
+    serde_json::from_str(data)
+        .map_err(|x| Error::CallbackData(format!("invalid callback data {:?}: {}", data, x)))
+}
+
+/// Serializes values like `serde_json::to_value` does, except for structs
+/// which are serialized as arrays of field values.
+struct CompactSerializer;
+
+struct SeqSerializer(Vec<Value>);
+
+struct VariantSerializer {
+    variant: &'static str,
+    fields: Vec<Value>,
+}
+
+struct MapSerializer {
+    map: Map<String, Value>,
+    next_key: Option<String>,
+}
+
+fn variant_value(variant: &'static str, value: Value) -> Value {
+    let mut map = Map::new();
+    map.insert(variant.to_string(), value);
+    Value::Object(map)
+}
+
+impl ser::Serializer for CompactSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = VariantSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = SeqSerializer;
+    type SerializeStructVariant = VariantSerializer;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Self::Error> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Self::Error> {
+        Ok(Value::from(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Self::Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Self::Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Self::Error> {
+        Ok(Value::Array(v.iter().map(|&x| Value::from(x)).collect()))
+    }
+
+    fn serialize_none(self) -> Result<Value, Self::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Self::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Self::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Self::Error> {
+        Ok(Value::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Self::Error> {
+        Ok(variant_value(variant, value.serialize(self)?))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, Self::Error> {
+        Ok(SeqSerializer(Vec::with_capacity(len.unwrap_or(0))))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer, Self::Error> {
+        Ok(VariantSerializer {
+            variant,
+            fields: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer, Self::Error> {
+        Ok(MapSerializer {
+            map: Map::new(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer, Self::Error> {
+        self.serialize_tuple_variant(name, variant_index, variant, len)
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.push(value.serialize(CompactSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(Value::Array(self.0))
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeStruct for SeqSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn skip_field(&mut self, _key: &'static str) -> Result<(), Self::Error> {
+        // Fields are identified by position, so skipped ones must still take their place
+        self.0.push(Value::Null);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.fields.push(value.serialize(CompactSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(variant_value(self.variant, Value::Array(self.fields)))
+    }
+}
+
+impl ser::SerializeStructVariant for VariantSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        ser::SerializeTupleVariant::serialize_field(self, value)
+    }
+
+    fn skip_field(&mut self, _key: &'static str) -> Result<(), Self::Error> {
+        self.fields.push(Value::Null);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        ser::SerializeTupleVariant::end(self)
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        let key = match key.serialize(CompactSerializer)? {
+            Value::String(x) => x,
+            Value::Number(x) => x.to_string(),
+            Value::Bool(x) => x.to_string(),
+            _ => return Err(ser::Error::custom("map key must be a string or a number")),
+        };
+        self.next_key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self
+            .next_key
+            .take()
+            .ok_or_else(|| ser::Error::custom("serialize_value called before serialize_key"))?;
+        self.map.insert(key, value.serialize(CompactSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(Value::Object(self.map))
+    }
+}
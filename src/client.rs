@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     future::{pending, ready, Pending, Ready},
     sync::Arc,
 };
@@ -60,7 +61,7 @@ impl<TResponse: DeserializeOwned> ApiRequest<TResponse> {
     /// # let bot = rutebot::client::Rutebot::new("token");
     /// # let allowed_updates = [UpdateKind::Message];
     /// # let get_updates = GetUpdates {
-    /// #    allowed_updates: Some(&allowed_updates),
+    /// #    allowed_updates: Some(allowed_updates[..].into()),
     /// #    ..GetUpdates::new()
     /// # };
     /// # let request = bot.prepare_api_request(get_updates);
@@ -132,7 +133,7 @@ impl Rutebot {
     /// let bot = rutebot::client::Rutebot::new("token");
     /// let allowed_updates = [UpdateKind::Message];
     /// let get_updates = GetUpdates {
-    ///     allowed_updates: Some(&allowed_updates),
+    ///     allowed_updates: Some(allowed_updates[..].into()),
     ///     ..GetUpdates::new()
     /// };
     /// let response = bot.prepare_api_request(get_updates);
@@ -154,6 +155,21 @@ impl Rutebot {
         }
     }
 
+    /// Prepare request with already serialized parameters for the method which is known only at runtime
+    pub(crate) fn prepare_json_request(
+        &self,
+        method: &str,
+        payload: &serde_json::Value,
+    ) -> ApiRequest<serde_json::Value> {
+        let uri = format!("{}{}/{}", BASE_API_URI, self.inner.token, method);
+        let http_request = requests::add_json_body(Request::post(uri), payload);
+        ApiRequest {
+            inner: self.inner.clone(),
+            http_request,
+            _data: PhantomData,
+        }
+    }
+
     /// Download file from telegram. Before downloading you need to prepare file and obtain `file_path`
     /// using [`crate::requests::GetFile`], see example below.
     /// ## Example
@@ -218,7 +234,7 @@ impl Rutebot {
                         offset,
                         limit: None,
                         timeout: Some(10),
-                        allowed_updates: updates_filter.as_deref(),
+                        allowed_updates: updates_filter.as_deref().map(Cow::Borrowed),
                     };
                     timeout(
                         Duration::from_secs(15),
@@ -261,27 +277,34 @@ impl Policy<Result<Vec<Update>, Error>, Elapsed> for UpdatesRetry {
         self,
         result: Option<Result<&Result<Vec<Update>, Error>, &Elapsed>>,
     ) -> Option<Self::RetryFuture> {
-        match result {
-            Some(Ok(Err(Error::Api {
-                error_code: 429,
-                parameters:
-                    Some(ResponseParameters {
-                        retry_after: Some(retry_after),
-                        ..
-                    }),
-                ..
-            }))) => {
-                let retry_after = *retry_after;
-                let wait_fut = async move {
-                    tokio::time::sleep(Duration::from_secs(retry_after as u64)).await;
-                    Self
-                }
-                .boxed();
-                Some(Either::Left(wait_fut))
+        if let Some(retry_after) = result.and_then(|x| flood_wait(x.ok()?.as_ref().err()?)) {
+            let wait_fut = async move {
+                tokio::time::sleep(retry_after).await;
+                Self
             }
+            .boxed();
+            return Some(Either::Left(wait_fut));
+        }
+        match result {
             Some(Ok(Ok(v))) if v.is_empty() => Some(Either::Right(ready(Self))),
             Some(Err(_)) => Some(Either::Right(ready(Self))),
             _ => None,
         }
     }
 }
+
+/// Returns how long to wait before repeating the request if telegram rejected it because of flood control
+pub(crate) fn flood_wait(error: &Error) -> Option<Duration> {
+    match error {
+        Error::Api {
+            error_code: 429,
+            parameters:
+                Some(ResponseParameters {
+                    retry_after: Some(retry_after),
+                    ..
+                }),
+            ..
+        } => Some(Duration::from_secs(*retry_after as u64)),
+        _ => None,
+    }
+}
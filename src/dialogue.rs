@@ -0,0 +1,365 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    marker::PhantomData,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::future::{ready, BoxFuture, FutureExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{error::Error, responses::Update};
+
+/// Identifies a conversation: a chat and, for chats with several participants, a user in that chat
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DialogueKey {
+    /// Identifier of the chat the conversation happens in
+    pub chat_id: i64,
+
+    /// Identifier of the user the bot talks to, empty for channel posts
+    pub user_id: Option<i64>,
+}
+
+impl DialogueKey {
+    pub fn new(chat_id: i64, user_id: Option<i64>) -> Self {
+        Self { chat_id, user_id }
+    }
+
+    /// Extract key from the update. Returns `None` for updates which do not belong to any chat
+    pub fn from_update(update: &Update) -> Option<Self> {
+        let message = update
+            .message
+            .as_ref()
+            .or(update.edited_message.as_ref())
+            .or(update.channel_post.as_ref())
+            .or(update.edited_channel_post.as_ref());
+        if let Some(message) = message {
+            return Some(Self::new(
+                message.chat.id,
+                message.from.as_ref().map(|x| x.id),
+            ));
+        }
+        let query = update.callback_query.as_ref()?;
+        let message = query.message.as_ref()?;
+        Some(Self::new(message.chat.id, Some(query.from.id)))
+    }
+
+    fn to_storage_key(self) -> String {
+        match self.user_id {
+            Some(user_id) => format!("{}:{}", self.chat_id, user_id),
+            None => self.chat_id.to_string(),
+        }
+    }
+}
+
+/// State of a conversation together with the time of the last transition
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StoredState<S> {
+    /// User-defined state
+    pub state: S,
+
+    /// Unix time in milliseconds when the state was set
+    pub updated_at: u64,
+}
+
+/// Storage for conversation states
+pub trait DialogueStorage<S>: Send + Sync {
+    /// Get state of the conversation. Returns `None` if there is no conversation with such key
+    fn get(&self, key: DialogueKey) -> BoxFuture<'_, Result<Option<StoredState<S>>, Error>>;
+
+    /// Set state of the conversation
+    fn set(&self, key: DialogueKey, state: StoredState<S>) -> BoxFuture<'_, Result<(), Error>>;
+
+    /// Remove the conversation
+    fn remove(&self, key: DialogueKey) -> BoxFuture<'_, Result<(), Error>>;
+}
+
+/// `DialogueStorage` which keeps states in memory. All states are lost on restart
+pub struct InMemoryStorage<S> {
+    states: Mutex<HashMap<DialogueKey, StoredState<S>>>,
+}
+
+impl<S> InMemoryStorage<S> {
+    pub fn new() -> Self {
+        Self {
+            states: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<S> Default for InMemoryStorage<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Clone + Send> DialogueStorage<S> for InMemoryStorage<S> {
+    fn get(&self, key: DialogueKey) -> BoxFuture<'_, Result<Option<StoredState<S>>, Error>> {
+        let state = self.states.lock().unwrap().get(&key).cloned();
+        ready(Ok(state)).boxed()
+    }
+
+    fn set(&self, key: DialogueKey, state: StoredState<S>) -> BoxFuture<'_, Result<(), Error>> {
+        self.states.lock().unwrap().insert(key, state);
+        ready(Ok(())).boxed()
+    }
+
+    fn remove(&self, key: DialogueKey) -> BoxFuture<'_, Result<(), Error>> {
+        self.states.lock().unwrap().remove(&key);
+        ready(Ok(())).boxed()
+    }
+}
+
+/// `DialogueStorage` which keeps states in memory and writes all of them to a JSON file on every change
+pub struct JsonFileStorage<S> {
+    path: PathBuf,
+    states: tokio::sync::Mutex<HashMap<String, StoredState<S>>>,
+}
+
+impl<S: DeserializeOwned> JsonFileStorage<S> {
+    /// Open storage, loading states from the file if it exists
+    pub async fn open<P: Into<PathBuf>>(path: P) -> Result<Self, Error> {
+        let path = path.into();
+        let states = match tokio::fs::read(&path).await {
+            Ok(content) => serde_json::from_slice(&content).map_err(Error::Serde)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(err) => return Err(Error::IO(err)),
+        };
+        Ok(Self {
+            path,
+            states: tokio::sync::Mutex::new(states),
+        })
+    }
+}
+
+impl<S: Serialize> JsonFileStorage<S> {
+    async fn save(&self, states: &HashMap<String, StoredState<S>>) -> Result<(), Error> {
+        let content = serde_json::to_vec(states).map_err(Error::Serde)?;
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        tokio::fs::write(&tmp_path, content)
+            .await
+            .map_err(Error::IO)?;
+        tokio::fs::rename(&tmp_path, &self.path)
+            .await
+            .map_err(Error::IO)
+    }
+}
+
+impl<S: Serialize + Clone + Send + Sync> DialogueStorage<S> for JsonFileStorage<S> {
+    fn get(&self, key: DialogueKey) -> BoxFuture<'_, Result<Option<StoredState<S>>, Error>> {
+        async move {
+            let states = self.states.lock().await;
+            Ok(states.get(&key.to_storage_key()).cloned())
+        }
+        .boxed()
+    }
+
+    fn set(&self, key: DialogueKey, state: StoredState<S>) -> BoxFuture<'_, Result<(), Error>> {
+        async move {
+            let mut states = self.states.lock().await;
+            states.insert(key.to_storage_key(), state);
+            self.save(&states).await
+        }
+        .boxed()
+    }
+
+    fn remove(&self, key: DialogueKey) -> BoxFuture<'_, Result<(), Error>> {
+        async move {
+            let mut states = self.states.lock().await;
+            if states.remove(&key.to_storage_key()).is_some() {
+                self.save(&states).await
+            } else {
+                Ok(())
+            }
+        }
+        .boxed()
+    }
+}
+
+/// What to do with the conversation after handling an update
+#[derive(Debug, Clone)]
+pub enum Transition<S> {
+    /// Move the conversation to the new state
+    Next(S),
+
+    /// Keep the current state. The timeout is not prolonged
+    Stay,
+
+    /// Finish the conversation. The next update will start from the default state
+    Exit,
+}
+
+/// Keeps track of multi-step conversations. Each conversation is identified by `DialogueKey`
+/// and has a user-defined state which starts from `S::default()`.
+/// Conversations which were not moved to a new state within the timeout are reset.
+/// Updates of the same conversation are handled one at a time, so concurrent updates can't lose transitions.
+///
+/// ## Example
+/// ```
+/// # use rutebot::dialogue::{Dialogue, InMemoryStorage, Transition};
+/// # use rutebot::responses::Update;
+/// # use std::time::Duration;
+/// #[derive(Clone, Default)]
+/// enum Registration {
+///     #[default]
+///     Start,
+///     AskedName,
+///     AskedPhoto { name: String },
+/// }
+///
+/// # async fn handle(update: Update) -> Result<(), rutebot::error::Error> {
+/// let dialogue = Dialogue::new(InMemoryStorage::new()).with_timeout(Duration::from_secs(600));
+/// dialogue
+///     .handle(update, |state, update| async move {
+///         let text = update.message.and_then(|x| x.text);
+///         Ok(match (state, text) {
+///             (Registration::Start, _) => Transition::Next(Registration::AskedName),
+///             (Registration::AskedName, Some(name)) => {
+///                 Transition::Next(Registration::AskedPhoto { name })
+///             }
+///             (Registration::AskedPhoto { .. }, _) => Transition::Exit,
+///             _ => Transition::Stay,
+///         })
+///     })
+///     .await
+/// # }
+/// ```
+pub struct Dialogue<S, St> {
+    storage: St,
+    timeout: Option<Duration>,
+    edited_messages: bool,
+    locks: Mutex<HashMap<DialogueKey, Arc<tokio::sync::Mutex<()>>>>,
+    _state: PhantomData<fn() -> S>,
+}
+
+impl<S, St> Dialogue<S, St>
+where
+    S: Default,
+    St: DialogueStorage<S>,
+{
+    pub fn new(storage: St) -> Self {
+        Self {
+            storage,
+            timeout: None,
+            edited_messages: false,
+            locks: Mutex::new(HashMap::new()),
+            _state: PhantomData,
+        }
+    }
+
+    /// Reset conversations which were not moved to a new state for longer than `timeout`
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Pass edited messages and channel posts to the handler. By default they are ignored,
+    /// because an edit is usually not an answer to the last question of the bot
+    pub fn with_edited_messages(self) -> Self {
+        Self {
+            edited_messages: true,
+            ..self
+        }
+    }
+
+    /// Get current state of the conversation
+    pub async fn state(&self, key: DialogueKey) -> Result<S, Error> {
+        match self.storage.get(key).await? {
+            Some(stored) if self.is_stale(&stored) => {
+                self.storage.remove(key).await?;
+                Ok(S::default())
+            }
+            Some(stored) => Ok(stored.state),
+            None => Ok(S::default()),
+        }
+    }
+
+    /// Move the conversation to the new state
+    pub async fn update(&self, key: DialogueKey, state: S) -> Result<(), Error> {
+        let stored = StoredState {
+            state,
+            updated_at: now_millis(),
+        };
+        self.storage.set(key, stored).await
+    }
+
+    /// Finish the conversation
+    pub async fn exit(&self, key: DialogueKey) -> Result<(), Error> {
+        self.storage.remove(key).await
+    }
+
+    /// Call `handler` with the current state of the conversation the update belongs to
+    /// and apply the returned transition. Updates which do not belong to any chat are ignored,
+    /// as well as edited messages unless `with_edited_messages` is set.
+    /// Updates of the same conversation wait until the previous one is handled.
+    pub async fn handle<F, Fut, E>(&self, update: Update, handler: F) -> Result<(), E>
+    where
+        F: FnOnce(S, Update) -> Fut,
+        Fut: Future<Output = Result<Transition<S>, E>>,
+        E: From<Error>,
+    {
+        let is_edit = update.edited_message.is_some() || update.edited_channel_post.is_some();
+        if is_edit && !self.edited_messages {
+            return Ok(());
+        }
+        let key = match DialogueKey::from_update(&update) {
+            Some(key) => key,
+            None => return Ok(()),
+        };
+        let _lock = self.lock(key).await;
+        let state = self.state(key).await?;
+        match handler(state, update).await? {
+            Transition::Next(state) => self.update(key, state).await?,
+            Transition::Stay => (),
+            Transition::Exit => self.exit(key).await?,
+        }
+        Ok(())
+    }
+
+    async fn lock(&self, key: DialogueKey) -> KeyLock<'_> {
+        let lock = self.locks.lock().unwrap().entry(key).or_default().clone();
+        let guard = lock.clone().lock_owned().await;
+        KeyLock {
+            locks: &self.locks,
+            key,
+            lock,
+            guard: Some(guard),
+        }
+    }
+
+    fn is_stale(&self, stored: &StoredState<S>) -> bool {
+        self.timeout.is_some_and(|timeout| {
+            now_millis().saturating_sub(stored.updated_at) > timeout.as_millis() as u64
+        })
+    }
+}
+
+/// Holds the lock of a conversation and removes it from the map when nobody else waits for it
+struct KeyLock<'a> {
+    locks: &'a Mutex<HashMap<DialogueKey, Arc<tokio::sync::Mutex<()>>>>,
+    key: DialogueKey,
+    lock: Arc<tokio::sync::Mutex<()>>,
+    guard: Option<tokio::sync::OwnedMutexGuard<()>>,
+}
+
+impl Drop for KeyLock<'_> {
+    fn drop(&mut self) {
+        self.guard.take();
+        let mut locks = self.locks.lock().unwrap();
+        // One reference is in the map and one is ours
+        if Arc::strong_count(&self.lock) == 2 {
+            locks.remove(&self.key);
+        }
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_millis() as u64)
+}
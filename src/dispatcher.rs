@@ -0,0 +1,375 @@
+use std::{
+    any::{Any, TypeId},
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use futures_util::{
+    future::{ready, select, BoxFuture, Either, FutureExt},
+    stream::{Stream, StreamExt},
+};
+
+use crate::{
+    ask::{Asker, Question},
+    client::Rutebot,
+    dialogue::DialogueKey,
+    error::Error,
+    responses::{Message, Update},
+};
+
+/// Error returned by handlers and middlewares
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Result of handling an update
+pub type HandlerResult = Result<(), HandlerError>;
+
+type Handler = dyn Fn(Context) -> BoxFuture<'static, HandlerResult> + Send + Sync;
+
+type ErrorHandler = dyn Fn(HandlerError, Update) -> BoxFuture<'static, ()> + Send + Sync;
+
+type StreamErrorHandler = dyn Fn(Error) -> BoxFuture<'static, ()> + Send + Sync;
+
+type DataMap = HashMap<TypeId, Arc<dyn Any + Send + Sync>>;
+
+const MIN_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Everything a handler needs to process an update
+#[derive(Clone)]
+pub struct Context {
+    /// Update being handled
+    pub update: Update,
+
+    /// Client for sending requests
+    pub rutebot: Rutebot,
+
+    asker: Asker,
+    shared: Arc<DataMap>,
+    local: DataMap,
+}
+
+impl Context {
+    /// Get value registered with [`Dispatcher::data`] or inserted by a middleware for this update
+    pub fn data<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        let id = TypeId::of::<T>();
+        self.local
+            .get(&id)
+            .or_else(|| self.shared.get(&id))
+            .and_then(|x| x.downcast_ref())
+    }
+
+    /// Insert value which will be available to the next middlewares and the handler of this update
+    pub fn insert<T: Send + Sync + 'static>(&mut self, value: T) {
+        self.local.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    /// Identifier of the user who sent the update
+    pub fn sender_id(&self) -> Option<i64> {
+        let update = &self.update;
+        let message = update.message.as_ref().or(update.edited_message.as_ref());
+        match message {
+            Some(message) => message.from.as_ref().map(|x| x.id),
+            None => (update.callback_query.as_ref().map(|x| &x.from))
+                .or(update.inline_query.as_ref().map(|x| &x.from))
+                .or(update.chosen_inline_result.as_ref().map(|x| &x.from))
+                .or(update.shipping_query.as_ref().map(|x| &x.from))
+                .or(update.pre_checkout_query.as_ref().map(|x| &x.from))
+                .or(update.chat_join_request.as_ref().map(|x| &x.from))
+                .map(|x| x.id),
+        }
+    }
+
+    /// Asker shared by all handlers of the dispatcher
+    pub fn asker(&self) -> &Asker {
+        &self.asker
+    }
+
+    /// Ask the sender of the update a question in the same chat and wait for the reply
+    pub async fn ask(&self, text: &str) -> Result<Message, Error> {
+        match DialogueKey::from_update(&self.update) {
+            Some(DialogueKey {
+                chat_id,
+                user_id: Some(user_id),
+            }) => self.asker.ask(Question::new(chat_id, user_id, text)).await,
+            _ => Err(Error::RequestBuilt(
+                "Update has no chat or sender to ask".to_string(),
+            )),
+        }
+    }
+}
+
+/// Hooks which run before and after the handler
+pub trait Middleware: Send + Sync {
+    /// Called before the handler. Return `false` to skip the update:
+    /// neither the handler nor the next middlewares will be called
+    fn before<'a>(&'a self, _ctx: &'a mut Context) -> BoxFuture<'a, Result<bool, HandlerError>> {
+        ready(Ok(true)).boxed()
+    }
+
+    /// Called after the handler with its result. Called in reverse order of registration
+    /// for every middleware whose `before` was called, so it also runs when one of the next
+    /// middlewares skips the update, with `Ok(())`, or fails, with its error
+    fn after<'a>(&'a self, _ctx: &'a Context, _result: &'a HandlerResult) -> BoxFuture<'a, ()> {
+        ready(()).boxed()
+    }
+}
+
+/// Middleware which passes only updates sent by the listed users, e.g. admins
+#[derive(Debug, Clone)]
+pub struct AllowList {
+    user_ids: HashSet<i64>,
+}
+
+impl AllowList {
+    pub fn new(user_ids: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            user_ids: user_ids.into_iter().collect(),
+        }
+    }
+}
+
+impl Middleware for AllowList {
+    fn before<'a>(&'a self, ctx: &'a mut Context) -> BoxFuture<'a, Result<bool, HandlerError>> {
+        let allowed = ctx.sender_id().is_some_and(|x| self.user_ids.contains(&x));
+        ready(Ok(allowed)).boxed()
+    }
+}
+
+/// Middleware which skips updates sent by banned users. Clones share the same list,
+/// so users can be banned and unbanned while the dispatcher runs
+#[derive(Debug, Clone, Default)]
+pub struct BanList {
+    user_ids: Arc<RwLock<HashSet<i64>>>,
+}
+
+impl BanList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ban(&self, user_id: i64) {
+        self.user_ids.write().unwrap().insert(user_id);
+    }
+
+    pub fn unban(&self, user_id: i64) {
+        self.user_ids.write().unwrap().remove(&user_id);
+    }
+
+    pub fn is_banned(&self, user_id: i64) -> bool {
+        self.user_ids.read().unwrap().contains(&user_id)
+    }
+}
+
+impl Middleware for BanList {
+    fn before<'a>(&'a self, ctx: &'a mut Context) -> BoxFuture<'a, Result<bool, HandlerError>> {
+        let banned = ctx.sender_id().is_some_and(|x| self.is_banned(x));
+        ready(Ok(!banned)).boxed()
+    }
+}
+
+struct Inner {
+    rutebot: Rutebot,
+    asker: Asker,
+    data: Arc<DataMap>,
+    middlewares: Vec<Box<dyn Middleware>>,
+    handler: Box<Handler>,
+    error_handler: Box<ErrorHandler>,
+    stream_error_handler: Box<StreamErrorHandler>,
+}
+
+/// Routes incoming updates through middlewares to the handler.
+/// Errors of middlewares and the handler are passed to the error handler together with the update
+/// that caused them, errors of the update stream are passed to the stream error handler.
+/// Both print errors to stderr by default.
+///
+/// ## Example
+/// ```no_run
+/// # use rutebot::{client::Rutebot, dispatcher::{BanList, Context, Dispatcher}, requests::SendMessage};
+/// struct Config {
+///     greeting: String,
+/// }
+///
+/// # async fn run() {
+/// let rutebot = Rutebot::new("token");
+/// Dispatcher::new(rutebot.clone(), |ctx: Context| async move {
+///     let greeting = &ctx.data::<Config>().unwrap().greeting;
+///     if let Some(message) = &ctx.update.message {
+///         let request = SendMessage::new(message.chat.id, greeting);
+///         ctx.rutebot.prepare_api_request(request).send().await?;
+///     }
+///     Ok(())
+/// })
+/// .data(Config { greeting: "Hello".to_string() })
+/// .middleware(BanList::new())
+/// .error_handler(|err, update| async move {
+///     eprintln!("Error {} while handling update {}", err, update.update_id)
+/// })
+/// .run(rutebot.incoming_updates(None, None))
+/// .await;
+/// # }
+/// ```
+pub struct Dispatcher {
+    inner: Arc<Inner>,
+}
+
+impl Dispatcher {
+    pub fn new<F, Fut>(rutebot: Rutebot, handler: F) -> Self
+    where
+        F: Fn(Context) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        let inner = Inner {
+            asker: Asker::new(rutebot.clone()),
+            rutebot,
+            data: Arc::new(HashMap::new()),
+            middlewares: Vec::new(),
+            handler: Box::new(move |ctx| handler(ctx).boxed()),
+            error_handler: Box::new(|err, update| {
+                eprintln!(
+                    "Error has occurred while handling update {}: {}",
+                    update.update_id, err
+                );
+                ready(()).boxed()
+            }),
+            stream_error_handler: Box::new(|err| {
+                eprintln!("Error has occurred while receiving updates: {}", err);
+                ready(()).boxed()
+            }),
+        };
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Register shared state available to handlers and middlewares via [`Context::data`]
+    pub fn data<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        Arc::get_mut(&mut self.inner_mut().data)
+            .expect("Data must not be shared before the dispatcher is started")
+            .insert(TypeId::of::<T>(), Arc::new(value));
+        self
+    }
+
+    /// Add middleware. Middlewares are called in order of registration
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.inner_mut().middlewares.push(Box::new(middleware));
+        self
+    }
+
+    /// Set handler for errors of middlewares and the handler. By default errors are printed to stderr
+    pub fn error_handler<F, Fut>(mut self, error_handler: F) -> Self
+    where
+        F: Fn(HandlerError, Update) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.inner_mut().error_handler =
+            Box::new(move |err, update| error_handler(err, update).boxed());
+        self
+    }
+
+    /// Set handler for errors of the update stream, e.g. failed requests for updates.
+    /// By default errors are printed to stderr
+    pub fn stream_error_handler<F, Fut>(mut self, stream_error_handler: F) -> Self
+    where
+        F: Fn(Error) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.inner_mut().stream_error_handler =
+            Box::new(move |err| stream_error_handler(err).boxed());
+        self
+    }
+
+    /// Handle updates from the stream, each one in a separate task.
+    /// Errors of the stream are passed to the stream error handler and do not stop it.
+    /// After each of consecutive errors polling waits twice as long, up to 30 seconds,
+    /// so an outage does not turn into a busy loop. Returns when the stream ends
+    pub async fn run<S>(self, updates: S)
+    where
+        S: Stream<Item = Result<Update, Error>>,
+    {
+        let mut updates = Box::pin(updates);
+        let mut backoff = None;
+        loop {
+            let released = Box::pin(self.inner.asker.released());
+            match select(updates.next(), released).await {
+                Either::Left((Some(Ok(update)), _)) => {
+                    backoff = None;
+                    let dispatcher = self.clone_shared();
+                    tokio::spawn(async move { dispatcher.handle(update).await });
+                }
+                Either::Left((Some(Err(err)), _)) => {
+                    (self.inner.stream_error_handler)(err).await;
+                    let delay = backoff.map_or(MIN_BACKOFF, |x: Duration| (x * 2).min(MAX_BACKOFF));
+                    backoff = Some(delay);
+                    tokio::time::sleep(delay).await;
+                }
+                Either::Left((None, _)) => break,
+                Either::Right((update, _)) => {
+                    let dispatcher = self.clone_shared();
+                    tokio::spawn(async move { dispatcher.process(update).await });
+                }
+            }
+        }
+    }
+
+    /// Handle a single update, e.g. received with a webhook.
+    /// Replies awaited with [`Context::ask`] are not passed to the handler.
+    /// Presses held back by the asker and released since the previous call are handled as well
+    pub async fn handle(&self, update: Update) {
+        let update = self.inner.asker.feed(update);
+        for released in self.inner.asker.take_released() {
+            self.process(released).await;
+        }
+        if let Some(update) = update {
+            self.process(update).await;
+        }
+    }
+
+    async fn process(&self, update: Update) {
+        let inner = &self.inner;
+        let mut ctx = Context {
+            update,
+            rutebot: inner.rutebot.clone(),
+            asker: inner.asker.clone(),
+            shared: inner.data.clone(),
+            local: HashMap::new(),
+        };
+        let mut called = 0;
+        let mut result = None;
+        for middleware in &inner.middlewares {
+            called += 1;
+            match middleware.before(&mut ctx).await {
+                Ok(true) => (),
+                Ok(false) => {
+                    result = Some(Ok(()));
+                    break;
+                }
+                Err(err) => {
+                    result = Some(Err(err));
+                    break;
+                }
+            }
+        }
+        let result = match result {
+            Some(result) => result,
+            None => (inner.handler)(ctx.clone()).await,
+        };
+        for middleware in inner.middlewares[..called].iter().rev() {
+            middleware.after(&ctx, &result).await;
+        }
+        if let Err(err) = result {
+            (inner.error_handler)(err, ctx.update).await;
+        }
+    }
+
+    fn inner_mut(&mut self) -> &mut Inner {
+        Arc::get_mut(&mut self.inner).expect("Dispatcher must not be changed after it is started")
+    }
+
+    fn clone_shared(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
@@ -12,6 +12,12 @@ pub enum Error {
     RequestBuilt(String),
     Serde(serde_json::Error),
     IO(std::io::Error),
+    /// Callback data could not be encoded or decoded
+    CallbackData(String),
+    /// Telegram Passport data could not be decrypted or authenticated
+    Passport(String),
+    /// Awaited update did not arrive in time
+    Timeout,
     /// Telegram bot api error
     Api {
         /// Error code returned by api
@@ -51,7 +57,10 @@ impl fmt::Display for Error {
                 error_code, description, parameters
             ),
             Error::RequestBuilt(x) => write!(f, "Request building was unsuccessful: {}", x),
-            Error::IO(io) => write!(f, "IO error has occurred: {}", io)
+            Error::IO(io) => write!(f, "IO error has occurred: {}", io),
+            Error::CallbackData(x) => write!(f, "Callback data error: {}", x),
+            Error::Passport(x) => write!(f, "Passport data error: {}", x),
+            Error::Timeout => write!(f, "Timeout has elapsed while waiting for an update"),
         }
     }
 }
@@ -0,0 +1,1140 @@
+use std::borrow::Cow;
+
+use crate::{
+    client::Rutebot,
+    error::Error,
+    requests::{
+        ChatId, ParseMode, Request, SendAnimation, SendAudio, SendDocument, SendMessage, SendPhoto,
+        SendVideo, SendVoice,
+    },
+    responses::{Message, MessageEntity, MessageEntityKind},
+};
+
+/// Maximum length of message text in UTF-16 code units
+pub const MAX_MESSAGE_LEN: usize = 4096;
+
+/// Maximum length of media caption in UTF-16 code units
+pub const MAX_CAPTION_LEN: usize = 1024;
+
+/// Formatting style of a part of the text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Style {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+
+    /// Monowidth string
+    Code,
+
+    /// Monowidth block with optional programming language of the code
+    Pre {
+        language: Option<String>,
+    },
+
+    /// Clickable text which opens the url
+    Link {
+        url: String,
+    },
+
+    /// Mention of a user by id, works for users without usernames
+    Mention {
+        user_id: i64,
+    },
+
+    /// Custom emoji. The text must be a single regular emoji shown instead of the custom one
+    /// where custom emoji are not supported
+    CustomEmoji {
+        custom_emoji_id: String,
+    },
+
+    Blockquote,
+
+    /// Blockquote which is collapsed by default
+    ExpandableBlockquote,
+}
+
+impl Style {
+    fn is_code(&self) -> bool {
+        matches!(self, Style::Code | Style::Pre { .. })
+    }
+
+    fn is_quote(&self) -> bool {
+        matches!(self, Style::Blockquote | Style::ExpandableBlockquote)
+    }
+
+    fn from_entity(entity: &MessageEntity) -> Option<Self> {
+        Some(match &entity.kind {
+            MessageEntityKind::Bold => Style::Bold,
+            MessageEntityKind::Italic => Style::Italic,
+            MessageEntityKind::Underline => Style::Underline,
+            MessageEntityKind::Strikethrough => Style::Strikethrough,
+            MessageEntityKind::Spoiler => Style::Spoiler,
+            MessageEntityKind::Code => Style::Code,
+            MessageEntityKind::Pre { language } => Style::Pre {
+                language: language.clone(),
+            },
+            MessageEntityKind::TextLink { url } => Style::Link { url: url.clone() },
+            MessageEntityKind::TextMention { user } => Style::Mention { user_id: user.id },
+            MessageEntityKind::CustomEmoji { custom_emoji_id } => Style::CustomEmoji {
+                custom_emoji_id: custom_emoji_id.clone(),
+            },
+            MessageEntityKind::Blockquote => Style::Blockquote,
+            MessageEntityKind::ExpandableBlockquote => Style::ExpandableBlockquote,
+            _ => return None,
+        })
+    }
+
+    fn to_entity(&self, offset: i64, length: i64) -> MessageEntity {
+        let kind = match self {
+            Style::Bold => MessageEntityKind::Bold,
+            Style::Italic => MessageEntityKind::Italic,
+            Style::Underline => MessageEntityKind::Underline,
+            Style::Strikethrough => MessageEntityKind::Strikethrough,
+            Style::Spoiler => MessageEntityKind::Spoiler,
+            Style::Code => MessageEntityKind::Code,
+            Style::Pre { language } => MessageEntityKind::Pre {
+                language: language.clone(),
+            },
+            Style::Link { url } => MessageEntityKind::TextLink { url: url.clone() },
+            Style::Mention { user_id } => MessageEntityKind::TextLink {
+                url: format!("tg://user?id={}", user_id),
+            },
+            Style::CustomEmoji { custom_emoji_id } => MessageEntityKind::CustomEmoji {
+                custom_emoji_id: custom_emoji_id.clone(),
+            },
+            Style::Blockquote => MessageEntityKind::Blockquote,
+            Style::ExpandableBlockquote => MessageEntityKind::ExpandableBlockquote,
+        };
+        MessageEntity {
+            kind,
+            offset,
+            length,
+        }
+    }
+}
+
+/// Text with markup ready to be sent with the corresponding parse mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Markup {
+    /// Text with markup
+    pub text: String,
+
+    /// Parse mode the markup is written in
+    pub parse_mode: ParseMode,
+}
+
+impl Markup {
+    /// Text and parse mode, the text stays borrowed if the markup is borrowed
+    pub(crate) fn into_parts(markup: Cow<'_, Markup>) -> (Cow<'_, str>, ParseMode) {
+        match markup {
+            Cow::Borrowed(x) => (x.text.as_str().into(), x.parse_mode),
+            Cow::Owned(x) => (x.text.into(), x.parse_mode),
+        }
+    }
+}
+
+impl<'a> From<&'a Markup> for Cow<'a, Markup> {
+    fn from(x: &'a Markup) -> Self {
+        Cow::Borrowed(x)
+    }
+}
+
+impl From<Markup> for Cow<'_, Markup> {
+    fn from(x: Markup) -> Self {
+        Cow::Owned(x)
+    }
+}
+
+/// Escape text for `ParseMode::Html`
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape text for `ParseMode::MarkdownV2`
+pub fn escape_markdown_v2(text: &str) -> String {
+    escape_with(text, |c| "_*[]()~`>#+-=|{}.!\\".contains(c))
+}
+
+/// Escape text inside `code` and `pre` entities for `ParseMode::MarkdownV2`
+pub fn escape_markdown_v2_code(text: &str) -> String {
+    escape_with(text, |c| c == '`' || c == '\\')
+}
+
+/// Escape url of inline links and custom emoji for `ParseMode::MarkdownV2`
+pub fn escape_markdown_v2_url(text: &str) -> String {
+    escape_with(text, |c| c == ')' || c == '\\')
+}
+
+fn escape_with(text: &str, must_be_escaped: impl Fn(char) -> bool) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if must_be_escaped(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Mode {
+    Html,
+    MarkdownV2,
+}
+
+impl Mode {
+    pub(crate) fn parse_mode(self) -> ParseMode {
+        match self {
+            Mode::Html => ParseMode::Html,
+            Mode::MarkdownV2 => ParseMode::MarkdownV2,
+        }
+    }
+
+    pub(crate) fn escape(self, text: &str, in_code: bool) -> String {
+        match (self, in_code) {
+            (Mode::Html, _) => escape_html(text),
+            (Mode::MarkdownV2, false) => escape_markdown_v2(text),
+            (Mode::MarkdownV2, true) => escape_markdown_v2_code(text),
+        }
+    }
+
+    pub(crate) fn push_open(self, out: &mut String, style: &Style) {
+        match self {
+            Mode::Html => match style {
+                Style::Bold => out.push_str("<b>"),
+                Style::Italic => out.push_str("<i>"),
+                Style::Underline => out.push_str("<u>"),
+                Style::Strikethrough => out.push_str("<s>"),
+                Style::Spoiler => out.push_str("<tg-spoiler>"),
+                Style::Code => out.push_str("<code>"),
+                Style::Pre { language: None } => out.push_str("<pre>"),
+                Style::Pre {
+                    language: Some(language),
+                } => {
+                    out.push_str("<pre><code class=\"language-");
+                    out.push_str(&escape_html(language));
+                    out.push_str("\">");
+                }
+                Style::Link { url } => {
+                    out.push_str("<a href=\"");
+                    out.push_str(&escape_html(url));
+                    out.push_str("\">");
+                }
+                Style::Mention { user_id } => {
+                    out.push_str(&format!("<a href=\"tg://user?id={}\">", user_id))
+                }
+                Style::CustomEmoji { custom_emoji_id } => {
+                    out.push_str("<tg-emoji emoji-id=\"");
+                    out.push_str(&escape_html(custom_emoji_id));
+                    out.push_str("\">");
+                }
+                Style::Blockquote => out.push_str("<blockquote>"),
+                Style::ExpandableBlockquote => out.push_str("<blockquote expandable>"),
+            },
+            Mode::MarkdownV2 => match style {
+                Style::Bold => out.push('*'),
+                Style::Italic => push_underscores(out, "_"),
+                Style::Underline => push_underscores(out, "__"),
+                Style::Strikethrough => out.push('~'),
+                Style::Spoiler => out.push_str("||"),
+                Style::Code => out.push('`'),
+                Style::Pre { language } => {
+                    out.push_str("```");
+                    out.push_str(&escape_markdown_v2_code(
+                        language.as_deref().unwrap_or_default(),
+                    ));
+                    out.push('\n');
+                }
+                Style::Link { .. } | Style::Mention { .. } => out.push('['),
+                Style::CustomEmoji { .. } => out.push_str("!["),
+                Style::Blockquote => {
+                    push_line_start(out);
+                    out.push('>')
+                }
+                Style::ExpandableBlockquote => {
+                    push_line_start(out);
+                    out.push_str("**>")
+                }
+            },
+        }
+    }
+
+    pub(crate) fn push_close(self, out: &mut String, style: &Style) {
+        match self {
+            Mode::Html => match style {
+                Style::Bold => out.push_str("</b>"),
+                Style::Italic => out.push_str("</i>"),
+                Style::Underline => out.push_str("</u>"),
+                Style::Strikethrough => out.push_str("</s>"),
+                Style::Spoiler => out.push_str("</tg-spoiler>"),
+                Style::Code => out.push_str("</code>"),
+                Style::Pre { language: None } => out.push_str("</pre>"),
+                Style::Pre { language: Some(_) } => out.push_str("</code></pre>"),
+                Style::Link { .. } | Style::Mention { .. } => out.push_str("</a>"),
+                Style::CustomEmoji { .. } => out.push_str("</tg-emoji>"),
+                Style::Blockquote | Style::ExpandableBlockquote => out.push_str("</blockquote>"),
+            },
+            Mode::MarkdownV2 => match style {
+                Style::Bold => out.push('*'),
+                Style::Italic => push_underscores(out, "_"),
+                Style::Underline => push_underscores(out, "__"),
+                Style::Strikethrough => out.push('~'),
+                Style::Spoiler => out.push_str("||"),
+                Style::Code => out.push('`'),
+                Style::Pre { .. } => out.push_str("```"),
+                Style::Link { url } => {
+                    out.push_str("](");
+                    out.push_str(&escape_markdown_v2_url(url));
+                    out.push(')');
+                }
+                Style::Mention { user_id } => out.push_str(&format!("](tg://user?id={})", user_id)),
+                Style::CustomEmoji { custom_emoji_id } => {
+                    out.push_str("](tg://emoji?id=");
+                    out.push_str(&escape_markdown_v2_url(custom_emoji_id));
+                    out.push(')');
+                }
+                Style::Blockquote => (),
+                Style::ExpandableBlockquote => out.push_str("||"),
+            },
+        }
+    }
+}
+
+/// Quotes in MarkdownV2 are recognized only at the start of a line
+fn push_line_start(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+/// `___` is ambiguous between italic and underline and is greedily parsed from left to right,
+/// so adjacent italic and underline delimiters are separated with `\r` which Telegram ignores
+fn push_underscores(out: &mut String, underscores: &str) {
+    if ends_with_unescaped(out, '_') {
+        out.push('\r');
+    }
+    out.push_str(underscores)
+}
+
+fn ends_with_unescaped(text: &str, c: char) -> bool {
+    match text.strip_suffix(c) {
+        Some(rest) => rest.chars().rev().take_while(|&x| x == '\\').count() % 2 == 0,
+        None => false,
+    }
+}
+
+/// Render text of a received message with its entities as `ParseMode::Html` markup,
+/// e.g. to quote the message in another chat. Entities which Telegram detects automatically,
+/// such as urls or hashtags, are left as plain text.
+///
+/// ## Example
+/// ```
+/// # use rutebot::{formatting::render_html, responses::{MessageEntity, MessageEntityKind}};
+/// let entities = [MessageEntity {
+///     kind: MessageEntityKind::Bold,
+///     offset: 4,
+///     length: 1,
+/// }];
+///
+/// assert_eq!(render_html("1 < 2", &entities).text, "1 &lt; <b>2</b>");
+/// ```
+pub fn render_html(text: &str, entities: &[MessageEntity]) -> Markup {
+    render(Mode::Html, text, entities)
+}
+
+/// Render text of a received message with its entities as `ParseMode::MarkdownV2` markup.
+/// Entities which Telegram detects automatically, such as urls or hashtags, are left as plain text.
+pub fn render_markdown_v2(text: &str, entities: &[MessageEntity]) -> Markup {
+    render(Mode::MarkdownV2, text, entities)
+}
+
+struct Span {
+    start: usize,
+    end: usize,
+    style: Style,
+}
+
+fn render(mode: Mode, text: &str, entities: &[MessageEntity]) -> Markup {
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    let clamp = |x: i64| x.clamp(0, utf16.len() as i64) as usize;
+    let mut spans: Vec<Span> = entities
+        .iter()
+        .filter_map(|entity| {
+            let start = clamp(entity.offset);
+            let end = clamp(entity.offset.saturating_add(entity.length));
+            let style = Style::from_entity(entity)?;
+            Some(Span { start, end, style }).filter(|_| start < end)
+        })
+        .collect();
+    // Outer entities are opened first, so they are sorted by start and then by end descending
+    spans.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+    let mut boundaries: Vec<usize> = spans.iter().flat_map(|x| [x.start, x.end]).collect();
+    boundaries.extend([0, utf16.len()]);
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let mut out = String::with_capacity(text.len());
+    let mut open: Vec<usize> = Vec::new();
+    let mut next_span = 0;
+    for (i, &position) in boundaries.iter().enumerate() {
+        // Entities which end here are closed together with all entities opened inside them.
+        // The latter are reopened, so overlapping entities are split into nested ones
+        let mut reopen = Vec::new();
+        let mut quote_ended = false;
+        if let Some(first_ended) = open.iter().position(|&x| spans[x].end <= position) {
+            for idx in open.drain(first_ended..).rev() {
+                mode.push_close(&mut out, &spans[idx].style);
+                quote_ended |= spans[idx].style.is_quote();
+                if spans[idx].end > position {
+                    reopen.push(idx);
+                }
+            }
+        }
+        // Everything after a MarkdownV2 quote on the same line would belong to the quote
+        let at_line_end = utf16.get(position).is_none_or(|&x| x == b'\n' as u16);
+        if mode == Mode::MarkdownV2 && quote_ended && !at_line_end {
+            out.push('\n');
+        }
+        reopen.reverse();
+        while next_span < spans.len() && spans[next_span].start == position {
+            reopen.push(next_span);
+            next_span += 1;
+        }
+        reopen.sort_by(|&a, &b| spans[b].end.cmp(&spans[a].end));
+        for idx in reopen {
+            mode.push_open(&mut out, &spans[idx].style);
+            open.push(idx);
+        }
+
+        if let Some(&next) = boundaries.get(i + 1) {
+            let segment = String::from_utf16_lossy(&utf16[position..next]);
+            let in_code = open.iter().any(|&x| spans[x].style.is_code());
+            let escaped = mode.escape(&segment, in_code);
+            let in_quote = open.iter().any(|&x| spans[x].style.is_quote());
+            if mode == Mode::MarkdownV2 && in_quote {
+                out.push_str(&escaped.replace('\n', "\n>"));
+            } else {
+                out.push_str(&escaped);
+            }
+        }
+    }
+
+    Markup {
+        text: out,
+        parse_mode: mode.parse_mode(),
+    }
+}
+
+/// Builds text with `ParseMode::Html` or `ParseMode::MarkdownV2` markup.
+/// All text is escaped, so user-provided strings can be safely interpolated.
+///
+/// ## Example
+/// ```
+/// # use rutebot::formatting::{MarkupBuilder, Style};
+/// let user_input = "1 + 1 = 2!";
+/// let markup = MarkupBuilder::markdown_v2()
+///     .bold("Result: ")
+///     .styled(Style::Italic, |b| b.text(user_input).strikethrough("?"))
+///     .build();
+///
+/// assert_eq!(markup.text, r"*Result: *_1 \+ 1 \= 2\!~?~_");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MarkupBuilder {
+    mode: Mode,
+    in_code: bool,
+    text: String,
+    quote_ended: bool,
+}
+
+impl MarkupBuilder {
+    /// Builder for `ParseMode::Html`
+    pub fn html() -> Self {
+        Self::new(Mode::Html, false)
+    }
+
+    /// Builder for `ParseMode::MarkdownV2`
+    pub fn markdown_v2() -> Self {
+        Self::new(Mode::MarkdownV2, false)
+    }
+
+    fn new(mode: Mode, in_code: bool) -> Self {
+        Self {
+            mode,
+            in_code,
+            text: String::new(),
+            quote_ended: false,
+        }
+    }
+
+    /// Append plain text
+    pub fn text(mut self, text: &str) -> Self {
+        if !text.is_empty() {
+            self.end_quote_line(text.starts_with('\n'));
+        }
+        let escaped = self.mode.escape(text, self.in_code);
+        self.text.push_str(&escaped);
+        self
+    }
+
+    /// Append text with the style. Styles can be nested using `build`,
+    /// except for `Style::Code` and `Style::Pre` which can contain only plain text
+    pub fn styled(mut self, style: Style, build: impl FnOnce(Self) -> Self) -> Self {
+        let inner = build(Self::new(self.mode, self.in_code || style.is_code())).text;
+        self.end_quote_line(false);
+        self.mode.push_open(&mut self.text, &style);
+        if self.mode == Mode::MarkdownV2 && style.is_quote() {
+            self.text.push_str(&inner.replace('\n', "\n>"));
+            self.quote_ended = true;
+        } else {
+            self.text.push_str(&inner);
+        }
+        self.mode.push_close(&mut self.text, &style);
+        self
+    }
+
+    pub fn bold(self, text: &str) -> Self {
+        self.styled(Style::Bold, |b| b.text(text))
+    }
+
+    pub fn italic(self, text: &str) -> Self {
+        self.styled(Style::Italic, |b| b.text(text))
+    }
+
+    pub fn underline(self, text: &str) -> Self {
+        self.styled(Style::Underline, |b| b.text(text))
+    }
+
+    pub fn strikethrough(self, text: &str) -> Self {
+        self.styled(Style::Strikethrough, |b| b.text(text))
+    }
+
+    pub fn spoiler(self, text: &str) -> Self {
+        self.styled(Style::Spoiler, |b| b.text(text))
+    }
+
+    pub fn code(self, code: &str) -> Self {
+        self.styled(Style::Code, |b| b.text(code))
+    }
+
+    /// Append monowidth block with optional programming language of the code
+    pub fn pre(self, code: &str, language: Option<&str>) -> Self {
+        let language = language.map(str::to_string);
+        self.styled(Style::Pre { language }, |b| b.text(code))
+    }
+
+    pub fn link(self, text: &str, url: &str) -> Self {
+        let url = url.to_string();
+        self.styled(Style::Link { url }, |b| b.text(text))
+    }
+
+    /// Append mention of the user by id
+    pub fn mention(self, text: &str, user_id: i64) -> Self {
+        self.styled(Style::Mention { user_id }, |b| b.text(text))
+    }
+
+    /// Append custom emoji. `emoji` is shown where custom emoji are not supported
+    pub fn custom_emoji(self, emoji: &str, custom_emoji_id: &str) -> Self {
+        let custom_emoji_id = custom_emoji_id.to_string();
+        self.styled(Style::CustomEmoji { custom_emoji_id }, |b| b.text(emoji))
+    }
+
+    pub fn blockquote(self, text: &str) -> Self {
+        self.styled(Style::Blockquote, |b| b.text(text))
+    }
+
+    pub fn expandable_blockquote(self, text: &str) -> Self {
+        self.styled(Style::ExpandableBlockquote, |b| b.text(text))
+    }
+
+    pub fn build(self) -> Markup {
+        Markup {
+            text: self.text,
+            parse_mode: self.mode.parse_mode(),
+        }
+    }
+
+    /// Everything after a MarkdownV2 quote on the same line would belong to the quote,
+    /// so the line is ended unless the appended text starts with a new line
+    fn end_quote_line(&mut self, starts_with_new_line: bool) {
+        if std::mem::take(&mut self.quote_ended) && !starts_with_new_line {
+            self.text.push('\n');
+        }
+    }
+}
+
+/// Plain text with entities describing its formatting. Unlike markup it needs no escaping,
+/// so it is the safest way to send text received from users.
+///
+/// ## Example
+/// ```
+/// # use rutebot::{formatting::{FormattedText, Style}, responses::MessageEntityKind};
+/// let formatted = FormattedText::new()
+///     .text("Привет, ")
+///     .styled(Style::Bold, |t| t.text("мир ").italic("🌍"));
+///
+/// assert_eq!(formatted.text, "Привет, мир 🌍");
+/// assert!(matches!(formatted.entities[0].kind, MessageEntityKind::Bold));
+/// assert_eq!((formatted.entities[0].offset, formatted.entities[0].length), (8, 6));
+/// assert_eq!((formatted.entities[1].offset, formatted.entities[1].length), (12, 2));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FormattedText {
+    /// Text without any markup
+    pub text: String,
+
+    /// Entities sorted by offset, offsets and lengths are in UTF-16 code units
+    pub entities: Vec<MessageEntity>,
+
+    utf16_len: i64,
+}
+
+impl FormattedText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Length of the text in UTF-16 code units
+    pub fn utf16_len(&self) -> i64 {
+        self.utf16_len
+    }
+
+    /// Parse text with `ParseMode::Html` markup
+    pub fn from_html(html: &str) -> Result<Self, Error> {
+        HtmlParser::default().parse(html)
+    }
+
+    /// Split the text into parts of at most `max_len` UTF-16 code units.
+    /// Parts are cut at paragraph, line or word boundaries outside of entities if possible,
+    /// entities which still have to be cut are continued in the next part.
+    /// Characters are never cut, so with `max_len` less than 2 characters outside of
+    /// the Basic Multilingual Plane, such as emoji, take parts of 2 code units.
+    /// `max_len` of 0 is treated as 1
+    pub fn split(&self, max_len: usize) -> Vec<FormattedText> {
+        let max_len = max_len.max(1);
+        let units: Vec<u16> = self.text.encode_utf16().collect();
+        let mut inside_entity = vec![false; units.len() + 1];
+        for entity in &self.entities {
+            let (start, end) = entity_range(entity, units.len());
+            for x in &mut inside_entity[(start + 1).min(end)..end] {
+                *x = true;
+            }
+        }
+
+        let mut parts = Vec::new();
+        let mut start = 0;
+        while units.len() - start > max_len {
+            let cut = find_cut(&units, &inside_entity, start, start + max_len);
+            parts.push(self.slice(&units, start, cut));
+            start = cut;
+        }
+        if start < units.len() || parts.is_empty() {
+            parts.push(self.slice(&units, start, units.len()));
+        }
+        parts
+    }
+
+    /// Split the text into a caption of at most `MAX_CAPTION_LEN` and the rest which
+    /// doesn't fit into the caption, split into messages of at most `MAX_MESSAGE_LEN`
+    pub fn split_caption(&self) -> (FormattedText, Vec<FormattedText>) {
+        let caption = self.split(MAX_CAPTION_LEN).swap_remove(0);
+        let units: Vec<u16> = self.text.encode_utf16().collect();
+        let start = caption.utf16_len as usize;
+        let rest = if start < units.len() {
+            self.slice(&units, start, units.len())
+                .split(MAX_MESSAGE_LEN)
+        } else {
+            Vec::new()
+        };
+        (caption, rest)
+    }
+
+    /// Text and entities, both stay borrowed if the formatted text is borrowed
+    pub(crate) fn into_parts(
+        formatted: Cow<'_, FormattedText>,
+    ) -> (Cow<'_, str>, Cow<'_, [MessageEntity]>) {
+        match formatted {
+            Cow::Borrowed(x) => (x.text.as_str().into(), x.entities.as_slice().into()),
+            Cow::Owned(x) => (x.text.into(), x.entities.into()),
+        }
+    }
+
+    fn slice(&self, units: &[u16], start: usize, end: usize) -> FormattedText {
+        let entities = self
+            .entities
+            .iter()
+            .filter_map(|entity| {
+                let (entity_start, entity_end) = entity_range(entity, units.len());
+                let (clipped_start, clipped_end) = (entity_start.max(start), entity_end.min(end));
+                if clipped_start >= clipped_end {
+                    return None;
+                }
+                Some(MessageEntity {
+                    kind: entity.kind.clone(),
+                    offset: (clipped_start - start) as i64,
+                    length: (clipped_end - clipped_start) as i64,
+                })
+            })
+            .collect();
+        FormattedText {
+            text: String::from_utf16_lossy(&units[start..end]),
+            entities,
+            utf16_len: (end - start) as i64,
+        }
+    }
+
+    /// Append plain text
+    pub fn text(mut self, text: &str) -> Self {
+        self.text.push_str(text);
+        self.utf16_len += text.encode_utf16().count() as i64;
+        self
+    }
+
+    /// Append text with the style. Styles can be nested using `build`
+    pub fn styled(self, style: Style, build: impl FnOnce(Self) -> Self) -> Self {
+        let offset = self.utf16_len;
+        let first_inner = self.entities.len();
+        let mut formatted = build(self);
+        let length = formatted.utf16_len - offset;
+        if length > 0 {
+            let entity = style.to_entity(offset, length);
+            formatted.entities.insert(first_inner, entity);
+        }
+        formatted
+    }
+
+    pub fn bold(self, text: &str) -> Self {
+        self.styled(Style::Bold, |t| t.text(text))
+    }
+
+    pub fn italic(self, text: &str) -> Self {
+        self.styled(Style::Italic, |t| t.text(text))
+    }
+
+    pub fn underline(self, text: &str) -> Self {
+        self.styled(Style::Underline, |t| t.text(text))
+    }
+
+    pub fn strikethrough(self, text: &str) -> Self {
+        self.styled(Style::Strikethrough, |t| t.text(text))
+    }
+
+    pub fn spoiler(self, text: &str) -> Self {
+        self.styled(Style::Spoiler, |t| t.text(text))
+    }
+
+    pub fn code(self, code: &str) -> Self {
+        self.styled(Style::Code, |t| t.text(code))
+    }
+
+    /// Append monowidth block with optional programming language of the code
+    pub fn pre(self, code: &str, language: Option<&str>) -> Self {
+        let language = language.map(str::to_string);
+        self.styled(Style::Pre { language }, |t| t.text(code))
+    }
+
+    pub fn link(self, text: &str, url: &str) -> Self {
+        let url = url.to_string();
+        self.styled(Style::Link { url }, |t| t.text(text))
+    }
+
+    /// Append mention of the user by id
+    pub fn mention(self, text: &str, user_id: i64) -> Self {
+        self.styled(Style::Mention { user_id }, |t| t.text(text))
+    }
+
+    /// Append custom emoji. `emoji` is shown where custom emoji are not supported
+    pub fn custom_emoji(self, emoji: &str, custom_emoji_id: &str) -> Self {
+        let custom_emoji_id = custom_emoji_id.to_string();
+        self.styled(Style::CustomEmoji { custom_emoji_id }, |t| t.text(emoji))
+    }
+
+    pub fn blockquote(self, text: &str) -> Self {
+        self.styled(Style::Blockquote, |t| t.text(text))
+    }
+
+    pub fn expandable_blockquote(self, text: &str) -> Self {
+        self.styled(Style::ExpandableBlockquote, |t| t.text(text))
+    }
+}
+
+impl<'a> From<&'a FormattedText> for Cow<'a, FormattedText> {
+    fn from(x: &'a FormattedText) -> Self {
+        Cow::Borrowed(x)
+    }
+}
+
+impl From<FormattedText> for Cow<'_, FormattedText> {
+    fn from(x: FormattedText) -> Self {
+        Cow::Owned(x)
+    }
+}
+
+fn entity_range(entity: &MessageEntity, len: usize) -> (usize, usize) {
+    let clamp = |x: i64| x.clamp(0, len as i64) as usize;
+    let start = clamp(entity.offset);
+    (
+        start,
+        clamp(entity.offset.saturating_add(entity.length)).max(start),
+    )
+}
+
+/// Find position to cut `units[start..]` at, no further than `limit`
+fn find_cut(units: &[u16], inside_entity: &[bool], start: usize, limit: usize) -> usize {
+    let is = |idx: usize, c: char| units[idx] == c as u16;
+    let paragraph = |x: usize| x >= 2 && is(x - 1, '\n') && is(x - 2, '\n');
+    let line = |x: usize| is(x - 1, '\n');
+    let word = |x: usize| is(x - 1, ' ') || is(x - 1, '\n');
+    let boundaries: [&dyn Fn(usize) -> bool; 3] = [&paragraph, &line, &word];
+    for allow_inside_entity in [false, true] {
+        for boundary in boundaries {
+            let cut = (start + 1..=limit)
+                .rev()
+                .find(|&x| (allow_inside_entity || !inside_entity[x]) && boundary(x));
+            if let Some(cut) = cut {
+                return cut;
+            }
+        }
+    }
+    // Low surrogate must stay together with the preceding high surrogate.
+    // If the pair is the only content of the part, the part has to exceed the limit
+    let cuts_pair = units
+        .get(limit)
+        .is_some_and(|x| (0xDC00..0xE000).contains(x));
+    if !cuts_pair {
+        limit
+    } else if limit - 1 > start {
+        limit - 1
+    } else {
+        limit + 1
+    }
+}
+
+struct OpenTag {
+    name: String,
+    style: Option<Style>,
+    start: i64,
+    first_inner: usize,
+}
+
+#[derive(Default)]
+struct HtmlParser {
+    formatted: FormattedText,
+    open: Vec<OpenTag>,
+}
+
+impl HtmlParser {
+    fn parse(mut self, html: &str) -> Result<FormattedText, Error> {
+        let mut rest = html;
+        while !rest.is_empty() {
+            let text_end = rest.find('<').unwrap_or(rest.len());
+            let text = decode_html_entities(&rest[..text_end]);
+            self.formatted = self.formatted.text(&text);
+            rest = &rest[text_end..];
+            if rest.is_empty() {
+                break;
+            }
+            let tag_end = rest
+                .find('>')
+                .ok_or_else(|| html_error("unclosed tag", rest))?;
+            let tag = &rest[1..tag_end];
+            match tag.strip_prefix('/') {
+                Some(name) => self.close(name.trim())?,
+                None => self.open(tag)?,
+            }
+            rest = &rest[tag_end + 1..];
+        }
+        match self.open.last() {
+            Some(tag) => Err(html_error("tag is not closed", &tag.name)),
+            None => Ok(self.formatted),
+        }
+    }
+
+    fn open(&mut self, tag: &str) -> Result<(), Error> {
+        let mut parts = tag.trim().splitn(2, char::is_whitespace);
+        let name = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let attributes = parse_attributes(parts.next().unwrap_or_default());
+        let attribute = |name: &str| {
+            attributes
+                .iter()
+                .find(|(x, _)| x == name)
+                .map(|(_, value)| value.clone())
+        };
+        let style = match name.as_str() {
+            "b" | "strong" => Some(Style::Bold),
+            "i" | "em" => Some(Style::Italic),
+            "u" | "ins" => Some(Style::Underline),
+            "s" | "strike" | "del" => Some(Style::Strikethrough),
+            "tg-spoiler" => Some(Style::Spoiler),
+            "span" if attribute("class").as_deref() == Some("tg-spoiler") => Some(Style::Spoiler),
+            "a" => {
+                let href = attribute("href").ok_or_else(|| html_error("link without href", tag))?;
+                match href.strip_prefix("tg://user?id=").map(str::parse) {
+                    Some(Ok(user_id)) => Some(Style::Mention { user_id }),
+                    _ => Some(Style::Link { url: href }),
+                }
+            }
+            "code" => {
+                let language = attribute("class")
+                    .and_then(|x| x.strip_prefix("language-").map(str::to_string));
+                let code_in_pre = match self.open.last_mut() {
+                    Some(OpenTag {
+                        style:
+                            Some(Style::Pre {
+                                language: pre_language,
+                            }),
+                        start,
+                        ..
+                    }) if *start == self.formatted.utf16_len => {
+                        *pre_language = language;
+                        true
+                    }
+                    _ => false,
+                };
+                if code_in_pre {
+                    None
+                } else {
+                    Some(Style::Code)
+                }
+            }
+            "pre" => Some(Style::Pre { language: None }),
+            "tg-emoji" => {
+                let custom_emoji_id = attribute("emoji-id")
+                    .ok_or_else(|| html_error("custom emoji without emoji-id", tag))?;
+                Some(Style::CustomEmoji { custom_emoji_id })
+            }
+            "blockquote" if attribute("expandable").is_some() => Some(Style::ExpandableBlockquote),
+            "blockquote" => Some(Style::Blockquote),
+            _ => return Err(html_error("unsupported tag", tag)),
+        };
+        self.open.push(OpenTag {
+            name,
+            style,
+            start: self.formatted.utf16_len,
+            first_inner: self.formatted.entities.len(),
+        });
+        Ok(())
+    }
+
+    fn close(&mut self, name: &str) -> Result<(), Error> {
+        let tag = match self.open.pop() {
+            Some(tag) if tag.name.eq_ignore_ascii_case(name) => tag,
+            _ => return Err(html_error("unexpected closing tag", name)),
+        };
+        let length = self.formatted.utf16_len - tag.start;
+        if let (Some(style), true) = (tag.style, length > 0) {
+            let entity = style.to_entity(tag.start, length);
+            self.formatted.entities.insert(tag.first_inner, entity);
+        }
+        Ok(())
+    }
+}
+
+fn html_error(reason: &str, near: &str) -> Error {
+    Error::RequestBuilt(format!("Can't parse html, {} near '{}'", reason, near))
+}
+
+fn parse_attributes(mut rest: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    loop {
+        rest = rest.trim_start().trim_start_matches('/');
+        if rest.is_empty() {
+            return attributes;
+        }
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+        let value = match rest.strip_prefix('=') {
+            Some(value) => {
+                let value = value.trim_start();
+                let (raw, tail) = match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let value = &value[1..];
+                        let end = value.find(quote).unwrap_or(value.len());
+                        (&value[..end], value.get(end + 1..).unwrap_or_default())
+                    }
+                    _ => value.split_at(value.find(char::is_whitespace).unwrap_or(value.len())),
+                };
+                rest = tail;
+                decode_html_entities(raw)
+            }
+            None => String::new(),
+        };
+        attributes.push((name, value));
+    }
+}
+
+fn decode_html_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(idx) = rest.find('&') {
+        decoded.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let c = entity.and_then(|(name, _)| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            _ => match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => name.strip_prefix('#').and_then(|x| x.parse().ok()),
+            }
+            .and_then(char::from_u32),
+        });
+        match (c, entity) {
+            (Some(c), Some((_, end))) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// Split `html` into parts of at most `max_len` UTF-16 code units of visible text.
+/// Formatting which has to be cut is closed at the end of a part and reopened in the next one
+pub fn split_html(html: &str, max_len: usize) -> Result<Vec<Markup>, Error> {
+    let formatted = FormattedText::from_html(html)?;
+    let parts = formatted.split(max_len);
+    Ok(parts
+        .iter()
+        .map(|x| render_html(&x.text, &x.entities))
+        .collect())
+}
+
+/// Send parts of the text one by one, each part replying to the previous one.
+/// The first part replies to `reply_to_message_id` if it is set
+pub async fn send_chain(
+    rutebot: &Rutebot,
+    chat_id: ChatId<'_>,
+    parts: &[FormattedText],
+    reply_to_message_id: Option<i64>,
+) -> Result<Vec<Message>, Error> {
+    let mut reply_to_message_id = reply_to_message_id;
+    let mut sent = Vec::with_capacity(parts.len());
+    for part in parts {
+        let request = SendMessage {
+            reply_to_message_id,
+            ..SendMessage::new_formatted(chat_id.clone(), part)
+        };
+        let message = rutebot.prepare_api_request(request).send().await?;
+        reply_to_message_id = Some(message.message_id);
+        sent.push(message);
+    }
+    Ok(sent)
+}
+
+/// Request which sends media with a caption
+pub trait CaptionedMedia: Request<ResponseType = Message> {
+    /// Set caption built with `FormattedText`
+    fn with_formatted_caption(self, caption: FormattedText) -> Self;
+}
+
+impl<'a> CaptionedMedia for SendAnimation<'a> {
+    fn with_formatted_caption(self, caption: FormattedText) -> Self {
+        SendAnimation::with_formatted_caption(self, caption)
+    }
+}
+
+impl<'a> CaptionedMedia for SendAudio<'a> {
+    fn with_formatted_caption(self, caption: FormattedText) -> Self {
+        SendAudio::with_formatted_caption(self, caption)
+    }
+}
+
+impl<'a> CaptionedMedia for SendDocument<'a> {
+    fn with_formatted_caption(self, caption: FormattedText) -> Self {
+        SendDocument::with_formatted_caption(self, caption)
+    }
+}
+
+impl<'a> CaptionedMedia for SendPhoto<'a> {
+    fn with_formatted_caption(self, caption: FormattedText) -> Self {
+        SendPhoto::with_formatted_caption(self, caption)
+    }
+}
+
+impl<'a> CaptionedMedia for SendVideo<'a> {
+    fn with_formatted_caption(self, caption: FormattedText) -> Self {
+        SendVideo::with_formatted_caption(self, caption)
+    }
+}
+
+impl<'a> CaptionedMedia for SendVoice<'a> {
+    fn with_formatted_caption(self, caption: FormattedText) -> Self {
+        SendVoice::with_formatted_caption(self, caption)
+    }
+}
+
+/// Send media with the caption of any length. The part of the caption which doesn't fit into
+/// `MAX_CAPTION_LEN` is sent in messages of at most `MAX_MESSAGE_LEN` as a reply chain to the media.
+/// Returns the media message followed by the messages with the rest of the caption
+///
+/// ## Example
+/// ```no_run
+/// # use rutebot::{client::Rutebot, formatting::{send_with_caption, FormattedText}, requests::{FileKind, SendPhoto}};
+/// # async fn run() -> Result<(), rutebot::error::Error> {
+/// let rutebot = Rutebot::new("token");
+/// let photo = SendPhoto::new(123, FileKind::FileId("photo-id".into()));
+/// let description = FormattedText::new().bold("Sunset").text(&"\nVery long story".repeat(100));
+/// let messages = send_with_caption(&rutebot, photo, &description).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn send_with_caption<R: CaptionedMedia>(
+    rutebot: &Rutebot,
+    request: R,
+    caption: &FormattedText,
+) -> Result<Vec<Message>, Error> {
+    let (caption, rest) = caption.split_caption();
+    let request = request.with_formatted_caption(caption);
+    let media = rutebot.prepare_api_request(request).send().await?;
+    let chat_id = media.chat.id;
+    let reply_to_message_id = Some(media.message_id);
+    let mut sent = vec![media];
+    sent.extend(send_chain(rutebot, chat_id.into(), &rest, reply_to_message_id).await?);
+    Ok(sent)
+}
+
+/// Send the text of any length, split into messages of at most `MAX_MESSAGE_LEN` as a reply chain
+///
+/// ## Example
+/// ```no_run
+/// # use rutebot::{client::Rutebot, formatting::{send_split, FormattedText}};
+/// # async fn run() -> Result<(), rutebot::error::Error> {
+/// let rutebot = Rutebot::new("token");
+/// let log = FormattedText::from_html(&"<b>Log</b>\n".repeat(1000))?;
+/// let messages = send_split(&rutebot, 123.into(), &log, None).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn send_split(
+    rutebot: &Rutebot,
+    chat_id: ChatId<'_>,
+    text: &FormattedText,
+    reply_to_message_id: Option<i64>,
+) -> Result<Vec<Message>, Error> {
+    let parts = text.split(MAX_MESSAGE_LEN);
+    send_chain(rutebot, chat_id, &parts, reply_to_message_id).await
+}
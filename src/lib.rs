@@ -49,3 +49,28 @@ pub mod requests;
 
 /// Errors definitions
 pub mod error;
+
+/// Typed callback data for inline keyboard buttons
+pub mod callback_data;
+
+/// Multi-step conversations with pluggable state storage
+pub mod dialogue;
+
+/// Asking users questions and awaiting their replies
+pub mod ask;
+
+/// Routing of incoming updates through middlewares to handlers
+pub mod dispatcher;
+
+/// Delayed and periodic sending of requests
+pub mod scheduler;
+
+/// Escaping and building of formatted text for `requests::ParseMode`
+pub mod formatting;
+
+/// Declarative synchronization of the bot's profile
+pub mod profile;
+
+/// Decryption of Telegram Passport data shared with the bot
+#[cfg(feature = "passport")]
+pub mod passport;
@@ -0,0 +1,333 @@
+use aes::{
+    cipher::{block_padding::NoPadding, BlockDecryptMut, KeyIvInit},
+    Aes256,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rsa::{pkcs1::DecodeRsaPrivateKey, pkcs8::DecodePrivateKey, Oaep, RsaPrivateKey};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+
+use crate::{
+    error::Error,
+    responses::{EncryptedCredentials, EncryptedPassportElementType},
+};
+
+/// Private RSA key of the bot which is used to decrypt `responses::EncryptedCredentials`.
+/// The public part of this key must be uploaded to [@BotFather](https://t.me/botfather).
+///
+/// ## Example
+/// ```no_run
+/// # use rutebot::passport::{PassportKey, PersonalDetails};
+/// # use rutebot::responses::{EncryptedPassportElementType, PassportData};
+/// # fn run(passport_data: PassportData, pem: &str) -> Result<(), rutebot::error::Error> {
+/// let key = PassportKey::from_pem(pem)?;
+/// let credentials = key.decrypt_credentials(&passport_data.credentials)?;
+/// for element in &passport_data.data {
+///     if let (Some(data), Some(value)) = (&element.data, credentials.secure_data.get(element.typ)) {
+///         if element.typ == EncryptedPassportElementType::PersonalDetails {
+///             let details: PersonalDetails = value.data.as_ref().unwrap().decrypt(data)?;
+///             println!("{} {}", details.first_name, details.last_name);
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PassportKey {
+    key: RsaPrivateKey,
+}
+
+impl PassportKey {
+    /// Parse PEM encoded private key in either PKCS#1 (`BEGIN RSA PRIVATE KEY`) or PKCS#8 (`BEGIN PRIVATE KEY`) format
+    pub fn from_pem(pem: &str) -> Result<Self, Error> {
+        RsaPrivateKey::from_pkcs1_pem(pem)
+            .or_else(|_| RsaPrivateKey::from_pkcs8_pem(pem))
+            .map(Self::from)
+            .map_err(|x| Error::Passport(format!("invalid private key: {}", x)))
+    }
+
+    /// Decrypt and authenticate credentials received in `responses::PassportData`
+    pub fn decrypt_credentials(
+        &self,
+        credentials: &EncryptedCredentials,
+    ) -> Result<Credentials, Error> {
+        let secret = self
+            .key
+            .decrypt(Oaep::new::<Sha1>(), &decode_base64(&credentials.secret)?)
+            .map_err(|x| Error::Passport(format!("secret could not be decrypted: {}", x)))?;
+        let data = decrypt(
+            &decode_base64(&credentials.data)?,
+            &secret,
+            &decode_base64(&credentials.hash)?,
+        )?;
+        serde_json::from_slice(&data).map_err(Error::Serde)
+    }
+}
+
+impl From<RsaPrivateKey> for PassportKey {
+    fn from(key: RsaPrivateKey) -> Self {
+        Self { key }
+    }
+}
+
+/// Decrypted credentials required to decrypt Telegram Passport elements
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Credentials {
+    /// Credentials for encrypted data
+    pub secure_data: SecureData,
+
+    /// Bot-specified nonce. Make sure that it is the same as the one passed in the authorization request
+    pub nonce: String,
+}
+
+/// Credentials for all Telegram Passport elements shared with the bot
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SecureData {
+    /// Credentials for encrypted personal details
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_details: Option<SecureValue>,
+
+    /// Credentials for encrypted passport
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport: Option<SecureValue>,
+
+    /// Credentials for encrypted internal passport
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal_passport: Option<SecureValue>,
+
+    /// Credentials for encrypted driver license
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_license: Option<SecureValue>,
+
+    /// Credentials for encrypted ID card
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identity_card: Option<SecureValue>,
+
+    /// Credentials for encrypted residential address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<SecureValue>,
+
+    /// Credentials for encrypted utility bill
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utility_bill: Option<SecureValue>,
+
+    /// Credentials for encrypted bank statement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_statement: Option<SecureValue>,
+
+    /// Credentials for encrypted rental agreement
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rental_agreement: Option<SecureValue>,
+
+    /// Credentials for encrypted registration from internal passport
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passport_registration: Option<SecureValue>,
+
+    /// Credentials for encrypted temporary registration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary_registration: Option<SecureValue>,
+}
+
+impl SecureData {
+    /// Credentials for the element of the given type. Phone number and email are not encrypted,
+    /// so there are never credentials for them
+    pub fn get(&self, typ: EncryptedPassportElementType) -> Option<&SecureValue> {
+        match typ {
+            EncryptedPassportElementType::PersonalDetails => self.personal_details.as_ref(),
+            EncryptedPassportElementType::Passport => self.passport.as_ref(),
+            EncryptedPassportElementType::InternalPassport => self.internal_passport.as_ref(),
+            EncryptedPassportElementType::DriverLicense => self.driver_license.as_ref(),
+            EncryptedPassportElementType::IdentityCard => self.identity_card.as_ref(),
+            EncryptedPassportElementType::Address => self.address.as_ref(),
+            EncryptedPassportElementType::UtilityBill => self.utility_bill.as_ref(),
+            EncryptedPassportElementType::BankStatement => self.bank_statement.as_ref(),
+            EncryptedPassportElementType::RentalAgreement => self.rental_agreement.as_ref(),
+            EncryptedPassportElementType::PassportRegistration => {
+                self.passport_registration.as_ref()
+            }
+            EncryptedPassportElementType::TemporaryRegistration => {
+                self.temporary_registration.as_ref()
+            }
+            EncryptedPassportElementType::PhoneNumber | EncryptedPassportElementType::Email => None,
+        }
+    }
+}
+
+/// Credentials for the data and files of a single Telegram Passport element
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SecureValue {
+    /// Credentials for encrypted Telegram Passport data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<DataCredentials>,
+
+    /// Credentials for encrypted document's front side
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_side: Option<FileCredentials>,
+
+    /// Credentials for encrypted document's reverse side
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_side: Option<FileCredentials>,
+
+    /// Credentials for encrypted selfie of the user with a document
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<FileCredentials>,
+
+    /// Credentials for encrypted translation of the document, in the same order as `EncryptedPassportElement::translation`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation: Option<Vec<FileCredentials>>,
+
+    /// Credentials for encrypted files, in the same order as `EncryptedPassportElement::files`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<FileCredentials>>,
+}
+
+/// Can be used to decrypt and authenticate `EncryptedPassportElement::data`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DataCredentials {
+    /// Checksum of encrypted data
+    pub data_hash: String,
+
+    /// Secret of encrypted data
+    pub secret: String,
+}
+
+impl DataCredentials {
+    /// Decrypt base64-encoded element data into `PersonalDetails`, `ResidentialAddress` or `IdDocumentData`
+    pub fn decrypt<T: DeserializeOwned>(&self, data: &str) -> Result<T, Error> {
+        let data = decrypt(
+            &decode_base64(data)?,
+            &decode_base64(&self.secret)?,
+            &decode_base64(&self.data_hash)?,
+        )?;
+        serde_json::from_slice(&data).map_err(Error::Serde)
+    }
+}
+
+/// Can be used to decrypt and authenticate the content of a downloaded `PassportFile`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FileCredentials {
+    /// Checksum of encrypted file
+    pub file_hash: String,
+
+    /// Secret of encrypted file
+    pub secret: String,
+}
+
+impl FileCredentials {
+    /// Decrypt content of the downloaded file. The result is a JPEG image
+    pub fn decrypt(&self, content: &[u8]) -> Result<Vec<u8>, Error> {
+        decrypt(
+            content,
+            &decode_base64(&self.secret)?,
+            &decode_base64(&self.file_hash)?,
+        )
+    }
+}
+
+/// Personal details of the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PersonalDetails {
+    /// First Name
+    pub first_name: String,
+
+    /// Last Name
+    pub last_name: String,
+
+    /// Middle Name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name: Option<String>,
+
+    /// Date of birth in DD.MM.YYYY format
+    pub birth_date: String,
+
+    /// Gender, male or female
+    pub gender: String,
+
+    /// Citizenship (ISO 3166-1 alpha-2 country code)
+    pub country_code: String,
+
+    /// Country of residence (ISO 3166-1 alpha-2 country code)
+    pub residence_country_code: String,
+
+    /// First Name in the language of the user's country of residence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name_native: Option<String>,
+
+    /// Last Name in the language of the user's country of residence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name_native: Option<String>,
+
+    /// Middle Name in the language of the user's country of residence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub middle_name_native: Option<String>,
+}
+
+/// Residential address of the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResidentialAddress {
+    /// First line for the address
+    pub street_line1: String,
+
+    /// Second line for the address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_line2: Option<String>,
+
+    /// City
+    pub city: String,
+
+    /// State
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: String,
+
+    /// Address post code
+    pub post_code: String,
+}
+
+/// Data of an identity document of the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IdDocumentData {
+    /// Document number
+    pub document_no: String,
+
+    /// Date of expiry, in DD.MM.YYYY format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry_date: Option<String>,
+}
+
+fn decode_base64(data: &str) -> Result<Vec<u8>, Error> {
+    STANDARD
+        .decode(data)
+        .map_err(|x| Error::Passport(format!("invalid base64: {}", x)))
+}
+
+fn decrypt(data: &[u8], secret: &[u8], hash: &[u8]) -> Result<Vec<u8>, Error> {
+    // Data always starts with the length of its padding, so it can't be empty
+    if data.is_empty() {
+        return Err(Error::Passport("encrypted data is empty".into()));
+    }
+    let secret_hash = Sha512::new()
+        .chain_update(secret)
+        .chain_update(hash)
+        .finalize();
+    let mut buf = data.to_vec();
+    let decrypted =
+        cbc::Decryptor::<Aes256>::new_from_slices(&secret_hash[..32], &secret_hash[32..48])
+            .map_err(|x| Error::Passport(format!("invalid secret: {}", x)))?
+            .decrypt_padded_mut::<NoPadding>(&mut buf)
+            .map_err(|_| {
+                Error::Passport("encrypted data is not aligned to the block size".into())
+            })?;
+    if Sha256::digest(decrypted).as_slice() != hash {
+        return Err(Error::Passport("data hash mismatch".into()));
+    }
+    let padding = decrypted[0] as usize;
+    if padding == 0 || padding > decrypted.len() {
+        return Err(Error::Passport("invalid padding".into()));
+    }
+    Ok(decrypted[padding..].to_vec())
+}
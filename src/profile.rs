@@ -0,0 +1,332 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::Rutebot,
+    error::Error,
+    requests::{
+        BotCommand, BotCommandScope, DeleteMyCommands, GetChatMenuButton, GetMyCommands,
+        GetMyDefaultAdministratorRights, GetMyDescription, GetMyName, GetMyShortDescription,
+        MenuButton, SetChatMenuButton, SetMyCommands, SetMyDefaultAdministratorRights,
+        SetMyDescription, SetMyName, SetMyShortDescription,
+    },
+    responses::ChatAdministratorRights,
+};
+
+/// Desired state of the bot's profile for a single language. Can be loaded from a config file,
+/// fields which are not set are left untouched by [`BotProfile::sync`].
+///
+/// ## Example
+/// ```no_run
+/// # use rutebot::client::Rutebot;
+/// # use rutebot::profile::{BotProfile, ScopedCommands};
+/// # use rutebot::requests::{BotCommand, BotCommandScope};
+/// # async fn run(rutebot: Rutebot) -> Result<(), rutebot::error::Error> {
+/// let profile = BotProfile {
+///     language_code: Some("de".into()),
+///     description: Some("Ich verwalte deine Gruppe".into()),
+///     commands: vec![
+///         ScopedCommands::new(BotCommandScope::AllPrivateChats, vec![BotCommand::new("start", "Starten")]),
+///         ScopedCommands::new(
+///             BotCommandScope::AllChatAdministrators,
+///             vec![BotCommand::new("ban", "Benutzer sperren")],
+///         ),
+///     ],
+///     ..BotProfile::default()
+/// };
+/// let changes = profile.sync(&rutebot).await?;
+/// println!("{:?}", changes);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BotProfile<'a> {
+    /// A two-letter ISO 639-1 language code the profile is applied for.
+    /// If empty, the profile is applied to all users for whose language there is no dedicated profile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+
+    /// Bot name; pass an empty string to remove the dedicated name for the language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Cow<'a, str>>,
+
+    /// Bot description; pass an empty string to remove the dedicated description for the language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// Bot short description; pass an empty string to remove the dedicated short description for the language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<Cow<'a, str>>,
+
+    /// Commands for each scope. An empty list of commands deletes commands of the scope
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<ScopedCommands<'a>>,
+
+    /// Default menu button of the bot. Doesn't depend on the language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub menu_button: Option<MenuButton<'a>>,
+
+    /// Default administrator rights requested in groups and supergroups. Doesn't depend on the language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_administrator_rights: Option<ChatAdministratorRights>,
+
+    /// Default administrator rights requested in channels. Doesn't depend on the language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_administrator_rights: Option<ChatAdministratorRights>,
+}
+
+/// Commands of the bot in the given scope
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScopedCommands<'a> {
+    /// Scope of users for which the commands are relevant
+    pub scope: BotCommandScope<'a>,
+
+    /// List of the commands
+    pub commands: Vec<BotCommand<'a>>,
+}
+
+impl<'a> ScopedCommands<'a> {
+    pub fn new(scope: BotCommandScope<'a>, commands: Vec<BotCommand<'a>>) -> Self {
+        Self { scope, commands }
+    }
+}
+
+/// Parts of the profile which were updated by [`BotProfile::sync`] or differ according to [`BotProfile::changes`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProfileChanges {
+    /// Bot name for the language was changed
+    pub name: bool,
+
+    /// Bot description for the language was changed
+    pub description: bool,
+
+    /// Bot short description for the language was changed
+    pub short_description: bool,
+
+    /// Number of scopes whose commands were updated
+    pub commands: usize,
+
+    /// Default menu button was changed
+    pub menu_button: bool,
+
+    /// Default administrator rights in groups and supergroups were changed
+    pub group_administrator_rights: bool,
+
+    /// Default administrator rights in channels were changed
+    pub channel_administrator_rights: bool,
+}
+
+impl<'a> BotProfile<'a> {
+    /// Compare the profile with the current one and send updates only for the fields which differ
+    pub async fn sync(&self, rutebot: &Rutebot) -> Result<ProfileChanges, Error> {
+        let current = self.load_current(rutebot).await?;
+        let changes = self.changes(&current);
+        let language_code = &self.language_code;
+
+        if let (Some(name), true) = (&self.name, changes.name) {
+            rutebot
+                .prepare_api_request(SetMyName {
+                    name: Some(name.clone()),
+                    language_code: language_code.clone(),
+                })
+                .send()
+                .await?;
+        }
+
+        if let (Some(description), true) = (&self.description, changes.description) {
+            rutebot
+                .prepare_api_request(SetMyDescription {
+                    description: Some(description.clone()),
+                    language_code: language_code.clone(),
+                })
+                .send()
+                .await?;
+        }
+
+        if let (Some(short_description), true) =
+            (&self.short_description, changes.short_description)
+        {
+            rutebot
+                .prepare_api_request(SetMyShortDescription {
+                    short_description: Some(short_description.clone()),
+                    language_code: language_code.clone(),
+                })
+                .send()
+                .await?;
+        }
+
+        for ScopedCommands { scope, commands } in self.changed_commands(&current) {
+            if commands.is_empty() {
+                rutebot
+                    .prepare_api_request(DeleteMyCommands::new_scoped(
+                        scope.clone(),
+                        language_code.clone(),
+                    ))
+                    .send()
+                    .await?;
+            } else {
+                rutebot
+                    .prepare_api_request(SetMyCommands::new_scoped(
+                        &commands[..],
+                        scope.clone(),
+                        language_code.clone(),
+                    ))
+                    .send()
+                    .await?;
+            }
+        }
+
+        if let (Some(menu_button), true) = (&self.menu_button, changes.menu_button) {
+            rutebot
+                .prepare_api_request(SetChatMenuButton::new(menu_button.clone()))
+                .send()
+                .await?;
+        }
+
+        let rights = [
+            (
+                &self.group_administrator_rights,
+                changes.group_administrator_rights,
+                false,
+            ),
+            (
+                &self.channel_administrator_rights,
+                changes.channel_administrator_rights,
+                true,
+            ),
+        ];
+        for (rights, changed, for_channels) in rights {
+            if let (Some(rights), true) = (rights, changed) {
+                rutebot
+                    .prepare_api_request(SetMyDefaultAdministratorRights::new(
+                        rights.clone(),
+                        for_channels,
+                    ))
+                    .send()
+                    .await?;
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Parts of the profile which differ from `current`. Only the fields which are set in this
+    /// profile are compared. Fields which are not set in `current` are treated as empty,
+    /// scopes missing in `current` as scopes without commands and absent channel- or group-only
+    /// administrator rights as not granted
+    pub fn changes(&self, current: &BotProfile<'a>) -> ProfileChanges {
+        let text_differs = |desired: &Option<Cow<'a, str>>, current: &Option<Cow<'a, str>>| {
+            desired
+                .as_deref()
+                .is_some_and(|x| x != current.as_deref().unwrap_or_default())
+        };
+        let rights_differ =
+            |desired: &Option<ChatAdministratorRights>,
+             current: &Option<ChatAdministratorRights>| {
+                desired.as_ref().is_some_and(|x| {
+                    normalize_rights(x) != normalize_rights(&current.clone().unwrap_or_default())
+                })
+            };
+        ProfileChanges {
+            name: text_differs(&self.name, &current.name),
+            description: text_differs(&self.description, &current.description),
+            short_description: text_differs(&self.short_description, &current.short_description),
+            commands: self.changed_commands(current).count(),
+            menu_button: self.menu_button.as_ref().is_some_and(|x| {
+                *x != *current.menu_button.as_ref().unwrap_or(&MenuButton::Default)
+            }),
+            group_administrator_rights: rights_differ(
+                &self.group_administrator_rights,
+                &current.group_administrator_rights,
+            ),
+            channel_administrator_rights: rights_differ(
+                &self.channel_administrator_rights,
+                &current.channel_administrator_rights,
+            ),
+        }
+    }
+
+    fn changed_commands<'b>(
+        &'b self,
+        current: &'b BotProfile<'a>,
+    ) -> impl Iterator<Item = &'b ScopedCommands<'a>> {
+        self.commands.iter().filter(move |desired| {
+            let current = current
+                .commands
+                .iter()
+                .find(|x| x.scope == desired.scope)
+                .map_or(&[][..], |x| &x.commands[..]);
+            current != &desired.commands[..]
+        })
+    }
+
+    /// Load current values of the fields which are set in this profile
+    async fn load_current(&self, rutebot: &Rutebot) -> Result<BotProfile<'a>, Error> {
+        let language_code = &self.language_code;
+        let mut current = BotProfile::default();
+
+        if self.name.is_some() {
+            let request = GetMyName {
+                language_code: language_code.clone(),
+            };
+            let name = rutebot.prepare_api_request(request).send().await?.name;
+            current.name = Some(name.into());
+        }
+
+        if self.description.is_some() {
+            let request = GetMyDescription {
+                language_code: language_code.clone(),
+            };
+            let description = rutebot.prepare_api_request(request).send().await?;
+            current.description = Some(description.description.into());
+        }
+
+        if self.short_description.is_some() {
+            let request = GetMyShortDescription {
+                language_code: language_code.clone(),
+            };
+            let short_description = rutebot.prepare_api_request(request).send().await?;
+            current.short_description = Some(short_description.short_description.into());
+        }
+
+        for ScopedCommands { scope, .. } in &self.commands {
+            let request = GetMyCommands::new_scoped(scope.clone(), language_code.clone());
+            let commands = rutebot.prepare_api_request(request).send().await?;
+            current
+                .commands
+                .push(ScopedCommands::new(scope.clone(), commands));
+        }
+
+        if self.menu_button.is_some() {
+            let request = GetChatMenuButton::new();
+            current.menu_button = Some(rutebot.prepare_api_request(request).send().await?);
+        }
+
+        if self.group_administrator_rights.is_some() {
+            let request = GetMyDefaultAdministratorRights::new(false);
+            current.group_administrator_rights =
+                Some(rutebot.prepare_api_request(request).send().await?);
+        }
+
+        if self.channel_administrator_rights.is_some() {
+            let request = GetMyDefaultAdministratorRights::new(true);
+            current.channel_administrator_rights =
+                Some(rutebot.prepare_api_request(request).send().await?);
+        }
+
+        Ok(current)
+    }
+}
+
+/// Telegram omits rights which are not applicable to the chat type, they are not granted either
+fn normalize_rights(rights: &ChatAdministratorRights) -> ChatAdministratorRights {
+    let granted = |x: Option<bool>| Some(x.unwrap_or(false));
+    ChatAdministratorRights {
+        can_post_messages: granted(rights.can_post_messages),
+        can_edit_messages: granted(rights.can_edit_messages),
+        can_pin_messages: granted(rights.can_pin_messages),
+        can_manage_topics: granted(rights.can_manage_topics),
+        ..rights.clone()
+    }
+}
@@ -1,106 +1,209 @@
-use std::ops::Not;
+use std::{borrow::Cow, ops::Not};
 
 use hyper::Body;
 use hyper_multipart_rfc7578::client::{multipart, multipart::Form};
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
+pub use add_sticker_to_set::*;
 pub use answer_callback_query::*;
+pub use answer_inline_query::*;
+pub use answer_pre_checkout_query::*;
+pub use answer_shipping_query::*;
+pub use approve_chat_join_request::*;
+pub use create_chat_invite_link::*;
+pub use create_chat_subscription_invite_link::*;
+pub use create_invoice_link::*;
+pub use create_new_sticker_set::*;
+pub use decline_chat_join_request::*;
 pub use delete_chat_photo::*;
 pub use delete_chat_sticker_set::*;
 pub use delete_message::*;
+pub use delete_my_commands::*;
+pub use delete_sticker_from_set::*;
+pub use delete_sticker_set::*;
+pub use edit_chat_invite_link::*;
+pub use edit_chat_subscription_invite_link::*;
 pub use edit_live_location::*;
 pub use edit_message_caption::*;
 pub use edit_message_media::*;
 pub use edit_message_reply_markup::*;
 pub use edit_message_text::*;
+pub use edit_user_star_subscription::*;
 pub use export_chat_invite_link::*;
 pub use forward_message::*;
 pub use get_chat::*;
 pub use get_chat_administrators::*;
 pub use get_chat_member::*;
 pub use get_chat_members_count::*;
+pub use get_chat_menu_button::*;
+pub use get_custom_emoji_stickers::*;
 pub use get_file::*;
+pub use get_game_high_scores::*;
 pub use get_me::*;
+pub use get_my_commands::*;
+pub use get_my_default_administrator_rights::*;
+pub use get_my_description::*;
+pub use get_my_name::*;
+pub use get_my_short_description::*;
+pub use get_my_star_balance::*;
+pub use get_star_transactions::*;
+pub use get_sticker_set::*;
 pub use get_updates::*;
 pub use get_user_profile_photos::*;
 pub use kick_chat_member::*;
 pub use leave_chat::*;
 pub use pin_chat_message::*;
 pub use promote_chat_member::*;
+pub use refund_star_payment::*;
+pub use replace_sticker_in_set::*;
 pub use restrict_chat_member::*;
+pub use revoke_chat_invite_link::*;
 pub use send_animation::*;
 pub use send_audio::*;
 pub use send_chat_action::*;
 pub use send_contact::*;
 pub use send_document::*;
+pub use send_game::*;
+pub use send_invoice::*;
 pub use send_location::*;
 pub use send_media_group::*;
 pub use send_message::*;
 pub use send_photo::*;
 pub use send_poll::*;
+pub use send_sticker::*;
 pub use send_venue::*;
 pub use send_video::*;
 pub use send_video_note::*;
 pub use send_voice::*;
 pub use set_chat_description::*;
+pub use set_chat_menu_button::*;
 pub use set_chat_photo::*;
 pub use set_chat_sticker_set::*;
 pub use set_chat_title::*;
+pub use set_custom_emoji_sticker_set_thumbnail::*;
+pub use set_game_score::*;
+pub use set_my_commands::*;
+pub use set_my_default_administrator_rights::*;
+pub use set_my_description::*;
+pub use set_my_name::*;
+pub use set_my_short_description::*;
+pub use set_passport_data_errors::*;
+pub use set_sticker_emoji_list::*;
+pub use set_sticker_keywords::*;
+pub use set_sticker_mask_position::*;
+pub use set_sticker_position_in_set::*;
+pub use set_sticker_set_thumbnail::*;
+pub use set_sticker_set_title::*;
 pub use stop_live_location::*;
 pub use stop_poll::*;
 pub use unban_chat_member::*;
 pub use unpin_chat_message::*;
+pub use upload_sticker_file::*;
 
 use crate::error::Error;
+use crate::formatting::{FormattedText, Markup};
+use crate::responses::{
+    CallbackGame, ChatAdministratorRights, CopyTextButton, LoginUrl, MaskPosition, MessageEntity,
+    SwitchInlineQueryChosenChat, WebAppInfo,
+};
 use std::io::Cursor;
 
+mod add_sticker_to_set;
 mod answer_callback_query;
+mod answer_inline_query;
+mod answer_pre_checkout_query;
+mod answer_shipping_query;
+mod approve_chat_join_request;
+mod create_chat_invite_link;
+mod create_chat_subscription_invite_link;
+mod create_invoice_link;
+mod create_new_sticker_set;
+mod decline_chat_join_request;
 mod delete_chat_photo;
 mod delete_chat_sticker_set;
 mod delete_message;
+mod delete_my_commands;
+mod delete_sticker_from_set;
+mod delete_sticker_set;
+mod edit_chat_invite_link;
+mod edit_chat_subscription_invite_link;
 mod edit_live_location;
 mod edit_message_caption;
 mod edit_message_media;
 mod edit_message_reply_markup;
 mod edit_message_text;
+mod edit_user_star_subscription;
 mod export_chat_invite_link;
 mod forward_message;
 mod get_chat;
 mod get_chat_administrators;
 mod get_chat_member;
 mod get_chat_members_count;
+mod get_chat_menu_button;
+mod get_custom_emoji_stickers;
 mod get_file;
+mod get_game_high_scores;
 mod get_me;
+mod get_my_commands;
+mod get_my_default_administrator_rights;
+mod get_my_description;
+mod get_my_name;
+mod get_my_short_description;
+mod get_my_star_balance;
+mod get_star_transactions;
+mod get_sticker_set;
 mod get_updates;
 mod get_user_profile_photos;
 mod kick_chat_member;
 mod leave_chat;
 mod pin_chat_message;
 mod promote_chat_member;
+mod refund_star_payment;
+mod replace_sticker_in_set;
 mod restrict_chat_member;
+mod revoke_chat_invite_link;
 mod send_animation;
 mod send_audio;
 mod send_chat_action;
 mod send_contact;
 mod send_document;
+mod send_game;
+mod send_invoice;
 mod send_location;
 mod send_media_group;
 mod send_message;
 mod send_photo;
 mod send_poll;
+mod send_sticker;
 mod send_venue;
 mod send_video;
 mod send_video_note;
 mod send_voice;
 mod set_chat_description;
+mod set_chat_menu_button;
 mod set_chat_photo;
 mod set_chat_sticker_set;
 mod set_chat_title;
+mod set_custom_emoji_sticker_set_thumbnail;
+mod set_game_score;
+mod set_my_commands;
+mod set_my_default_administrator_rights;
+mod set_my_description;
+mod set_my_name;
+mod set_my_short_description;
+mod set_passport_data_errors;
+mod set_sticker_emoji_list;
+mod set_sticker_keywords;
+mod set_sticker_mask_position;
+mod set_sticker_position_in_set;
+mod set_sticker_set_thumbnail;
+mod set_sticker_set_title;
 mod stop_live_location;
 mod stop_poll;
 mod unban_chat_member;
 mod unpin_chat_message;
+mod upload_sticker_file;
 
 /// Basic request type.
 pub trait Request: Serialize + Sized {
@@ -142,7 +245,11 @@ pub(crate) fn add_file_to_form(form: &mut Form, file: FileKind, upload_type: Opt
         thumb,
     } = file
     {
-        form.add_reader_file(upload_type.unwrap_or(name), Cursor::new(content), name);
+        form.add_reader_file(
+            upload_type.unwrap_or(&name),
+            Cursor::new(content),
+            name.as_ref(),
+        );
         if let Some(thumb) = thumb {
             let thumb_name = format!("thumb_{}", name);
             form.add_reader_file(&thumb_name, Cursor::new(thumb), thumb_name.as_str());
@@ -159,6 +266,8 @@ pub(crate) fn add_fields_to_form<S: Serialize + Sized>(
     if let Value::Object(map) = json {
         for (k, v) in map {
             match v {
+                // Uploaded files are added to the form by `add_file_to_form` under the field name
+                Value::String(s) if s.starts_with("attach://") => (),
                 Value::String(s) => form.add_text(k, s),
                 other => form.add_text(k, other.to_string()),
             }
@@ -167,22 +276,26 @@ pub(crate) fn add_fields_to_form<S: Serialize + Sized>(
     Ok(())
 }
 
-/// File to send
+/// File to send.
+///
+/// `InputFile` is serialized as `attach://<name>`. When deserialized, `attach://<name>` becomes
+/// `InputFile` with empty content and no thumbnail, since the content of uploaded files is sent
+/// apart from json; `http://` and `https://` urls become `Url`.
 #[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum FileKind<'a> {
     /// Identifier of file on the telegram servers
-    FileId(&'a str),
+    FileId(Cow<'a, str>),
 
     /// Http url for the file to be sent. Telegram will download and send the file.
     /// 5 MB max size for photos and 20 MB max for other types of content
-    Url(&'a str),
+    Url(Cow<'a, str>),
 
     /// Arbitrary file to be uploaded
     #[serde(serialize_with = "FileKind::serialize_attach")]
     InputFile {
         /// Name of the file
-        name: &'a str,
+        name: Cow<'a, str>,
 
         /// File content
         content: Vec<u8>,
@@ -208,14 +321,31 @@ impl<'a> FileKind<'a> {
     }
 }
 
+impl<'a, 'de> Deserialize<'de> for FileKind<'a> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(if let Some(name) = value.strip_prefix("attach://") {
+            FileKind::InputFile {
+                name: Cow::Owned(name.to_string()),
+                content: Vec::new(),
+                thumb: None,
+            }
+        } else if value.starts_with("http://") || value.starts_with("https://") {
+            FileKind::Url(Cow::Owned(value))
+        } else {
+            FileKind::FileId(Cow::Owned(value))
+        })
+    }
+}
+
 /// Unique identifier for the target group or username of the target supergroup or channel (in the format @channelusername)
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum ChatId<'a> {
     /// Unique identifier for the target group
     Id(i64),
     /// Username of the target supergroup or channel (in the format @channelusername)
-    Username(&'a str),
+    Username(Cow<'a, str>),
 }
 
 impl<'a> From<i64> for ChatId<'a> {
@@ -226,25 +356,35 @@ impl<'a> From<i64> for ChatId<'a> {
 
 impl<'a> From<&'a str> for ChatId<'a> {
     fn from(x: &'a str) -> Self {
-        ChatId::Username(x)
+        ChatId::Username(Cow::Borrowed(x))
+    }
+}
+
+impl<'a> From<String> for ChatId<'a> {
+    fn from(x: String) -> Self {
+        ChatId::Username(Cow::Owned(x))
     }
 }
 
 /// Represents a photo to be sent.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InputMediaPhoto<'a> {
     /// File to send
     pub media: FileKind<'a>,
 
     /// Caption of the photo to be sent, 0-1024 characters
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<&'a str>,
+    pub caption: Option<Cow<'a, str>>,
 
-    /// Send `ParseMode::Markdown` or `ParseMode::Html`,
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
     /// if you want Telegram apps to show
     /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
 }
 
 impl<'a> InputMediaPhoto<'a> {
@@ -253,26 +393,53 @@ impl<'a> InputMediaPhoto<'a> {
             media,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
+        }
+    }
+
+    /// Set caption built by `formatting::MarkupBuilder`
+    pub fn with_caption_markup(self, markup: impl Into<Cow<'a, Markup>>) -> Self {
+        let (caption, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: Some(parse_mode),
+            caption_entities: None,
+            ..self
+        }
+    }
+
+    /// Set caption built with `formatting::FormattedText`
+    pub fn with_formatted_caption(self, formatted: impl Into<Cow<'a, FormattedText>>) -> Self {
+        let (caption, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: None,
+            caption_entities: Some(entities),
+            ..self
         }
     }
 }
 
 /// Represents a video to be sent.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InputMediaVideo<'a> {
     /// File to send
     pub media: FileKind<'a>,
 
     /// Caption of the photo to be sent, 0-1024 characters
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<&'a str>,
+    pub caption: Option<Cow<'a, str>>,
 
-    /// Send `ParseMode::Markdown` or `ParseMode::Html`,
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
     /// if you want Telegram apps to show
     /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
 
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
     /// Duration of sent video in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i64>,
@@ -286,7 +453,7 @@ pub struct InputMediaVideo<'a> {
     pub height: Option<i64>,
 
     /// Pass True, if the uploaded video is suitable for streaming.
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub supports_streaming: bool,
 }
 
@@ -296,30 +463,57 @@ impl<'a> InputMediaVideo<'a> {
             media,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             duration: None,
             width: None,
             height: None,
             supports_streaming: false,
         }
     }
+
+    /// Set caption built by `formatting::MarkupBuilder`
+    pub fn with_caption_markup(self, markup: impl Into<Cow<'a, Markup>>) -> Self {
+        let (caption, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: Some(parse_mode),
+            caption_entities: None,
+            ..self
+        }
+    }
+
+    /// Set caption built with `formatting::FormattedText`
+    pub fn with_formatted_caption(self, formatted: impl Into<Cow<'a, FormattedText>>) -> Self {
+        let (caption, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: None,
+            caption_entities: Some(entities),
+            ..self
+        }
+    }
 }
 
 /// Represents an animation file (GIF or H.264/MPEG-4 AVC video without sound) to be sent.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InputMediaAnimation<'a> {
     /// File to send
     pub media: FileKind<'a>,
 
     /// Caption of the animation to be sent, 0-1024 characters
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<&'a str>,
+    pub caption: Option<Cow<'a, str>>,
 
-    /// Send `ParseMode::Markdown` or `ParseMode::Html`,
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
     /// if you want Telegram apps to show
     /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
 
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
     /// Animation duration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i64>,
@@ -339,28 +533,55 @@ impl<'a> InputMediaAnimation<'a> {
             media,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             duration: None,
             width: None,
             height: None,
         }
     }
+
+    /// Set caption built by `formatting::MarkupBuilder`
+    pub fn with_caption_markup(self, markup: impl Into<Cow<'a, Markup>>) -> Self {
+        let (caption, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: Some(parse_mode),
+            caption_entities: None,
+            ..self
+        }
+    }
+
+    /// Set caption built with `formatting::FormattedText`
+    pub fn with_formatted_caption(self, formatted: impl Into<Cow<'a, FormattedText>>) -> Self {
+        let (caption, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: None,
+            caption_entities: Some(entities),
+            ..self
+        }
+    }
 }
 
 /// Represents a general file to be sent.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InputMediaDocument<'a> {
     /// File to send
     pub media: FileKind<'a>,
 
     /// Caption of the document to be sent, 0-1024 characters
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<&'a str>,
+    pub caption: Option<Cow<'a, str>>,
 
-    /// Send `ParseMode::Markdown` or `ParseMode::Html`,
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
     /// if you want Telegram apps to show
     /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
 }
 
 impl<'a> InputMediaDocument<'a> {
@@ -369,42 +590,69 @@ impl<'a> InputMediaDocument<'a> {
             media,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
+        }
+    }
+
+    /// Set caption built by `formatting::MarkupBuilder`
+    pub fn with_caption_markup(self, markup: impl Into<Cow<'a, Markup>>) -> Self {
+        let (caption, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: Some(parse_mode),
+            caption_entities: None,
+            ..self
+        }
+    }
+
+    /// Set caption built with `formatting::FormattedText`
+    pub fn with_formatted_caption(self, formatted: impl Into<Cow<'a, FormattedText>>) -> Self {
+        let (caption, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: None,
+            caption_entities: Some(entities),
+            ..self
         }
     }
 }
 
 /// Represents an audio file to be treated as music to be sent.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InputMediaAudio<'a> {
     /// File to send
     pub media: FileKind<'a>,
 
     /// Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side.
     /// The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail‘s width and height should not exceed 320
-    #[serde(skip_serializing)]
+    #[serde(skip)]
     pub thumb: Option<Vec<u8>>,
 
     /// Caption of the audio to be sent, 0-1024 characters
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<&'a str>,
+    pub caption: Option<Cow<'a, str>>,
 
-    /// Send `ParseMode::Markdown` or `ParseMode::Html`,
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
     /// if you want Telegram apps to show
     /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
 
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
     /// Duration of the audio in seconds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i64>,
 
     /// Performer of the audio
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub performer: Option<&'a str>,
+    pub performer: Option<Cow<'a, str>>,
 
     /// Title of the audio
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<&'a str>,
+    pub title: Option<Cow<'a, str>>,
 }
 
 impl<'a> InputMediaAudio<'a> {
@@ -414,14 +662,37 @@ impl<'a> InputMediaAudio<'a> {
             thumb: None,
             caption: None,
             parse_mode: None,
+            caption_entities: None,
             duration: None,
             performer: None,
             title: None,
         }
     }
+
+    /// Set caption built by `formatting::MarkupBuilder`
+    pub fn with_caption_markup(self, markup: impl Into<Cow<'a, Markup>>) -> Self {
+        let (caption, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: Some(parse_mode),
+            caption_entities: None,
+            ..self
+        }
+    }
+
+    /// Set caption built with `formatting::FormattedText`
+    pub fn with_formatted_caption(self, formatted: impl Into<Cow<'a, FormattedText>>) -> Self {
+        let (caption, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: None,
+            caption_entities: Some(entities),
+            ..self
+        }
+    }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
 pub enum InputMedia<'a> {
     #[serde(rename = "video")]
@@ -463,7 +734,7 @@ impl<'a> InputMedia<'a> {
 }
 
 /// Additional interface options
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum ReplyMarkup<'a> {
     InlineKeyboard(InlineKeyboard<'a>),
@@ -472,31 +743,137 @@ pub enum ReplyMarkup<'a> {
     ForceReply(ForceReply),
 }
 
+impl<'a> From<InlineKeyboard<'a>> for ReplyMarkup<'a> {
+    fn from(x: InlineKeyboard<'a>) -> Self {
+        ReplyMarkup::InlineKeyboard(x)
+    }
+}
+
+impl<'a> From<ReplyKeyboardMarkup<'a>> for ReplyMarkup<'a> {
+    fn from(x: ReplyKeyboardMarkup<'a>) -> Self {
+        ReplyMarkup::ReplyKeyboardMarkup(x)
+    }
+}
+
+impl<'a> From<ReplyKeyboardRemove> for ReplyMarkup<'a> {
+    fn from(x: ReplyKeyboardRemove) -> Self {
+        ReplyMarkup::ReplyKeyboardRemove(x)
+    }
+}
+
+impl<'a> From<ForceReply> for ReplyMarkup<'a> {
+    fn from(x: ForceReply) -> Self {
+        ReplyMarkup::ForceReply(x)
+    }
+}
+
 /// This object represents an [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating)
-/// that appears right next to the message it belongs to
-#[derive(Serialize, Debug, Clone)]
+/// that appears right next to the message it belongs to.
+///
+/// Rows can be either borrowed or built dynamically:
+/// ```
+/// # use rutebot::requests::{InlineKeyboard, InlineKeyboardButton, ReplyMarkup};
+/// let button = |text: &'static str| InlineKeyboardButton::CallbackData {
+///     text: text.into(),
+///     callback_data: text.into(),
+/// };
+/// let keyboard = InlineKeyboard::grid(["1", "2", "3", "4", "5"].map(button), 2)
+///     .row()
+///     .button(InlineKeyboardButton::Url {
+///         text: "Help".into(),
+///         url: format!("https://example.com/{}", "help").into(),
+///     });
+///
+/// assert_eq!(keyboard.inline_keyboard.len(), 4);
+/// let reply_markup: ReplyMarkup = keyboard.into();
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct InlineKeyboard<'a> {
     /// Array of button rows, each represented by an Array of `InlineKeyboardButton` objects
-    pub inline_keyboard: &'a [Vec<InlineKeyboardButton<'a>>],
+    pub inline_keyboard: Cow<'a, [Vec<InlineKeyboardButton<'a>>]>,
 }
 
-/// This object represents a custom keyboard with reply options.
-#[derive(Serialize, Debug, Clone)]
+impl<'a> InlineKeyboard<'a> {
+    /// Empty keyboard
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keyboard with `buttons` laid out in rows of `columns` buttons
+    pub fn grid(
+        buttons: impl IntoIterator<Item = InlineKeyboardButton<'a>>,
+        columns: usize,
+    ) -> Self {
+        Self {
+            inline_keyboard: grid(buttons, columns).into(),
+        }
+    }
+
+    /// Start a new row. Buttons added after it are placed in the new row
+    pub fn row(mut self) -> Self {
+        self.inline_keyboard.to_mut().push(Vec::new());
+        self
+    }
+
+    /// Add button to the last row
+    pub fn button(mut self, button: InlineKeyboardButton<'a>) -> Self {
+        push_button(self.inline_keyboard.to_mut(), button);
+        self
+    }
+
+    /// Add rows of another keyboard after the rows of this one
+    pub fn append(mut self, other: InlineKeyboard<'a>) -> Self {
+        self.inline_keyboard
+            .to_mut()
+            .extend(other.inline_keyboard.into_owned());
+        self
+    }
+
+    /// Remove row of buttons. Returns `None` if there is no such row
+    pub fn remove_row(&mut self, row: usize) -> Option<Vec<InlineKeyboardButton<'a>>> {
+        remove_row(&mut self.inline_keyboard, row)
+    }
+
+    /// Remove button, the row is removed too if it becomes empty.
+    /// Returns `None` if there is no such button
+    pub fn remove_button(&mut self, row: usize, column: usize) -> Option<InlineKeyboardButton<'a>> {
+        remove_button(&mut self.inline_keyboard, row, column)
+    }
+}
+
+impl<'a> From<&'a [Vec<InlineKeyboardButton<'a>>]> for InlineKeyboard<'a> {
+    fn from(x: &'a [Vec<InlineKeyboardButton<'a>>]) -> Self {
+        Self {
+            inline_keyboard: Cow::Borrowed(x),
+        }
+    }
+}
+
+impl<'a> From<Vec<Vec<InlineKeyboardButton<'a>>>> for InlineKeyboard<'a> {
+    fn from(x: Vec<Vec<InlineKeyboardButton<'a>>>) -> Self {
+        Self {
+            inline_keyboard: Cow::Owned(x),
+        }
+    }
+}
+
+/// This object represents a custom keyboard with reply options. Built the same way as `InlineKeyboard`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct ReplyKeyboardMarkup<'a> {
     /// Array of button rows, each represented by an Array of `KeyboardButton` objects
-    pub keyboard: &'a [Vec<KeyboardButton<'a>>],
+    pub keyboard: Cow<'a, [Vec<KeyboardButton<'a>>]>,
 
     /// Requests clients to resize the keyboard vertically for optimal fit
     /// (e.g., make the keyboard smaller if there are just two rows of buttons).
     /// Defaults to false, in which case the custom keyboard is always of the same height as the app's standard keyboard
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub resize_keyboard: bool,
 
     /// Requests clients to hide the keyboard as soon as it's been used.
     /// The keyboard will still be available, but clients will automatically display the usual
     /// letter-keyboard in the chat – the user can press a special button in the input field to
     /// see the custom keyboard again. Defaults to false
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub one_time_keyboard: bool,
     /// Use this parameter if you want to show the keyboard to specific users only.
     /// Targets: 1) users that are @mentioned in the text of the Message object; 2)
@@ -504,15 +881,125 @@ pub struct ReplyKeyboardMarkup<'a> {
 
     /// Example: A user requests to change the bot‘s language, bot replies to the request with a
     /// keyboard to select the new language. Other users in the group don’t see the keyboard
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub selective: bool,
+
+    /// Requests clients to always show the keyboard when the regular keyboard is hidden.
+    /// Defaults to false, in which case the custom keyboard can be hidden and opened with a keyboard icon
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub is_persistent: bool,
+
+    /// The placeholder to be shown in the input field when the keyboard is active, 1-64 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_field_placeholder: Option<Cow<'a, str>>,
+}
+
+impl<'a> ReplyKeyboardMarkup<'a> {
+    /// Empty keyboard
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keyboard with `buttons` laid out in rows of `columns` buttons
+    pub fn grid(buttons: impl IntoIterator<Item = KeyboardButton<'a>>, columns: usize) -> Self {
+        Self {
+            keyboard: grid(buttons, columns).into(),
+            ..Self::default()
+        }
+    }
+
+    /// Start a new row. Buttons added after it are placed in the new row
+    pub fn row(mut self) -> Self {
+        self.keyboard.to_mut().push(Vec::new());
+        self
+    }
+
+    /// Add button to the last row
+    pub fn button(mut self, button: KeyboardButton<'a>) -> Self {
+        push_button(self.keyboard.to_mut(), button);
+        self
+    }
+
+    /// Add rows of another keyboard after the rows of this one
+    pub fn append(mut self, other: ReplyKeyboardMarkup<'a>) -> Self {
+        self.keyboard.to_mut().extend(other.keyboard.into_owned());
+        self
+    }
+
+    /// Remove row of buttons. Returns `None` if there is no such row
+    pub fn remove_row(&mut self, row: usize) -> Option<Vec<KeyboardButton<'a>>> {
+        remove_row(&mut self.keyboard, row)
+    }
+
+    /// Remove button, the row is removed too if it becomes empty.
+    /// Returns `None` if there is no such button
+    pub fn remove_button(&mut self, row: usize, column: usize) -> Option<KeyboardButton<'a>> {
+        remove_button(&mut self.keyboard, row, column)
+    }
+}
+
+impl<'a> From<&'a [Vec<KeyboardButton<'a>>]> for ReplyKeyboardMarkup<'a> {
+    fn from(x: &'a [Vec<KeyboardButton<'a>>]) -> Self {
+        Self {
+            keyboard: Cow::Borrowed(x),
+            ..Self::default()
+        }
+    }
+}
+
+impl<'a> From<Vec<Vec<KeyboardButton<'a>>>> for ReplyKeyboardMarkup<'a> {
+    fn from(x: Vec<Vec<KeyboardButton<'a>>>) -> Self {
+        Self {
+            keyboard: Cow::Owned(x),
+            ..Self::default()
+        }
+    }
+}
+
+fn grid<B>(buttons: impl IntoIterator<Item = B>, columns: usize) -> Vec<Vec<B>> {
+    let columns = columns.max(1);
+    let mut rows: Vec<Vec<B>> = Vec::new();
+    for button in buttons {
+        match rows.last_mut() {
+            Some(row) if row.len() < columns => row.push(button),
+            _ => rows.push(vec![button]),
+        }
+    }
+    rows
+}
+
+fn push_button<B>(rows: &mut Vec<Vec<B>>, button: B) {
+    match rows.last_mut() {
+        Some(row) => row.push(button),
+        None => rows.push(vec![button]),
+    }
+}
+
+fn remove_row<B: Clone>(rows: &mut Cow<[Vec<B>]>, row: usize) -> Option<Vec<B>> {
+    if row < rows.len() {
+        Some(rows.to_mut().remove(row))
+    } else {
+        None
+    }
+}
+
+fn remove_button<B: Clone>(rows: &mut Cow<[Vec<B>]>, row: usize, column: usize) -> Option<B> {
+    if column >= rows.get(row)?.len() {
+        return None;
+    }
+    let rows = rows.to_mut();
+    let button = rows[row].remove(column);
+    if rows[row].is_empty() {
+        rows.remove(row);
+    }
+    Some(button)
 }
 
 /// Upon receiving a message with this object, Telegram clients will remove the current custom keyboard
 /// and display the default letter-keyboard. By default, custom keyboards are displayed until a
 /// new keyboard is sent by a bot. An exception is made for one-time keyboards that are hidden
 /// immediately after the user presses a button (see `ReplyKeyboardMarkup`)
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ReplyKeyboardRemove {
     /// Requests clients to remove the custom keyboard
     /// (user will not be able to summon this keyboard; if you want to hide the keyboard from sight but keep it accessible,
@@ -527,7 +1014,7 @@ pub struct ReplyKeyboardRemove {
     /// Example: A user votes in a poll, bot returns confirmation message in reply
     /// to the vote and removes the keyboard for that user, while still showing the keyboard
     /// with poll options to users who haven't voted yet
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub selective: bool,
 }
 
@@ -535,7 +1022,7 @@ pub struct ReplyKeyboardRemove {
 /// to the user (act as if the user has selected the bot‘s message and tapped ’Reply').
 /// This can be extremely useful if you want to create user-friendly step-by-step interfaces
 /// without having to sacrifice [privacy mode](https://core.telegram.org/bots#privacy-mode).
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ForceReply {
     /// Shows reply interface to the user, as if they manually selected the bot‘s message and tapped ’Reply'
     #[serde(skip_serializing_if = "Not::not")]
@@ -543,50 +1030,444 @@ pub struct ForceReply {
     /// Use this parameter if you want to force reply from specific users only.
     /// Targets: 1) users that are @mentioned in the text of the Message object; 2)
     /// if the bot's message is a reply (has `reply_to_message_id`), sender of the original message
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub selective: bool,
 }
 
-#[derive(Serialize, Debug, Clone)]
+/// This object represents one button of an inline keyboard
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum InlineKeyboardButton<'a> {
     Url {
         /// Label text on the button
-        text: &'a str,
+        text: Cow<'a, str>,
         /// HTTP or tg:// url to be opened when button is pressed
-        url: &'a str,
+        url: Cow<'a, str>,
     },
     CallbackData {
         /// Label text on the button
-        text: &'a str,
+        text: Cow<'a, str>,
         /// Data to be sent in a [callback query](https://core.telegram.org/bots/api#callbackquery)
         /// to the bot when button is pressed, 1-64 bytes
-        callback_data: &'a str,
+        callback_data: Cow<'a, str>,
+    },
+    WebApp {
+        /// Label text on the button
+        text: Cow<'a, str>,
+        /// Description of the [Web App](https://core.telegram.org/bots/webapps) that will be launched
+        /// when the user presses the button. Available only in private chats
+        web_app: WebAppInfo,
+    },
+    LoginUrl {
+        /// Label text on the button
+        text: Cow<'a, str>,
+        /// HTTP URL used to automatically authorize the user. Can be used as a replacement for the
+        /// [Telegram Login Widget](https://core.telegram.org/widgets/login)
+        login_url: LoginUrl,
+    },
+    SwitchInlineQuery {
+        /// Label text on the button
+        text: Cow<'a, str>,
+        /// Prompts the user to select one of their chats, open that chat and insert the bot's username
+        /// and this inline query in the input field. Can be empty
+        switch_inline_query: Cow<'a, str>,
+    },
+    SwitchInlineQueryCurrentChat {
+        /// Label text on the button
+        text: Cow<'a, str>,
+        /// Inserts the bot's username and this inline query in the input field of the current chat.
+        /// Can be empty
+        switch_inline_query_current_chat: Cow<'a, str>,
+    },
+    SwitchInlineQueryChosenChat {
+        /// Label text on the button
+        text: Cow<'a, str>,
+        /// Prompts the user to select one of their chats of the specified type, open that chat
+        /// and insert the bot's username and the specified inline query in the input field
+        switch_inline_query_chosen_chat: SwitchInlineQueryChosenChat,
+    },
+    CopyText {
+        /// Label text on the button
+        text: Cow<'a, str>,
+        /// Description of the button that copies the specified text to the clipboard
+        copy_text: CopyTextButton,
+    },
+    CallbackGame {
+        /// Label text on the button
+        text: Cow<'a, str>,
+        /// Game that will be launched when the user presses the button.
+        /// Must always be the first button in the first row
+        callback_game: CallbackGame,
+    },
+    Pay {
+        /// Label text on the button
+        text: Cow<'a, str>,
+        /// Must be true. Sends a pay button, which must always be the first button in the first row
+        /// and can only be used in invoice messages
+        pay: bool,
     },
 }
 
 /// This object represents one button of the reply keyboard
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum KeyboardButton<'a> {
     /// Text of the button. It will be sent as a message when the button is pressed
-    Text(&'a str),
+    Text(Cow<'a, str>),
+
+    RequestContact {
+        /// Text of the button
+        text: Cow<'a, str>,
+        /// If true, the user's phone number will be sent as a contact when the button is pressed.
+        /// Available in private chats only
+        request_contact: bool,
+    },
+
+    RequestLocation {
+        /// Text of the button
+        text: Cow<'a, str>,
+        /// If true, the user's current location will be sent when the button is pressed.
+        /// Available in private chats only
+        request_location: bool,
+    },
+
+    RequestPoll {
+        /// Text of the button
+        text: Cow<'a, str>,
+        /// The user will be asked to create a poll and send it to the bot when the button is pressed.
+        /// Available in private chats only
+        request_poll: KeyboardButtonPollType<'a>,
+    },
+
+    RequestUsers {
+        /// Text of the button
+        text: Cow<'a, str>,
+        /// A list of suitable users will be opened when the button is pressed. Tapping on any user
+        /// will send their identifier to the bot in a `users_shared` service message.
+        /// Available in private chats only
+        request_users: KeyboardButtonRequestUsers,
+    },
+
+    RequestChat {
+        /// Text of the button
+        text: Cow<'a, str>,
+        /// A list of suitable chats will be opened when the button is pressed. Tapping on a chat
+        /// will send its identifier to the bot in a `chat_shared` service message.
+        /// Available in private chats only
+        request_chat: KeyboardButtonRequestChat,
+    },
+
+    WebApp {
+        /// Text of the button
+        text: Cow<'a, str>,
+        /// The [Web App](https://core.telegram.org/bots/webapps) will be launched when the button is pressed.
+        /// The Web App will be able to send a `web_app_data` service message. Available in private chats only
+        web_app: WebAppInfo,
+    },
+}
+
+/// This object represents type of a poll, which is allowed to be created and sent when
+/// the corresponding button is pressed
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct KeyboardButtonPollType<'a> {
+    /// If `quiz` is passed, the user will be allowed to create only polls in the quiz mode.
+    /// If `regular` is passed, only regular polls will be allowed.
+    /// Otherwise, the user will be allowed to create a poll of any type
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub typ: Option<Cow<'a, str>>,
+}
+
+/// This object defines the criteria used to request suitable users
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KeyboardButtonRequestUsers {
+    /// Signed 32-bit identifier of the request that will be received back in the `UsersShared` object.
+    /// Must be unique within the message
+    pub request_id: i32,
+
+    /// Pass true to request bots, pass false to request regular users.
+    /// If not specified, no additional restrictions are applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_bot: Option<bool>,
+
+    /// Pass true to request premium users, pass false to request non-premium users.
+    /// If not specified, no additional restrictions are applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_is_premium: Option<bool>,
+
+    /// The maximum number of users to be selected, 1-10. Defaults to 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_quantity: Option<i64>,
+
+    /// Request the users' first and last names
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub request_name: bool,
+
+    /// Request the users' usernames
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub request_username: bool,
+
+    /// Request the users' photos
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub request_photo: bool,
+}
+
+impl KeyboardButtonRequestUsers {
+    pub fn new(request_id: i32) -> Self {
+        Self {
+            request_id,
+            user_is_bot: None,
+            user_is_premium: None,
+            max_quantity: None,
+            request_name: false,
+            request_username: false,
+            request_photo: false,
+        }
+    }
 }
 
-#[derive(Serialize, Debug, Clone, Copy)]
+/// This object defines the criteria used to request a suitable chat. The bot will be granted
+/// requested rights in the chat if appropriate
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KeyboardButtonRequestChat {
+    /// Signed 32-bit identifier of the request, which will be received back in the `ChatShared` object.
+    /// Must be unique within the message
+    pub request_id: i32,
+
+    /// Pass true to request a channel chat, pass false to request a group or a supergroup chat
+    pub chat_is_channel: bool,
+
+    /// Pass true to request a forum supergroup, pass false to request a non-forum chat.
+    /// If not specified, no additional restrictions are applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_is_forum: Option<bool>,
+
+    /// Pass true to request a supergroup or a channel with a username, pass false to request
+    /// a chat without a username. If not specified, no additional restrictions are applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_has_username: Option<bool>,
+
+    /// Request a chat owned by the user
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub chat_is_created: bool,
+
+    /// Required administrator rights of the user in the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_administrator_rights: Option<ChatAdministratorRights>,
+
+    /// Required administrator rights of the bot in the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_administrator_rights: Option<ChatAdministratorRights>,
+
+    /// Request a chat with the bot as a member
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub bot_is_member: bool,
+
+    /// Request the chat's title
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub request_title: bool,
+
+    /// Request the chat's username
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub request_username: bool,
+
+    /// Request the chat's photo
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub request_photo: bool,
+}
+
+impl KeyboardButtonRequestChat {
+    pub fn new(request_id: i32, chat_is_channel: bool) -> Self {
+        Self {
+            request_id,
+            chat_is_channel,
+            chat_is_forum: None,
+            chat_has_username: None,
+            chat_is_created: false,
+            user_administrator_rights: None,
+            bot_administrator_rights: None,
+            bot_is_member: false,
+            request_title: false,
+            request_username: false,
+            request_photo: false,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParseMode {
     Html,
+
+    /// Legacy markdown, kept for backward compatibility. Prefer `ParseMode::MarkdownV2`
     Markdown,
+
+    MarkdownV2,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum MessageOrInlineMessageId<'a> {
     Inline {
-        inline_message_id: &'a str,
+        inline_message_id: Cow<'a, str>,
     },
     Chat {
         chat_id: ChatId<'a>,
         message_id: i64,
     },
 }
+
+/// Format of a sticker
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StickerFormat {
+    /// .WEBP or .PNG image
+    Static,
+
+    /// .TGS animation
+    Animated,
+
+    /// .WEBM video
+    Video,
+}
+
+/// Describes a sticker to be added to a sticker set
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputSticker<'a> {
+    /// The added sticker. Animated and video stickers can't be uploaded via http url
+    pub sticker: FileKind<'a>,
+
+    /// Format of the added sticker
+    pub format: StickerFormat,
+
+    /// List of 1-20 emoji associated with the sticker
+    pub emoji_list: Cow<'a, [Cow<'a, str>]>,
+
+    /// Position where the mask should be placed on faces. For mask stickers only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+
+    /// List of 0-20 search keywords for the sticker with total length of up to 64 characters.
+    /// For regular and custom emoji stickers only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Cow<'a, [Cow<'a, str>]>>,
+}
+
+impl<'a> InputSticker<'a> {
+    pub fn new(
+        sticker: FileKind<'a>,
+        format: StickerFormat,
+        emoji_list: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> Self {
+        Self {
+            sticker,
+            format,
+            emoji_list: emoji_list.into_iter().map(Into::into).collect(),
+            mask_position: None,
+            keywords: None,
+        }
+    }
+}
+
+/// Currency code of Telegram Stars
+pub const STARS_CURRENCY: &str = "XTR";
+
+/// The only subscription period in seconds supported by Telegram, 30 days
+pub const STARS_SUBSCRIPTION_PERIOD: i32 = 2_592_000;
+
+/// Portion of the price for goods or services
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LabeledPrice<'a> {
+    /// Portion label
+    pub label: Cow<'a, str>,
+
+    /// Price of the product in the smallest units of the currency (integer, not float/double).
+    /// For example, for a price of US$ 1.45 pass amount = 145
+    pub amount: i64,
+}
+
+impl<'a> LabeledPrice<'a> {
+    pub fn new(label: impl Into<Cow<'a, str>>, amount: i64) -> Self {
+        Self {
+            label: label.into(),
+            amount,
+        }
+    }
+}
+
+/// This object represents a bot command
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BotCommand<'a> {
+    /// Text of the command; 1-32 characters. Can contain only lowercase English letters, digits and underscores
+    pub command: Cow<'a, str>,
+
+    /// Description of the command; 1-256 characters
+    pub description: Cow<'a, str>,
+}
+
+impl<'a> BotCommand<'a> {
+    pub fn new(command: impl Into<Cow<'a, str>>, description: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            command: command.into(),
+            description: description.into(),
+        }
+    }
+}
+
+/// Scope to which bot commands are applied. If several scopes match a user, the most narrow one is used:
+/// `ChatMember`, `ChatAdministrators`, `Chat`, `AllChatAdministrators`, `AllGroupChats` or `AllPrivateChats`,
+/// and finally `Default`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BotCommandScope<'a> {
+    /// Default commands are used if no commands with a narrower scope are specified for the user
+    Default,
+
+    /// Covers all private chats
+    AllPrivateChats,
+
+    /// Covers all group and supergroup chats
+    AllGroupChats,
+
+    /// Covers all group and supergroup chat administrators
+    AllChatAdministrators,
+
+    /// Covers a specific chat
+    Chat {
+        /// Unique identifier for the target chat
+        chat_id: ChatId<'a>,
+    },
+
+    /// Covers all administrators of a specific group or supergroup chat
+    ChatAdministrators {
+        /// Unique identifier for the target chat
+        chat_id: ChatId<'a>,
+    },
+
+    /// Covers a specific member of a group or supergroup chat
+    ChatMember {
+        /// Unique identifier for the target chat
+        chat_id: ChatId<'a>,
+
+        /// Unique identifier of the target user
+        user_id: i64,
+    },
+}
+
+/// This object describes the bot's menu button in a private chat
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MenuButton<'a> {
+    /// Opens the bot's list of commands
+    Commands,
+
+    /// Launches a [Web App](https://core.telegram.org/bots/webapps)
+    WebApp {
+        /// Text on the button
+        text: Cow<'a, str>,
+
+        /// Description of the Web App that will be launched when the user presses the button
+        web_app: WebAppInfo,
+    },
+
+    /// Describes that no specific value for the menu button was set
+    Default,
+}
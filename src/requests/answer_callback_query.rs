@@ -1,28 +1,28 @@
-use std::ops::Not;
+use std::{borrow::Cow, ops::Not};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::requests::Request;
 
 /// Use this struct to send answers to callback queries sent from inline keyboards.
 /// The answer will be displayed to the user as a notification at the top of the chat screen or as an alert.
 /// On success, `True` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct AnswerCallbackQuery<'a> {
     /// Unique identifier for the query to be answered
-    pub callback_query_id: &'a str,
+    pub callback_query_id: Cow<'a, str>,
 
     /// Text of the notification. If not specified, nothing will be shown to the user, 0-200 characters
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text: Option<&'a str>,
+    pub text: Option<Cow<'a, str>>,
 
     /// If true, an alert will be shown by the client instead of a notification at the top of the chat screen. Defaults to false.
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub show_alert: bool,
 
     /// URL that will be opened by the user's client
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<&'a str>,
+    pub url: Option<Cow<'a, str>>,
 
     /// The maximum amount of time in seconds that the result of the callback query may be cached client-side.
     /// Defaults to 0.
@@ -39,13 +39,39 @@ impl<'a> Request for AnswerCallbackQuery<'a> {
 }
 
 impl<'a> AnswerCallbackQuery<'a> {
-    pub fn new(query_id: &'a str, notification_text: &'a str) -> Self {
+    pub fn new(
+        query_id: impl Into<Cow<'a, str>>,
+        notification_text: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
-            callback_query_id: query_id,
-            text: Some(notification_text),
+            callback_query_id: query_id.into(),
+            text: Some(notification_text.into()),
             show_alert: false,
             url: None,
             cache_time: None,
         }
     }
+
+    /// Answer without a notification, which only hides the progress indicator on the pressed button
+    pub fn new_silent(query_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            callback_query_id: query_id.into(),
+            text: None,
+            show_alert: false,
+            url: None,
+            cache_time: None,
+        }
+    }
+
+    /// Answer to a query from a `CallbackGame` button, which opens the game at `url`.
+    /// The url may contain query parameters to identify the user and the game message
+    pub fn new_game_url(query_id: impl Into<Cow<'a, str>>, url: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            callback_query_id: query_id.into(),
+            text: None,
+            show_alert: false,
+            url: Some(url.into()),
+            cache_time: None,
+        }
+    }
 }
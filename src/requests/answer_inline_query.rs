@@ -0,0 +1,1926 @@
+use std::{borrow::Cow, ops::Not};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{InlineKeyboard, LabeledPrice, ParseMode, Request},
+    responses::{MessageEntity, WebAppInfo},
+};
+
+/// Use this struct to send answers to an inline query. No more than 50 results per query are allowed.
+/// On success, `True` is returned.
+///
+/// Results can be paginated: pass the `next_offset` the client should send back in
+/// `InlineQuery::offset` to receive more results.
+/// ```
+/// # use rutebot::requests::{
+/// #     AnswerInlineQuery, InlineQueryResultArticle, InputTextMessageContent,
+/// # };
+/// let offset: usize = "".parse().unwrap_or(0);
+/// let results: Vec<_> = (offset..offset + 2)
+///     .map(|i| {
+///         let content = InputTextMessageContent::new(format!("Result {}", i));
+///         InlineQueryResultArticle::new(i.to_string(), format!("#{}", i), content.into()).into()
+///     })
+///     .collect();
+/// let answer = AnswerInlineQuery::new_page("query id", results, (offset + 2).to_string());
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnswerInlineQuery<'a> {
+    /// Unique identifier for the answered query
+    pub inline_query_id: Cow<'a, str>,
+
+    /// Results for the inline query
+    pub results: Cow<'a, [InlineQueryResult<'a>]>,
+
+    /// The maximum amount of time in seconds that the result of the inline query may be cached on the server.
+    /// Defaults to 300.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_time: Option<i32>,
+
+    /// Pass true, if results may be cached on the server side only for the user that sent the query.
+    /// By default, results may be returned to any user who sends the same query
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub is_personal: bool,
+
+    /// Offset that a client should send in the next query with the same text to receive more results.
+    /// Pass `None` if there are no more results or if you don't support pagination. Offset length can't exceed 64 bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<Cow<'a, str>>,
+
+    /// Button to be shown above inline query results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub button: Option<InlineQueryResultsButton<'a>>,
+}
+
+impl<'a> Request for AnswerInlineQuery<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "answerInlineQuery"
+    }
+}
+
+impl<'a> AnswerInlineQuery<'a> {
+    pub fn new(
+        inline_query_id: impl Into<Cow<'a, str>>,
+        results: impl Into<Cow<'a, [InlineQueryResult<'a>]>>,
+    ) -> Self {
+        Self {
+            inline_query_id: inline_query_id.into(),
+            results: results.into(),
+            cache_time: None,
+            is_personal: false,
+            next_offset: None,
+            button: None,
+        }
+    }
+
+    /// Answer with one page of results, `next_offset` is sent back by the client to request the next page
+    pub fn new_page(
+        inline_query_id: impl Into<Cow<'a, str>>,
+        results: impl Into<Cow<'a, [InlineQueryResult<'a>]>>,
+        next_offset: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            next_offset: Some(next_offset.into()),
+            ..Self::new(inline_query_id, results)
+        }
+    }
+}
+
+/// Button to be shown above inline query results
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum InlineQueryResultsButton<'a> {
+    /// Button that launches a [Web App](https://core.telegram.org/bots/webapps) in inline mode
+    WebApp {
+        /// Label text on the button
+        text: Cow<'a, str>,
+
+        /// Web App that will be launched when the user presses the button
+        web_app: WebAppInfo,
+    },
+
+    /// Button that opens a private chat with the bot and sends it `/start` with the parameter
+    StartParameter {
+        /// Label text on the button
+        text: Cow<'a, str>,
+
+        /// Deep-linking parameter for the /start message sent to the bot when a user presses the button.
+        /// 1-64 characters, only `A-Z`, `a-z`, `0-9`, `_` and `-` are allowed
+        start_parameter: Cow<'a, str>,
+    },
+}
+
+/// One result of an inline query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", from = "UntaggedInlineQueryResult<'a>")]
+pub enum InlineQueryResult<'a> {
+    #[serde(rename = "article")]
+    Article(InlineQueryResultArticle<'a>),
+
+    #[serde(rename = "photo")]
+    Photo(InlineQueryResultPhoto<'a>),
+
+    #[serde(rename = "gif")]
+    Gif(InlineQueryResultGif<'a>),
+
+    #[serde(rename = "mpeg4_gif")]
+    Mpeg4Gif(InlineQueryResultMpeg4Gif<'a>),
+
+    #[serde(rename = "video")]
+    Video(InlineQueryResultVideo<'a>),
+
+    #[serde(rename = "audio")]
+    Audio(InlineQueryResultAudio<'a>),
+
+    #[serde(rename = "voice")]
+    Voice(InlineQueryResultVoice<'a>),
+
+    #[serde(rename = "document")]
+    Document(InlineQueryResultDocument<'a>),
+
+    #[serde(rename = "location")]
+    Location(InlineQueryResultLocation<'a>),
+
+    #[serde(rename = "venue")]
+    Venue(InlineQueryResultVenue<'a>),
+
+    #[serde(rename = "contact")]
+    Contact(InlineQueryResultContact<'a>),
+
+    #[serde(rename = "game")]
+    Game(InlineQueryResultGame<'a>),
+
+    #[serde(rename = "photo")]
+    CachedPhoto(InlineQueryResultCachedPhoto<'a>),
+
+    #[serde(rename = "gif")]
+    CachedGif(InlineQueryResultCachedGif<'a>),
+
+    #[serde(rename = "mpeg4_gif")]
+    CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif<'a>),
+
+    #[serde(rename = "sticker")]
+    CachedSticker(InlineQueryResultCachedSticker<'a>),
+
+    #[serde(rename = "document")]
+    CachedDocument(InlineQueryResultCachedDocument<'a>),
+
+    #[serde(rename = "video")]
+    CachedVideo(InlineQueryResultCachedVideo<'a>),
+
+    #[serde(rename = "voice")]
+    CachedVoice(InlineQueryResultCachedVoice<'a>),
+
+    #[serde(rename = "audio")]
+    CachedAudio(InlineQueryResultCachedAudio<'a>),
+}
+
+/// Cached and url results share their types, so they are told apart by their fields
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum UntaggedInlineQueryResult<'a> {
+    Photo(InlineQueryResultPhoto<'a>),
+    Gif(InlineQueryResultGif<'a>),
+    Mpeg4Gif(InlineQueryResultMpeg4Gif<'a>),
+    Video(InlineQueryResultVideo<'a>),
+    Audio(InlineQueryResultAudio<'a>),
+    Voice(InlineQueryResultVoice<'a>),
+    Document(InlineQueryResultDocument<'a>),
+    Contact(InlineQueryResultContact<'a>),
+    Game(InlineQueryResultGame<'a>),
+    CachedPhoto(InlineQueryResultCachedPhoto<'a>),
+    CachedGif(InlineQueryResultCachedGif<'a>),
+    CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif<'a>),
+    CachedSticker(InlineQueryResultCachedSticker<'a>),
+    CachedDocument(InlineQueryResultCachedDocument<'a>),
+    CachedVideo(InlineQueryResultCachedVideo<'a>),
+    CachedVoice(InlineQueryResultCachedVoice<'a>),
+    CachedAudio(InlineQueryResultCachedAudio<'a>),
+    Venue(InlineQueryResultVenue<'a>),
+    Location(InlineQueryResultLocation<'a>),
+    Article(InlineQueryResultArticle<'a>),
+}
+
+impl<'a> From<UntaggedInlineQueryResult<'a>> for InlineQueryResult<'a> {
+    fn from(x: UntaggedInlineQueryResult<'a>) -> Self {
+        match x {
+            UntaggedInlineQueryResult::Photo(x) => InlineQueryResult::Photo(x),
+            UntaggedInlineQueryResult::Gif(x) => InlineQueryResult::Gif(x),
+            UntaggedInlineQueryResult::Mpeg4Gif(x) => InlineQueryResult::Mpeg4Gif(x),
+            UntaggedInlineQueryResult::Video(x) => InlineQueryResult::Video(x),
+            UntaggedInlineQueryResult::Audio(x) => InlineQueryResult::Audio(x),
+            UntaggedInlineQueryResult::Voice(x) => InlineQueryResult::Voice(x),
+            UntaggedInlineQueryResult::Document(x) => InlineQueryResult::Document(x),
+            UntaggedInlineQueryResult::Contact(x) => InlineQueryResult::Contact(x),
+            UntaggedInlineQueryResult::Game(x) => InlineQueryResult::Game(x),
+            UntaggedInlineQueryResult::CachedPhoto(x) => InlineQueryResult::CachedPhoto(x),
+            UntaggedInlineQueryResult::CachedGif(x) => InlineQueryResult::CachedGif(x),
+            UntaggedInlineQueryResult::CachedMpeg4Gif(x) => InlineQueryResult::CachedMpeg4Gif(x),
+            UntaggedInlineQueryResult::CachedSticker(x) => InlineQueryResult::CachedSticker(x),
+            UntaggedInlineQueryResult::CachedDocument(x) => InlineQueryResult::CachedDocument(x),
+            UntaggedInlineQueryResult::CachedVideo(x) => InlineQueryResult::CachedVideo(x),
+            UntaggedInlineQueryResult::CachedVoice(x) => InlineQueryResult::CachedVoice(x),
+            UntaggedInlineQueryResult::CachedAudio(x) => InlineQueryResult::CachedAudio(x),
+            UntaggedInlineQueryResult::Venue(x) => InlineQueryResult::Venue(x),
+            UntaggedInlineQueryResult::Location(x) => InlineQueryResult::Location(x),
+            UntaggedInlineQueryResult::Article(x) => InlineQueryResult::Article(x),
+        }
+    }
+}
+
+impl<'a> From<InlineQueryResultArticle<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultArticle<'a>) -> Self {
+        InlineQueryResult::Article(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultPhoto<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultPhoto<'a>) -> Self {
+        InlineQueryResult::Photo(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultGif<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultGif<'a>) -> Self {
+        InlineQueryResult::Gif(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultMpeg4Gif<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultMpeg4Gif<'a>) -> Self {
+        InlineQueryResult::Mpeg4Gif(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultVideo<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultVideo<'a>) -> Self {
+        InlineQueryResult::Video(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultAudio<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultAudio<'a>) -> Self {
+        InlineQueryResult::Audio(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultVoice<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultVoice<'a>) -> Self {
+        InlineQueryResult::Voice(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultDocument<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultDocument<'a>) -> Self {
+        InlineQueryResult::Document(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultLocation<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultLocation<'a>) -> Self {
+        InlineQueryResult::Location(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultVenue<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultVenue<'a>) -> Self {
+        InlineQueryResult::Venue(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultContact<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultContact<'a>) -> Self {
+        InlineQueryResult::Contact(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultGame<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultGame<'a>) -> Self {
+        InlineQueryResult::Game(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultCachedPhoto<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultCachedPhoto<'a>) -> Self {
+        InlineQueryResult::CachedPhoto(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultCachedGif<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultCachedGif<'a>) -> Self {
+        InlineQueryResult::CachedGif(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultCachedMpeg4Gif<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultCachedMpeg4Gif<'a>) -> Self {
+        InlineQueryResult::CachedMpeg4Gif(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultCachedSticker<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultCachedSticker<'a>) -> Self {
+        InlineQueryResult::CachedSticker(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultCachedDocument<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultCachedDocument<'a>) -> Self {
+        InlineQueryResult::CachedDocument(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultCachedVideo<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultCachedVideo<'a>) -> Self {
+        InlineQueryResult::CachedVideo(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultCachedVoice<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultCachedVoice<'a>) -> Self {
+        InlineQueryResult::CachedVoice(x)
+    }
+}
+
+impl<'a> From<InlineQueryResultCachedAudio<'a>> for InlineQueryResult<'a> {
+    fn from(x: InlineQueryResultCachedAudio<'a>) -> Self {
+        InlineQueryResult::CachedAudio(x)
+    }
+}
+
+/// Content of a message to be sent as a result of an inline query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum InputMessageContent<'a> {
+    Text(InputTextMessageContent<'a>),
+
+    Invoice(InputInvoiceMessageContent<'a>),
+
+    Venue(InputVenueMessageContent<'a>),
+
+    Location(InputLocationMessageContent),
+
+    Contact(InputContactMessageContent<'a>),
+}
+
+impl<'a> From<InputTextMessageContent<'a>> for InputMessageContent<'a> {
+    fn from(x: InputTextMessageContent<'a>) -> Self {
+        InputMessageContent::Text(x)
+    }
+}
+
+impl<'a> From<InputInvoiceMessageContent<'a>> for InputMessageContent<'a> {
+    fn from(x: InputInvoiceMessageContent<'a>) -> Self {
+        InputMessageContent::Invoice(x)
+    }
+}
+
+impl<'a> From<InputVenueMessageContent<'a>> for InputMessageContent<'a> {
+    fn from(x: InputVenueMessageContent<'a>) -> Self {
+        InputMessageContent::Venue(x)
+    }
+}
+
+impl<'a> From<InputLocationMessageContent> for InputMessageContent<'a> {
+    fn from(x: InputLocationMessageContent) -> Self {
+        InputMessageContent::Location(x)
+    }
+}
+
+impl<'a> From<InputContactMessageContent<'a>> for InputMessageContent<'a> {
+    fn from(x: InputContactMessageContent<'a>) -> Self {
+        InputMessageContent::Contact(x)
+    }
+}
+
+/// Link to an article or web page
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultArticle<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// Title of the result
+    pub title: Cow<'a, str>,
+
+    /// Content of the message to be sent
+    pub input_message_content: InputMessageContent<'a>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Url of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<Cow<'a, str>>,
+
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<Cow<'a, str>>,
+
+    /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i32>,
+
+    /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i32>,
+}
+
+impl<'a> InlineQueryResultArticle<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+        input_message_content: InputMessageContent<'a>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            input_message_content,
+            reply_markup: None,
+            url: None,
+            description: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+/// Link to a photo. By default, this photo will be sent by the user with optional caption
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultPhoto<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid url of the photo. Photo must be in JPEG format. Photo size must not exceed 5MB
+    pub photo_url: Cow<'a, str>,
+
+    /// Url of the thumbnail for the photo
+    pub thumbnail_url: Cow<'a, str>,
+
+    /// Width of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i32>,
+
+    /// Height of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i32>,
+
+    /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Cow<'a, str>>,
+
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// Caption of the photo to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Pass true, if the caption must be shown above the message media
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub show_caption_above_media: bool,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultPhoto<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        photo_url: impl Into<Cow<'a, str>>,
+        thumbnail_url: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            photo_url: photo_url.into(),
+            thumbnail_url: thumbnail_url.into(),
+            photo_width: None,
+            photo_height: None,
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to an animated GIF file. By default, this animated GIF file will be sent by the user with optional caption
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultGif<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid url for the GIF file. File size must not exceed 1MB
+    pub gif_url: Cow<'a, str>,
+
+    /// Width of the GIF
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_width: Option<i32>,
+
+    /// Height of the GIF
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_height: Option<i32>,
+
+    /// Duration of the GIF in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gif_duration: Option<i32>,
+
+    /// Url of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
+    pub thumbnail_url: Cow<'a, str>,
+
+    /// MIME type of the thumbnail, must be one of “image/jpeg”, “image/gif”, or “video/mp4”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_mime_type: Option<Cow<'a, str>>,
+
+    /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Cow<'a, str>>,
+
+    /// Caption of the GIF file to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Pass true, if the caption must be shown above the message media
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub show_caption_above_media: bool,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the GIF animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultGif<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        gif_url: impl Into<Cow<'a, str>>,
+        thumbnail_url: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            gif_url: gif_url.into(),
+            thumbnail_url: thumbnail_url.into(),
+            gif_width: None,
+            gif_height: None,
+            gif_duration: None,
+            thumbnail_mime_type: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to a video animation (H.264/MPEG-4 AVC video without sound). By default, this animated MPEG-4 file will be sent by the user with optional caption
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultMpeg4Gif<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid url for the MPEG4 file. File size must not exceed 1MB
+    pub mpeg4_url: Cow<'a, str>,
+
+    /// Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_width: Option<i32>,
+
+    /// Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_height: Option<i32>,
+
+    /// Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpeg4_duration: Option<i32>,
+
+    /// Url of the static (JPEG or GIF) or animated (MPEG4) thumbnail for the result
+    pub thumbnail_url: Cow<'a, str>,
+
+    /// MIME type of the thumbnail, must be one of “image/jpeg”, “image/gif”, or “video/mp4”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_mime_type: Option<Cow<'a, str>>,
+
+    /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Cow<'a, str>>,
+
+    /// Caption of the MPEG-4 file to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Pass true, if the caption must be shown above the message media
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub show_caption_above_media: bool,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the video animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultMpeg4Gif<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        mpeg4_url: impl Into<Cow<'a, str>>,
+        thumbnail_url: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            mpeg4_url: mpeg4_url.into(),
+            thumbnail_url: thumbnail_url.into(),
+            mpeg4_width: None,
+            mpeg4_height: None,
+            mpeg4_duration: None,
+            thumbnail_mime_type: None,
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to a page containing an embedded video player or a video file. By default, this video file will be sent by the user with an optional caption
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultVideo<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid url for the embedded video player or video file
+    pub video_url: Cow<'a, str>,
+
+    /// MIME type of the content of the video url, “text/html” or “video/mp4”
+    pub mime_type: Cow<'a, str>,
+
+    /// Url of the thumbnail (JPEG only) for the video
+    pub thumbnail_url: Cow<'a, str>,
+
+    /// Title for the result
+    pub title: Cow<'a, str>,
+
+    /// Caption of the video to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Pass true, if the caption must be shown above the message media
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub show_caption_above_media: bool,
+
+    /// Video width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_width: Option<i32>,
+
+    /// Video height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_height: Option<i32>,
+
+    /// Video duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_duration: Option<i32>,
+
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultVideo<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        video_url: impl Into<Cow<'a, str>>,
+        mime_type: impl Into<Cow<'a, str>>,
+        thumbnail_url: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            video_url: video_url.into(),
+            mime_type: mime_type.into(),
+            thumbnail_url: thumbnail_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            video_width: None,
+            video_height: None,
+            video_duration: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to an MP3 audio file. By default, this audio file will be sent by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultAudio<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid url for the audio file
+    pub audio_url: Cow<'a, str>,
+
+    /// Title
+    pub title: Cow<'a, str>,
+
+    /// Caption of the audio to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Performer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub performer: Option<Cow<'a, str>>,
+
+    /// Audio duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_duration: Option<i32>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultAudio<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        audio_url: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            audio_url: audio_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            performer: None,
+            audio_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to a voice recording in an .OGG container encoded with OPUS. By default, this voice recording will be sent by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultVoice<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid url for the voice recording
+    pub voice_url: Cow<'a, str>,
+
+    /// Recording title
+    pub title: Cow<'a, str>,
+
+    /// Caption of the voice message to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Recording duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_duration: Option<i32>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the voice recording
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultVoice<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        voice_url: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            voice_url: voice_url.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            voice_duration: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to a file. By default, this file will be sent by the user with an optional caption. Currently, only .PDF and .ZIP files can be sent using this method
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultDocument<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// Title for the result
+    pub title: Cow<'a, str>,
+
+    /// Caption of the document to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// A valid url for the file
+    pub document_url: Cow<'a, str>,
+
+    /// MIME type of the content of the file, either “application/pdf” or “application/zip”
+    pub mime_type: Cow<'a, str>,
+
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+
+    /// Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<Cow<'a, str>>,
+
+    /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i32>,
+
+    /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i32>,
+}
+
+impl<'a> InlineQueryResultDocument<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+        document_url: impl Into<Cow<'a, str>>,
+        mime_type: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            document_url: document_url.into(),
+            mime_type: mime_type.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            description: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+/// Location on a map. By default, the location will be sent by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultLocation<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// Latitude in degrees
+    pub latitude: f64,
+
+    /// Longitude in degrees
+    pub longitude: f64,
+
+    /// Location title
+    pub title: Cow<'a, str>,
+
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+
+    /// Period in seconds during which the location can be updated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i32>,
+
+    /// For live locations, a direction in which the user is moving, in degrees; 1-360
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i32>,
+
+    /// For live locations, a maximum distance for proximity alerts about approaching another chat member, in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i32>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+
+    /// Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<Cow<'a, str>>,
+
+    /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i32>,
+
+    /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i32>,
+}
+
+impl<'a> InlineQueryResultLocation<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        latitude: f64,
+        longitude: f64,
+        title: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            latitude,
+            longitude,
+            title: title.into(),
+            horizontal_accuracy: None,
+            live_period: None,
+            heading: None,
+            proximity_alert_radius: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+/// Venue. By default, the venue will be sent by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultVenue<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// Latitude of the venue location in degrees
+    pub latitude: f64,
+
+    /// Longitude of the venue location in degrees
+    pub longitude: f64,
+
+    /// Title of the venue
+    pub title: Cow<'a, str>,
+
+    /// Address of the venue
+    pub address: Cow<'a, str>,
+
+    /// Foursquare identifier of the venue if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<Cow<'a, str>>,
+
+    /// Foursquare type of the venue, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<Cow<'a, str>>,
+
+    /// Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<Cow<'a, str>>,
+
+    /// Google Places type of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<Cow<'a, str>>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+
+    /// Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<Cow<'a, str>>,
+
+    /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i32>,
+
+    /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i32>,
+}
+
+impl<'a> InlineQueryResultVenue<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        latitude: f64,
+        longitude: f64,
+        title: impl Into<Cow<'a, str>>,
+        address: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            latitude,
+            longitude,
+            title: title.into(),
+            address: address.into(),
+            foursquare_id: None,
+            foursquare_type: None,
+            google_place_id: None,
+            google_place_type: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+/// Contact with a phone number. By default, this contact will be sent by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultContact<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// Contact's phone number
+    pub phone_number: Cow<'a, str>,
+
+    /// Contact's first name
+    pub first_name: Cow<'a, str>,
+
+    /// Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<Cow<'a, str>>,
+
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<Cow<'a, str>>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+
+    /// Url of the thumbnail for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<Cow<'a, str>>,
+
+    /// Thumbnail width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_width: Option<i32>,
+
+    /// Thumbnail height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail_height: Option<i32>,
+}
+
+impl<'a> InlineQueryResultContact<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        phone_number: impl Into<Cow<'a, str>>,
+        first_name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
+            last_name: None,
+            vcard: None,
+            reply_markup: None,
+            input_message_content: None,
+            thumbnail_url: None,
+            thumbnail_width: None,
+            thumbnail_height: None,
+        }
+    }
+}
+
+/// [Game](https://core.telegram.org/bots/api#games)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultGame<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// Short name of the game
+    pub game_short_name: Cow<'a, str>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+}
+
+impl<'a> InlineQueryResultGame<'a> {
+    pub fn new(id: impl Into<Cow<'a, str>>, game_short_name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            game_short_name: game_short_name.into(),
+            reply_markup: None,
+        }
+    }
+}
+
+/// Link to a photo stored on the Telegram servers. By default, this photo will be sent by the user with an optional caption
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultCachedPhoto<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid file identifier of the photo
+    pub photo_file_id: Cow<'a, str>,
+
+    /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Cow<'a, str>>,
+
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// Caption of the photo to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Pass true, if the caption must be shown above the message media
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub show_caption_above_media: bool,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultCachedPhoto<'a> {
+    pub fn new(id: impl Into<Cow<'a, str>>, photo_file_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            photo_file_id: photo_file_id.into(),
+            title: None,
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to an animated GIF file stored on the Telegram servers. By default, this animated GIF file will be sent by the user with an optional caption
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultCachedGif<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid file identifier for the GIF file
+    pub gif_file_id: Cow<'a, str>,
+
+    /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Cow<'a, str>>,
+
+    /// Caption of the GIF file to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Pass true, if the caption must be shown above the message media
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub show_caption_above_media: bool,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the GIF animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultCachedGif<'a> {
+    pub fn new(id: impl Into<Cow<'a, str>>, gif_file_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            gif_file_id: gif_file_id.into(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to a video animation (H.264/MPEG-4 AVC video without sound) stored on the Telegram servers. By default, this animated MPEG-4 file will be sent by the user with an optional caption
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultCachedMpeg4Gif<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid file identifier for the MPEG4 file
+    pub mpeg4_file_id: Cow<'a, str>,
+
+    /// Title for the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Cow<'a, str>>,
+
+    /// Caption of the MPEG-4 file to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Pass true, if the caption must be shown above the message media
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub show_caption_above_media: bool,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the video animation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultCachedMpeg4Gif<'a> {
+    pub fn new(id: impl Into<Cow<'a, str>>, mpeg4_file_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            mpeg4_file_id: mpeg4_file_id.into(),
+            title: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to a sticker stored on the Telegram servers. By default, this sticker will be sent by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultCachedSticker<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid file identifier of the sticker
+    pub sticker_file_id: Cow<'a, str>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultCachedSticker<'a> {
+    pub fn new(id: impl Into<Cow<'a, str>>, sticker_file_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            sticker_file_id: sticker_file_id.into(),
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to a file stored on the Telegram servers. By default, this file will be sent by the user with an optional caption
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultCachedDocument<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// Title for the result
+    pub title: Cow<'a, str>,
+
+    /// A valid file identifier for the file
+    pub document_file_id: Cow<'a, str>,
+
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// Caption of the document to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultCachedDocument<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+        document_file_id: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            document_file_id: document_file_id.into(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to a video file stored on the Telegram servers. By default, this video file will be sent by the user with an optional caption
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultCachedVideo<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid file identifier for the video file
+    pub video_file_id: Cow<'a, str>,
+
+    /// Title for the result
+    pub title: Cow<'a, str>,
+
+    /// Short description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// Caption of the video to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Pass true, if the caption must be shown above the message media
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub show_caption_above_media: bool,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the video
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultCachedVideo<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        video_file_id: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            video_file_id: video_file_id.into(),
+            title: title.into(),
+            description: None,
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            show_caption_above_media: false,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to a voice message stored on the Telegram servers. By default, this voice message will be sent by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultCachedVoice<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid file identifier for the voice message
+    pub voice_file_id: Cow<'a, str>,
+
+    /// Voice message title
+    pub title: Cow<'a, str>,
+
+    /// Caption of the voice message to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the voice message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultCachedVoice<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        voice_file_id: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            voice_file_id: voice_file_id.into(),
+            title: title.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Link to an MP3 audio file stored on the Telegram servers. By default, this audio file will be sent by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQueryResultCachedAudio<'a> {
+    /// Unique identifier for this result, 1-64 bytes
+    pub id: Cow<'a, str>,
+
+    /// A valid file identifier for the audio file
+    pub audio_file_id: Cow<'a, str>,
+
+    /// Caption of the audio to be sent, 0-1024 characters after entities parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Inline keyboard attached to the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+
+    /// Content of the message to be sent instead of the audio
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_message_content: Option<InputMessageContent<'a>>,
+}
+
+impl<'a> InlineQueryResultCachedAudio<'a> {
+    pub fn new(id: impl Into<Cow<'a, str>>, audio_file_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            id: id.into(),
+            audio_file_id: audio_file_id.into(),
+            caption: None,
+            parse_mode: None,
+            caption_entities: None,
+            reply_markup: None,
+            input_message_content: None,
+        }
+    }
+}
+
+/// Represents the content of a text message to be sent as the result of an inline query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputTextMessageContent<'a> {
+    /// Text of the message to be sent, 1-4096 characters
+    pub message_text: Cow<'a, str>,
+
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
+    /// if you want Telegram apps to show
+    /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+
+    /// List of special entities that appear in message text, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Cow<'a, [MessageEntity]>>,
+
+    /// Disables link previews for links in the sent message
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub disable_web_page_preview: bool,
+}
+
+impl<'a> InputTextMessageContent<'a> {
+    pub fn new(message_text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            message_text: message_text.into(),
+            parse_mode: None,
+            entities: None,
+            disable_web_page_preview: false,
+        }
+    }
+}
+
+/// Represents the content of an invoice message to be sent as the result of an inline query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputInvoiceMessageContent<'a> {
+    /// Product name, 1-32 characters
+    pub title: Cow<'a, str>,
+
+    /// Product description, 1-255 characters
+    pub description: Cow<'a, str>,
+
+    /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the user, use it for your internal processes
+    pub payload: Cow<'a, str>,
+
+    /// Payment provider token. Pass `None` for payments in Telegram Stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<Cow<'a, str>>,
+
+    /// Three-letter ISO 4217 currency code
+    pub currency: Cow<'a, str>,
+
+    /// Price breakdown (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.)
+    pub prices: Cow<'a, [LabeledPrice<'a>]>,
+
+    /// The maximum accepted amount for tips in the smallest units of the currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+
+    /// Suggested amounts of tip in the smallest units of the currency, at most 4
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Cow<'a, [i64]>>,
+
+    /// JSON-serialized data about the invoice, which will be shared with the payment provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<Cow<'a, str>>,
+
+    /// Url of the product photo for the invoice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<Cow<'a, str>>,
+
+    /// Photo size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i32>,
+
+    /// Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i32>,
+
+    /// Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i32>,
+
+    /// Pass true, if you require the user's full name to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_name: bool,
+
+    /// Pass true, if you require the user's phone number to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_phone_number: bool,
+
+    /// Pass true, if you require the user's email address to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_email: bool,
+
+    /// Pass true, if you require the user's shipping address to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_shipping_address: bool,
+
+    /// Pass true, if the user's phone number should be sent to provider
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub send_phone_number_to_provider: bool,
+
+    /// Pass true, if the user's email address should be sent to provider
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub send_email_to_provider: bool,
+
+    /// Pass true, if the final price depends on the shipping method
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub is_flexible: bool,
+}
+
+impl<'a> InputInvoiceMessageContent<'a> {
+    pub fn new(
+        title: impl Into<Cow<'a, str>>,
+        description: impl Into<Cow<'a, str>>,
+        payload: impl Into<Cow<'a, str>>,
+        currency: impl Into<Cow<'a, str>>,
+        prices: impl Into<Cow<'a, [LabeledPrice<'a>]>>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            currency: currency.into(),
+            prices: prices.into(),
+            provider_token: None,
+            max_tip_amount: None,
+            suggested_tip_amounts: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: false,
+            need_phone_number: false,
+            need_email: false,
+            need_shipping_address: false,
+            send_phone_number_to_provider: false,
+            send_email_to_provider: false,
+            is_flexible: false,
+        }
+    }
+}
+
+/// Represents the content of a venue message to be sent as the result of an inline query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputVenueMessageContent<'a> {
+    /// Latitude of the venue in degrees
+    pub latitude: f64,
+
+    /// Longitude of the venue in degrees
+    pub longitude: f64,
+
+    /// Name of the venue
+    pub title: Cow<'a, str>,
+
+    /// Address of the venue
+    pub address: Cow<'a, str>,
+
+    /// Foursquare identifier of the venue, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_id: Option<Cow<'a, str>>,
+
+    /// Foursquare type of the venue, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foursquare_type: Option<Cow<'a, str>>,
+
+    /// Google Places identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_id: Option<Cow<'a, str>>,
+
+    /// Google Places type of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub google_place_type: Option<Cow<'a, str>>,
+}
+
+impl<'a> InputVenueMessageContent<'a> {
+    pub fn new(
+        latitude: f64,
+        longitude: f64,
+        title: impl Into<Cow<'a, str>>,
+        address: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            latitude,
+            longitude,
+            title: title.into(),
+            address: address.into(),
+            foursquare_id: None,
+            foursquare_type: None,
+            google_place_id: None,
+            google_place_type: None,
+        }
+    }
+}
+
+/// Represents the content of a location message to be sent as the result of an inline query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputLocationMessageContent {
+    /// Latitude in degrees
+    pub latitude: f64,
+
+    /// Longitude in degrees
+    pub longitude: f64,
+
+    /// The radius of uncertainty for the location, measured in meters; 0-1500
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_accuracy: Option<f64>,
+
+    /// Period in seconds during which the location can be updated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live_period: Option<i32>,
+
+    /// For live locations, a direction in which the user is moving, in degrees; 1-360
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<i32>,
+
+    /// For live locations, a maximum distance for proximity alerts about approaching another chat member, in meters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proximity_alert_radius: Option<i32>,
+}
+
+impl InputLocationMessageContent {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            horizontal_accuracy: None,
+            live_period: None,
+            heading: None,
+            proximity_alert_radius: None,
+        }
+    }
+}
+
+/// Represents the content of a contact message to be sent as the result of an inline query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InputContactMessageContent<'a> {
+    /// Contact's phone number
+    pub phone_number: Cow<'a, str>,
+
+    /// Contact's first name
+    pub first_name: Cow<'a, str>,
+
+    /// Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<Cow<'a, str>>,
+
+    /// Additional data about the contact in the form of a vCard, 0-2048 bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vcard: Option<Cow<'a, str>>,
+}
+
+impl<'a> InputContactMessageContent<'a> {
+    pub fn new(phone_number: impl Into<Cow<'a, str>>, first_name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
+            last_name: None,
+            vcard: None,
+        }
+    }
+}
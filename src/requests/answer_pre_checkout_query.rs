@@ -0,0 +1,55 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Once the user has confirmed their payment and shipping details, the Bot API sends the final confirmation
+/// in the form of an `Update` with the field `pre_checkout_query`. Use this struct to respond to such
+/// pre-checkout queries. On success, `True` is returned.
+/// **Note:** The Bot API must receive an answer within 10 seconds after the pre-checkout query was sent.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnswerPreCheckoutQuery<'a> {
+    /// Unique identifier for the query to be answered
+    pub pre_checkout_query_id: Cow<'a, str>,
+
+    /// Specify true if everything is alright (goods are available, etc.) and the bot is ready to proceed with the order.
+    /// Use false if there are any problems
+    pub ok: bool,
+
+    /// Required if `ok` is false. Error message in human readable form that explains the reason for failure
+    /// to proceed with the checkout. Telegram will display this message to the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for AnswerPreCheckoutQuery<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "answerPreCheckoutQuery"
+    }
+}
+
+impl<'a> AnswerPreCheckoutQuery<'a> {
+    /// The bot is ready to proceed with the order
+    pub fn new_ok(pre_checkout_query_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            pre_checkout_query_id: pre_checkout_query_id.into(),
+            ok: true,
+            error_message: None,
+        }
+    }
+
+    /// The order can't be completed, `error_message` explains why
+    pub fn new_error(
+        pre_checkout_query_id: impl Into<Cow<'a, str>>,
+        error_message: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            pre_checkout_query_id: pre_checkout_query_id.into(),
+            ok: false,
+            error_message: Some(error_message.into()),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::{LabeledPrice, Request};
+
+/// If you sent an invoice requesting a shipping address and the parameter `is_flexible` was specified,
+/// the Bot API will send an `Update` with a `shipping_query` field to the bot.
+/// Use this struct to reply to shipping queries. On success, `True` is returned.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AnswerShippingQuery<'a> {
+    /// Unique identifier for the query to be answered
+    pub shipping_query_id: Cow<'a, str>,
+
+    /// Pass true if delivery to the specified address is possible and false if there are any problems
+    pub ok: bool,
+
+    /// Required if `ok` is true. Available shipping options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_options: Option<Cow<'a, [ShippingOption<'a>]>>,
+
+    /// Required if `ok` is false. Error message in human readable form that explains why it is impossible
+    /// to complete the order (e.g. "Sorry, delivery to your desired address is unavailable").
+    /// Telegram will display this message to the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for AnswerShippingQuery<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "answerShippingQuery"
+    }
+}
+
+impl<'a> AnswerShippingQuery<'a> {
+    /// Delivery is possible with the given `shipping_options`
+    pub fn new_ok(
+        shipping_query_id: impl Into<Cow<'a, str>>,
+        shipping_options: impl Into<Cow<'a, [ShippingOption<'a>]>>,
+    ) -> Self {
+        Self {
+            shipping_query_id: shipping_query_id.into(),
+            ok: true,
+            shipping_options: Some(shipping_options.into()),
+            error_message: None,
+        }
+    }
+
+    /// Delivery is impossible, `error_message` explains why
+    pub fn new_error(
+        shipping_query_id: impl Into<Cow<'a, str>>,
+        error_message: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            shipping_query_id: shipping_query_id.into(),
+            ok: false,
+            shipping_options: None,
+            error_message: Some(error_message.into()),
+        }
+    }
+}
+
+/// This object represents one shipping option
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ShippingOption<'a> {
+    /// Shipping option identifier
+    pub id: Cow<'a, str>,
+
+    /// Option title
+    pub title: Cow<'a, str>,
+
+    /// List of price portions
+    pub prices: Cow<'a, [LabeledPrice<'a>]>,
+}
+
+impl<'a> ShippingOption<'a> {
+    pub fn new(
+        id: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+        prices: impl Into<Cow<'a, [LabeledPrice<'a>]>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            prices: prices.into(),
+        }
+    }
+}
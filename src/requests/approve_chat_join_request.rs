@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::requests::{ChatId, Request};
+
+/// Use this struct to approve a chat join request. The bot must be an administrator in the chat for this to work
+/// and must have the `can_invite_users` administrator right. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ApproveChatJoinRequest<'a> {
+    /// Unique identifier for the target chat or username of the target channel
+    pub chat_id: ChatId<'a>,
+
+    /// Unique identifier of the target user
+    pub user_id: i64,
+}
+
+impl<'a> Request for ApproveChatJoinRequest<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "approveChatJoinRequest"
+    }
+}
+
+impl<'a> ApproveChatJoinRequest<'a> {
+    pub fn new(chat_id: impl Into<ChatId<'a>>, user_id: i64) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            user_id,
+        }
+    }
+}
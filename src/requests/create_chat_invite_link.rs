@@ -0,0 +1,63 @@
+use std::{borrow::Cow, ops::Not};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{ChatId, Request},
+    responses::ChatInviteLink,
+};
+
+/// Use this struct to create an additional invite link for a chat. The bot must be an administrator in the chat
+/// for this to work and must have the appropriate administrator rights. The link can be revoked using `RevokeChatInviteLink`.
+/// Returns the new invite link as `ChatInviteLink` object.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateChatInviteLink<'a> {
+    /// Unique identifier for the target chat or username of the target channel
+    pub chat_id: ChatId<'a>,
+
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Cow<'a, str>>,
+
+    /// Point in time (Unix timestamp) when the link will expire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+
+    /// The maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999. Can't be used with `creates_join_request`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i32>,
+
+    /// True, if users joining the chat via the link need to be approved by chat administrators.
+    /// If true, `member_limit` can't be specified
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub creates_join_request: bool,
+}
+
+impl<'a> Request for CreateChatInviteLink<'a> {
+    type ResponseType = ChatInviteLink;
+
+    fn method(&self) -> &'static str {
+        "createChatInviteLink"
+    }
+}
+
+impl<'a> CreateChatInviteLink<'a> {
+    pub fn new(chat_id: impl Into<ChatId<'a>>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            name: None,
+            expire_date: None,
+            member_limit: None,
+            creates_join_request: false,
+        }
+    }
+
+    /// Link which sends a `ChatJoinRequest` to the bot instead of adding the user to the chat
+    pub fn new_join_request(chat_id: impl Into<ChatId<'a>>) -> Self {
+        Self {
+            creates_join_request: true,
+            ..Self::new(chat_id)
+        }
+    }
+}
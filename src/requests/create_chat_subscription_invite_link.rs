@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{ChatId, Request, STARS_SUBSCRIPTION_PERIOD},
+    responses::ChatInviteLink,
+};
+
+/// Use this struct to create a [subscription invite link](https://telegram.org/blog/superchannels-star-reactions-subscriptions#star-subscriptions)
+/// for a channel chat. The bot must have the `can_invite_users` administrator rights. The link can be edited using
+/// `EditChatSubscriptionInviteLink` or revoked using `RevokeChatInviteLink`. Returns the new invite link as a `ChatInviteLink` object.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateChatSubscriptionInviteLink<'a> {
+    /// Unique identifier for the target channel chat or username of the target channel
+    pub chat_id: ChatId<'a>,
+
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Cow<'a, str>>,
+
+    /// The number of seconds the subscription will be active for before the next payment.
+    /// Currently, it must always be 2592000 (30 days)
+    pub subscription_period: i32,
+
+    /// The amount of Telegram Stars a user must pay initially and after each subsequent subscription period
+    /// to be a member of the chat; 1-10000
+    pub subscription_price: i64,
+}
+
+impl<'a> Request for CreateChatSubscriptionInviteLink<'a> {
+    type ResponseType = ChatInviteLink;
+
+    fn method(&self) -> &'static str {
+        "createChatSubscriptionInviteLink"
+    }
+}
+
+impl<'a> CreateChatSubscriptionInviteLink<'a> {
+    /// Link for a subscription that charges `subscription_price` Telegram Stars every 30 days
+    pub fn new(chat_id: impl Into<ChatId<'a>>, subscription_price: i64) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            name: None,
+            subscription_period: STARS_SUBSCRIPTION_PERIOD,
+            subscription_price,
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::{borrow::Cow, ops::Not};
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::{LabeledPrice, Request, STARS_CURRENCY, STARS_SUBSCRIPTION_PERIOD};
+
+/// Use this struct to create a link for an invoice. Returns the created invoice link as `String` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateInvoiceLink<'a> {
+    /// Product name, 1-32 characters
+    pub title: Cow<'a, str>,
+
+    /// Product description, 1-255 characters
+    pub description: Cow<'a, str>,
+
+    /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the user,
+    /// use it for your internal processes
+    pub payload: Cow<'a, str>,
+
+    /// Payment provider token, obtained via [@BotFather](https://t.me/botfather).
+    /// Pass `None` for payments in Telegram Stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<Cow<'a, str>>,
+
+    /// Three-letter ISO 4217 currency code, see [more on currencies](https://core.telegram.org/bots/payments#supported-currencies).
+    /// Pass “XTR” for payments in Telegram Stars
+    pub currency: Cow<'a, str>,
+
+    /// Price breakdown (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.).
+    /// Must contain exactly one item for payments in Telegram Stars
+    pub prices: Cow<'a, [LabeledPrice<'a>]>,
+
+    /// The number of seconds the subscription will be active for before the next payment.
+    /// Currently, it must always be 2592000 (30 days) and only Telegram Stars are supported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_period: Option<i32>,
+
+    /// The maximum accepted amount for tips in the smallest units of the currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+
+    /// Suggested amounts of tips in the smallest units of the currency, at most 4
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Cow<'a, [i64]>>,
+
+    /// JSON-serialized data about the invoice, which will be shared with the payment provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<Cow<'a, str>>,
+
+    /// Url of the product photo for the invoice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<Cow<'a, str>>,
+
+    /// Photo size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i32>,
+
+    /// Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i32>,
+
+    /// Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i32>,
+
+    /// Pass true, if you require the user's full name to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_name: bool,
+
+    /// Pass true, if you require the user's phone number to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_phone_number: bool,
+
+    /// Pass true, if you require the user's email address to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_email: bool,
+
+    /// Pass true, if you require the user's shipping address to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_shipping_address: bool,
+
+    /// Pass true, if the user's phone number should be sent to provider
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub send_phone_number_to_provider: bool,
+
+    /// Pass true, if the user's email address should be sent to provider
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub send_email_to_provider: bool,
+
+    /// Pass true, if the final price depends on the shipping method
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub is_flexible: bool,
+}
+
+impl<'a> Request for CreateInvoiceLink<'a> {
+    type ResponseType = String;
+
+    fn method(&self) -> &'static str {
+        "createInvoiceLink"
+    }
+}
+
+impl<'a> CreateInvoiceLink<'a> {
+    pub fn new(
+        title: impl Into<Cow<'a, str>>,
+        description: impl Into<Cow<'a, str>>,
+        payload: impl Into<Cow<'a, str>>,
+        currency: impl Into<Cow<'a, str>>,
+        prices: impl Into<Cow<'a, [LabeledPrice<'a>]>>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: None,
+            currency: currency.into(),
+            prices: prices.into(),
+            subscription_period: None,
+            max_tip_amount: None,
+            suggested_tip_amounts: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: false,
+            need_phone_number: false,
+            need_email: false,
+            need_shipping_address: false,
+            send_phone_number_to_provider: false,
+            send_email_to_provider: false,
+            is_flexible: false,
+        }
+    }
+
+    /// Invoice link for a payment of `amount` Telegram Stars
+    pub fn new_stars(
+        title: impl Into<Cow<'a, str>>,
+        description: impl Into<Cow<'a, str>>,
+        payload: impl Into<Cow<'a, str>>,
+        amount: i64,
+    ) -> Self {
+        let title = title.into();
+        let prices = vec![LabeledPrice::new(title.clone(), amount)];
+        Self::new(title, description, payload, STARS_CURRENCY, prices)
+    }
+
+    /// Invoice link for a subscription that charges `amount` Telegram Stars every 30 days
+    pub fn new_stars_subscription(
+        title: impl Into<Cow<'a, str>>,
+        description: impl Into<Cow<'a, str>>,
+        payload: impl Into<Cow<'a, str>>,
+        amount: i64,
+    ) -> Self {
+        Self {
+            subscription_period: Some(STARS_SUBSCRIPTION_PERIOD),
+            ..Self::new_stars(title, description, payload, amount)
+        }
+    }
+}
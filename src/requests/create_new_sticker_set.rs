@@ -0,0 +1,83 @@
+use std::{borrow::Cow, ops::Not};
+
+use hyper::Body;
+use hyper_multipart_rfc7578::client::multipart::Form;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    requests::{
+        add_fields_to_form, add_file_to_form, add_form_body, add_json_body, InputSticker, Request,
+    },
+    responses::StickerType,
+};
+
+/// Use this struct to create a new sticker set owned by a user. The bot will be able to edit
+/// the sticker set thus created. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateNewStickerSet<'a> {
+    /// User identifier of created sticker set owner
+    pub user_id: i64,
+
+    /// Short name of sticker set, to be used in `t.me/addstickers/` URLs (e.g., animals).
+    /// Can contain only English letters, digits and underscores. Must begin with a letter,
+    /// can't contain consecutive underscores and must end in `_by_<bot_username>`. 1-64 characters
+    pub name: Cow<'a, str>,
+
+    /// Sticker set title, 1-64 characters
+    pub title: Cow<'a, str>,
+
+    /// List of 1-50 initial stickers to be added to the sticker set
+    pub stickers: Cow<'a, [InputSticker<'a>]>,
+
+    /// Type of stickers in the set. By default, a regular sticker set is created
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker_type: Option<StickerType>,
+
+    /// Pass true if stickers in the sticker set must be repainted to the color of text when used in messages.
+    /// For custom emoji sticker sets only
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub needs_repainting: bool,
+}
+
+impl<'a> Request for CreateNewStickerSet<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "createNewStickerSet"
+    }
+
+    fn set_http_request_body(
+        self,
+        request_builder: hyper::http::request::Builder,
+    ) -> Result<hyper::http::request::Request<Body>, Error> {
+        if self.stickers.iter().any(|x| x.sticker.is_input_file()) {
+            let mut form = Form::default();
+            add_fields_to_form(&mut form, &self)?;
+            for sticker in self.stickers.into_owned() {
+                add_file_to_form(&mut form, sticker.sticker, None);
+            }
+            add_form_body(request_builder, form)
+        } else {
+            add_json_body(request_builder, &self)
+        }
+    }
+}
+
+impl<'a> CreateNewStickerSet<'a> {
+    pub fn new(
+        user_id: i64,
+        name: impl Into<Cow<'a, str>>,
+        title: impl Into<Cow<'a, str>>,
+        stickers: impl Into<Cow<'a, [InputSticker<'a>]>>,
+    ) -> Self {
+        Self {
+            user_id,
+            name: name.into(),
+            title: title.into(),
+            stickers: stickers.into(),
+            sticker_type: None,
+            needs_repainting: false,
+        }
+    }
+}
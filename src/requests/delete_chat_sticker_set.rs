@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::requests::{ChatId, Request};
 
@@ -6,7 +6,7 @@ use crate::requests::{ChatId, Request};
 /// The bot must be an administrator in the chat for this to work and must have the appropriate
 /// admin rights. Use the field can_set_sticker_set optionally returned in
 /// getChat requests to check if the bot can use this method. Returns `True` on success.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DeleteChatStickerSet<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
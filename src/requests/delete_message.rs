@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::requests::{ChatId, Request};
 
@@ -9,8 +9,9 @@ use crate::requests::{ChatId, Request};
 /// - Bots granted can_post_messages permissions can delete outgoing messages in channels.
 /// - If the bot is an administrator of a group, it can delete any message there.
 /// - If the bot has can_delete_messages permission in a supergroup or a channel, it can delete any message there.
+///
 /// Returns `True` on success.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct DeleteMessage<'a> {
     /// Identifier for the target chat
     pub chat_id: ChatId<'a>,
@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::{BotCommandScope, Request};
+
+/// Use this struct to delete the list of the bot's commands for the given scope and user language.
+/// After deletion, higher level commands will be shown to affected users. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DeleteMyCommands<'a> {
+    /// Scope of users for which the commands are relevant. Defaults to `BotCommandScope::Default`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope<'a>>,
+
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied to all users from the given scope,
+    /// for whose language there are no dedicated commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for DeleteMyCommands<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "deleteMyCommands"
+    }
+}
+
+impl<'a> DeleteMyCommands<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn new_scoped(scope: BotCommandScope<'a>, language_code: Option<Cow<'a, str>>) -> Self {
+        Self {
+            scope: Some(scope),
+            language_code,
+        }
+    }
+}
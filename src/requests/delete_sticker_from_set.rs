@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to delete a sticker from a set created by the bot. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeleteStickerFromSet<'a> {
+    /// File identifier of the sticker
+    pub sticker: Cow<'a, str>,
+}
+
+impl<'a> Request for DeleteStickerFromSet<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "deleteStickerFromSet"
+    }
+}
+
+impl<'a> DeleteStickerFromSet<'a> {
+    pub fn new(sticker: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            sticker: sticker.into(),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to delete a sticker set that was created by the bot. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeleteStickerSet<'a> {
+    /// Sticker set name
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> Request for DeleteStickerSet<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "deleteStickerSet"
+    }
+}
+
+impl<'a> DeleteStickerSet<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self { name: name.into() }
+    }
+}
@@ -0,0 +1,58 @@
+use std::{borrow::Cow, ops::Not};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{ChatId, Request},
+    responses::ChatInviteLink,
+};
+
+/// Use this struct to edit a non-primary invite link created by the bot. The bot must be an administrator in the chat
+/// for this to work and must have the appropriate administrator rights. Returns the edited invite link as a `ChatInviteLink` object.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EditChatInviteLink<'a> {
+    /// Unique identifier for the target chat or username of the target channel
+    pub chat_id: ChatId<'a>,
+
+    /// The invite link to edit
+    pub invite_link: Cow<'a, str>,
+
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Cow<'a, str>>,
+
+    /// Point in time (Unix timestamp) when the link will expire
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+
+    /// The maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999. Can't be used with `creates_join_request`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i32>,
+
+    /// True, if users joining the chat via the link need to be approved by chat administrators.
+    /// If true, `member_limit` can't be specified
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub creates_join_request: bool,
+}
+
+impl<'a> Request for EditChatInviteLink<'a> {
+    type ResponseType = ChatInviteLink;
+
+    fn method(&self) -> &'static str {
+        "editChatInviteLink"
+    }
+}
+
+impl<'a> EditChatInviteLink<'a> {
+    pub fn new(chat_id: impl Into<ChatId<'a>>, invite_link: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            invite_link: invite_link.into(),
+            name: None,
+            expire_date: None,
+            member_limit: None,
+            creates_join_request: false,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{ChatId, Request},
+    responses::ChatInviteLink,
+};
+
+/// Use this struct to edit a subscription invite link created by the bot. The bot must have the `can_invite_users`
+/// administrator rights. Returns the edited invite link as a `ChatInviteLink` object.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EditChatSubscriptionInviteLink<'a> {
+    /// Unique identifier for the target chat or username of the target channel
+    pub chat_id: ChatId<'a>,
+
+    /// The invite link to edit
+    pub invite_link: Cow<'a, str>,
+
+    /// Invite link name; 0-32 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for EditChatSubscriptionInviteLink<'a> {
+    type ResponseType = ChatInviteLink;
+
+    fn method(&self) -> &'static str {
+        "editChatSubscriptionInviteLink"
+    }
+}
+
+impl<'a> EditChatSubscriptionInviteLink<'a> {
+    pub fn new(
+        chat_id: impl Into<ChatId<'a>>,
+        invite_link: impl Into<Cow<'a, str>>,
+        name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            invite_link: invite_link.into(),
+            name: Some(name.into()),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use serde::Serialize;
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, MessageOrInlineMessageId, ReplyMarkup, Request},
@@ -9,7 +11,7 @@ use crate::{
 /// A location can be edited until its live_period expires or editing is explicitly disabled by a
 /// call to `StopMessageLiveLocation`. On success, if the edited message was sent by the bot,
 /// the edited `EditLiveLocationResponse::Message` is returned, otherwise `EditLiveLocationResponse::True` is returned
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EditLiveLocation<'a> {
     /// Identifier of message in chat or identifier of inline message
     #[serde(flatten)]
@@ -35,9 +37,15 @@ impl<'a> Request for EditLiveLocation<'a> {
 }
 
 impl<'a> EditLiveLocation<'a> {
-    pub fn new_inline_message(inline_message_id: &'a str, latitude: f64, longitude: f64) -> Self {
+    pub fn new_inline_message(
+        inline_message_id: impl Into<Cow<'a, str>>,
+        latitude: f64,
+        longitude: f64,
+    ) -> Self {
         Self {
-            message_or_inline_message_id: MessageOrInlineMessageId::Inline { inline_message_id },
+            message_or_inline_message_id: MessageOrInlineMessageId::Inline {
+                inline_message_id: inline_message_id.into(),
+            },
             latitude,
             longitude,
             reply_markup: None,
@@ -1,27 +1,34 @@
-use serde::Serialize;
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    formatting::{FormattedText, Markup},
     requests::{ChatId, MessageOrInlineMessageId, ParseMode, ReplyMarkup, Request},
-    responses::EditedMessage,
+    responses::{EditedMessage, MessageEntity},
 };
 
 /// Use this struct to edit captions of messages. On success,
 /// if edited message is sent by the bot, the edited `Message` is returned, otherwise `True` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EditMessageCaption<'a> {
     /// Identifier of message in chat or identifier of inline message
     #[serde(flatten)]
     pub message_or_inline_message_id: MessageOrInlineMessageId<'a>,
 
     /// New caption of the message.
-    pub caption: Option<&'a str>,
+    pub caption: Option<Cow<'a, str>>,
 
-    /// Send `ParseMode::Markdown` or `ParseMode::Html`,
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
     /// if you want Telegram apps to show
     /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in your bot's message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
 
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
     /// Additional interface options.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_markup: Option<ReplyMarkup<'a>>,
@@ -36,24 +43,57 @@ impl<'a> Request for EditMessageCaption<'a> {
 }
 
 impl<'a> EditMessageCaption<'a> {
-    pub fn new_inline_message(inline_message_id: &'a str, caption: &'a str) -> Self {
+    pub fn new_inline_message(
+        inline_message_id: impl Into<Cow<'a, str>>,
+        caption: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
-            message_or_inline_message_id: MessageOrInlineMessageId::Inline { inline_message_id },
-            caption: Some(caption),
+            message_or_inline_message_id: MessageOrInlineMessageId::Inline {
+                inline_message_id: inline_message_id.into(),
+            },
+            caption: Some(caption.into()),
             parse_mode: None,
+            caption_entities: None,
             reply_markup: None,
         }
     }
 
-    pub fn new_message(chat_id: impl Into<ChatId<'a>>, message_id: i64, caption: &'a str) -> Self {
+    pub fn new_message(
+        chat_id: impl Into<ChatId<'a>>,
+        message_id: i64,
+        caption: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
             message_or_inline_message_id: MessageOrInlineMessageId::Chat {
                 chat_id: chat_id.into(),
                 message_id,
             },
-            caption: Some(caption),
+            caption: Some(caption.into()),
             parse_mode: None,
+            caption_entities: None,
             reply_markup: None,
         }
     }
+
+    /// Set caption built by `formatting::MarkupBuilder`
+    pub fn with_caption_markup(self, markup: impl Into<Cow<'a, Markup>>) -> Self {
+        let (caption, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: Some(parse_mode),
+            caption_entities: None,
+            ..self
+        }
+    }
+
+    /// Set caption built with `formatting::FormattedText`
+    pub fn with_formatted_caption(self, formatted: impl Into<Cow<'a, FormattedText>>) -> Self {
+        let (caption, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: None,
+            caption_entities: Some(entities),
+            ..self
+        }
+    }
 }
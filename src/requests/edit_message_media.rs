@@ -1,6 +1,8 @@
+use std::borrow::Cow;
+
 use hyper::Body;
 use hyper_multipart_rfc7578::client::multipart::Form;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     error::Error,
@@ -16,7 +18,7 @@ use crate::{
 /// Otherwise, message type can be changed arbitrarily. When inline message is edited, new file
 /// can't be uploaded. Use previously uploaded file via its file_id or specify a URL. On success,
 /// if the edited message was sent by the bot, the edited `Message` is returned, otherwise `True` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EditMessageMedia<'a> {
     /// Identifier of message in chat or identifier of inline message
     #[serde(flatten)]
@@ -53,9 +55,14 @@ impl<'a> Request for EditMessageMedia<'a> {
 }
 
 impl<'a> EditMessageMedia<'a> {
-    pub fn new_inline_message(inline_message_id: &'a str, media: InputMedia<'a>) -> Self {
+    pub fn new_inline_message(
+        inline_message_id: impl Into<Cow<'a, str>>,
+        media: InputMedia<'a>,
+    ) -> Self {
         Self {
-            message_or_inline_message_id: MessageOrInlineMessageId::Inline { inline_message_id },
+            message_or_inline_message_id: MessageOrInlineMessageId::Inline {
+                inline_message_id: inline_message_id.into(),
+            },
             media,
             reply_markup: None,
         }
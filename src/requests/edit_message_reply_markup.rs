@@ -1,4 +1,6 @@
-use serde::Serialize;
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, MessageOrInlineMessageId, ReplyMarkup, Request},
@@ -7,7 +9,7 @@ use crate::{
 
 /// Use this struct to edit only the reply markup of messages.
 /// On success, if edited message is sent by the bot, the edited `Message` is returned, otherwise `True` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EditMessageReplyMarkup<'a> {
     /// Identifier of message in chat or identifier of inline message
     #[serde(flatten)]
@@ -27,9 +29,14 @@ impl<'a> Request for EditMessageReplyMarkup<'a> {
 }
 
 impl<'a> EditMessageReplyMarkup<'a> {
-    pub fn new_inline_message(inline_message_id: &'a str, reply_markup: ReplyMarkup<'a>) -> Self {
+    pub fn new_inline_message(
+        inline_message_id: impl Into<Cow<'a, str>>,
+        reply_markup: ReplyMarkup<'a>,
+    ) -> Self {
         Self {
-            message_or_inline_message_id: MessageOrInlineMessageId::Inline { inline_message_id },
+            message_or_inline_message_id: MessageOrInlineMessageId::Inline {
+                inline_message_id: inline_message_id.into(),
+            },
             reply_markup: Some(reply_markup),
         }
     }
@@ -1,31 +1,36 @@
-use std::ops::Not;
+use std::{borrow::Cow, ops::Not};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    formatting::{FormattedText, Markup},
     requests::{ChatId, MessageOrInlineMessageId, ParseMode, ReplyMarkup, Request},
-    responses::EditedMessage,
+    responses::{EditedMessage, MessageEntity},
 };
 
 /// Use this struct to edit text and game messages. On success, if edited message is sent by the bot, the edited
 /// `Message `is returned, otherwise `True` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EditMessageText<'a> {
     /// Identifier of message in chat or identifier of inline message
     #[serde(flatten)]
     pub message_or_inline_message_id: MessageOrInlineMessageId<'a>,
 
     /// New text of the message.
-    pub text: &'a str,
+    pub text: Cow<'a, str>,
 
-    /// Send `ParseMode::Markdown` or `ParseMode::Html`,
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
     /// if you want Telegram apps to show
     /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in your bot's message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
 
+    /// List of special entities that appear in the message text, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Cow<'a, [MessageEntity]>>,
+
     /// Disables link previews for links in this message
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_web_page_preview: bool,
 
     /// Additional interface options.
@@ -42,26 +47,89 @@ impl<'a> Request for EditMessageText<'a> {
 }
 
 impl<'a> EditMessageText<'a> {
-    pub fn new_inline_message(inline_message_id: &'a str, text: &'a str) -> Self {
+    pub fn new_inline_message(
+        inline_message_id: impl Into<Cow<'a, str>>,
+        text: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
-            message_or_inline_message_id: MessageOrInlineMessageId::Inline { inline_message_id },
-            text,
+            message_or_inline_message_id: MessageOrInlineMessageId::Inline {
+                inline_message_id: inline_message_id.into(),
+            },
+            text: text.into(),
             parse_mode: None,
+            entities: None,
             disable_web_page_preview: false,
             reply_markup: None,
         }
     }
 
-    pub fn new_message(chat_id: impl Into<ChatId<'a>>, message_id: i64, text: &'a str) -> Self {
+    pub fn new_message(
+        chat_id: impl Into<ChatId<'a>>,
+        message_id: i64,
+        text: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
             message_or_inline_message_id: MessageOrInlineMessageId::Chat {
                 chat_id: chat_id.into(),
                 message_id,
             },
-            text,
+            text: text.into(),
             parse_mode: None,
+            entities: None,
             disable_web_page_preview: false,
             reply_markup: None,
         }
     }
+
+    /// Edit inline message with text built by `formatting::MarkupBuilder`
+    pub fn new_inline_message_markup(
+        inline_message_id: impl Into<Cow<'a, str>>,
+        markup: impl Into<Cow<'a, Markup>>,
+    ) -> Self {
+        let (text, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            parse_mode: Some(parse_mode),
+            entities: None,
+            ..Self::new_inline_message(inline_message_id, text)
+        }
+    }
+
+    /// Edit message with text built by `formatting::MarkupBuilder`
+    pub fn new_message_markup(
+        chat_id: impl Into<ChatId<'a>>,
+        message_id: i64,
+        markup: impl Into<Cow<'a, Markup>>,
+    ) -> Self {
+        let (text, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            parse_mode: Some(parse_mode),
+            entities: None,
+            ..Self::new_message(chat_id, message_id, text)
+        }
+    }
+
+    /// Edit inline message with text and entities built with `formatting::FormattedText`
+    pub fn new_inline_message_formatted(
+        inline_message_id: impl Into<Cow<'a, str>>,
+        formatted: impl Into<Cow<'a, FormattedText>>,
+    ) -> Self {
+        let (text, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            entities: Some(entities),
+            ..Self::new_inline_message(inline_message_id, text)
+        }
+    }
+
+    /// Edit message with text and entities built with `formatting::FormattedText`
+    pub fn new_message_formatted(
+        chat_id: impl Into<ChatId<'a>>,
+        message_id: i64,
+        formatted: impl Into<Cow<'a, FormattedText>>,
+    ) -> Self {
+        let (text, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            entities: Some(entities),
+            ..Self::new_message(chat_id, message_id, text)
+        }
+    }
 }
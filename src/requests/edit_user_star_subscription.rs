@@ -0,0 +1,43 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Allows the bot to cancel or re-enable extension of a subscription paid in Telegram Stars.
+/// Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EditUserStarSubscription<'a> {
+    /// Identifier of the user whose subscription will be edited
+    pub user_id: i64,
+
+    /// Telegram payment identifier for the subscription
+    pub telegram_payment_charge_id: Cow<'a, str>,
+
+    /// Pass true to cancel extension of the user subscription; the subscription must be active up to the end
+    /// of the current subscription period. Pass false to allow the user to re-enable a subscription
+    /// that was previously canceled by the bot
+    pub is_canceled: bool,
+}
+
+impl<'a> Request for EditUserStarSubscription<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "editUserStarSubscription"
+    }
+}
+
+impl<'a> EditUserStarSubscription<'a> {
+    pub fn new(
+        user_id: i64,
+        telegram_payment_charge_id: impl Into<Cow<'a, str>>,
+        is_canceled: bool,
+    ) -> Self {
+        Self {
+            user_id,
+            telegram_payment_charge_id: telegram_payment_charge_id.into(),
+            is_canceled,
+        }
+    }
+}
@@ -1,11 +1,11 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::requests::{ChatId, Request};
 
 /// Use this struct to generate a new invite link for a chat; any previously generated link is revoked.
 /// The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns the new invite link as
 /// `String` on success
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ExportChatInviteLink<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
@@ -1,6 +1,6 @@
 use std::ops::Not;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, Request},
@@ -8,7 +8,7 @@ use crate::{
 };
 
 /// Use this struct to forward messages of any kind. On success, the sent `Message` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ForwardMessage<'a> {
     /// Unique identifier for the target chat
     chat_id: ChatId<'a>,
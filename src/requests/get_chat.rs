@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, Request},
@@ -8,7 +8,7 @@ use crate::{
 /// Use this struct to get up to date information about the chat
 /// (current name of the user for one-on-one
 /// conversations, current username of a user, group or channel, etc.). Returns a `Chat` object on success.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetChat<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, Request},
@@ -9,7 +9,7 @@ use crate::{
 /// returns an Array of `ChatMember` objects that contains information about all chat
 /// administrators except other bots. If the chat is a group or a supergroup and no
 /// administrators were appointed, only the creator will be returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetChatAdministrators<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, Request},
@@ -6,7 +6,7 @@ use crate::{
 };
 
 /// Use this method to get information about a member of a chat. Returns a `ChatMember` object on success.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetChatMember<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::requests::{MenuButton, Request};
+
+/// Use this struct to get the current value of the bot's menu button in a private chat, or the default menu button.
+/// Returns `MenuButton` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+pub struct GetChatMenuButton {
+    /// Unique identifier for the target private chat. If not specified, default bot's menu button will be returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+}
+
+impl Request for GetChatMenuButton {
+    type ResponseType = MenuButton<'static>;
+
+    fn method(&self) -> &'static str {
+        "getChatMenuButton"
+    }
+}
+
+impl GetChatMenuButton {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn new_chat(chat_id: i64) -> Self {
+        Self {
+            chat_id: Some(chat_id),
+        }
+    }
+}
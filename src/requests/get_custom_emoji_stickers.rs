@@ -0,0 +1,29 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::Sticker};
+
+/// Use this struct to get information about custom emoji stickers by their identifiers.
+/// Returns an Array of `Sticker` objects.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetCustomEmojiStickers<'a> {
+    /// List of custom emoji identifiers. At most 200 custom emoji identifiers can be specified
+    pub custom_emoji_ids: Cow<'a, [Cow<'a, str>]>,
+}
+
+impl<'a> Request for GetCustomEmojiStickers<'a> {
+    type ResponseType = Vec<Sticker>;
+
+    fn method(&self) -> &'static str {
+        "getCustomEmojiStickers"
+    }
+}
+
+impl<'a> GetCustomEmojiStickers<'a> {
+    pub fn new(custom_emoji_ids: impl IntoIterator<Item = impl Into<Cow<'a, str>>>) -> Self {
+        Self {
+            custom_emoji_ids: custom_emoji_ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{ChatId, MessageOrInlineMessageId, Request},
+    responses::GameHighScore,
+};
+
+/// Use this struct to get data for high score tables. Will return the score of the specified user
+/// and several of their neighbors in a game. On success, returns an Array of `GameHighScore` objects.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetGameHighScores<'a> {
+    /// Target user id
+    pub user_id: i64,
+
+    /// Identifier of the game message in chat or identifier of the inline message
+    #[serde(flatten)]
+    pub message_or_inline_message_id: MessageOrInlineMessageId<'a>,
+}
+
+impl<'a> Request for GetGameHighScores<'a> {
+    type ResponseType = Vec<GameHighScore>;
+
+    fn method(&self) -> &'static str {
+        "getGameHighScores"
+    }
+}
+
+impl<'a> GetGameHighScores<'a> {
+    pub fn new_inline_message(inline_message_id: impl Into<Cow<'a, str>>, user_id: i64) -> Self {
+        Self {
+            user_id,
+            message_or_inline_message_id: MessageOrInlineMessageId::Inline {
+                inline_message_id: inline_message_id.into(),
+            },
+        }
+    }
+
+    pub fn new_message(chat_id: impl Into<ChatId<'a>>, message_id: i64, user_id: i64) -> Self {
+        Self {
+            user_id,
+            message_or_inline_message_id: MessageOrInlineMessageId::Chat {
+                chat_id: chat_id.into(),
+                message_id,
+            },
+        }
+    }
+}
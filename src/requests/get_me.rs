@@ -1,10 +1,10 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{requests::Request, responses::User};
 
 /// A simple struct for testing your bot's auth token. Requires no parameters.
 /// Returns basic information about the bot in form of a `User` object.
-#[derive(Serialize, Debug, Clone, Copy, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
 pub struct GetMe;
 
 impl Request for GetMe {
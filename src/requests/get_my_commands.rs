@@ -0,0 +1,39 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::{BotCommand, BotCommandScope, Request};
+
+/// Use this struct to get the current list of the bot's commands for the given scope and user language.
+/// Returns an array of `BotCommand` objects. If commands aren't set, an empty list is returned.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GetMyCommands<'a> {
+    /// Scope of users. Defaults to `BotCommandScope::Default`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope<'a>>,
+
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for GetMyCommands<'a> {
+    type ResponseType = Vec<BotCommand<'static>>;
+
+    fn method(&self) -> &'static str {
+        "getMyCommands"
+    }
+}
+
+impl<'a> GetMyCommands<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn new_scoped(scope: BotCommandScope<'a>, language_code: Option<Cow<'a, str>>) -> Self {
+        Self {
+            scope: Some(scope),
+            language_code,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use std::ops::Not;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::ChatAdministratorRights};
+
+/// Use this struct to get the current default administrator rights of the bot.
+/// Returns `ChatAdministratorRights` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+pub struct GetMyDefaultAdministratorRights {
+    /// Pass true to get default administrator rights of the bot in channels.
+    /// Otherwise, default administrator rights of the bot for groups and supergroups will be returned
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub for_channels: bool,
+}
+
+impl Request for GetMyDefaultAdministratorRights {
+    type ResponseType = ChatAdministratorRights;
+
+    fn method(&self) -> &'static str {
+        "getMyDefaultAdministratorRights"
+    }
+}
+
+impl GetMyDefaultAdministratorRights {
+    pub fn new(for_channels: bool) -> Self {
+        Self { for_channels }
+    }
+}
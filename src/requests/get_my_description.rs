@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::BotDescription};
+
+/// Use this struct to get the current bot description for the given user language. Returns `BotDescription` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GetMyDescription<'a> {
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for GetMyDescription<'a> {
+    type ResponseType = BotDescription;
+
+    fn method(&self) -> &'static str {
+        "getMyDescription"
+    }
+}
+
+impl<'a> GetMyDescription<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn new_localized(language_code: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            language_code: Some(language_code.into()),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::BotShortDescription};
+
+/// Use this struct to get the current bot short description for the given user language. Returns `BotShortDescription` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct GetMyShortDescription<'a> {
+    /// A two-letter ISO 639-1 language code or an empty string
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for GetMyShortDescription<'a> {
+    type ResponseType = BotShortDescription;
+
+    fn method(&self) -> &'static str {
+        "getMyShortDescription"
+    }
+}
+
+impl<'a> GetMyShortDescription<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn new_localized(language_code: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            language_code: Some(language_code.into()),
+        }
+    }
+}
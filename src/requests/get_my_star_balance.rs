@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::StarAmount};
+
+/// A method to get the current Telegram Stars balance of the bot. Requires no parameters.
+/// On success, returns a `StarAmount` object.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+pub struct GetMyStarBalance;
+
+impl Request for GetMyStarBalance {
+    type ResponseType = StarAmount;
+
+    fn method(&self) -> &'static str {
+        "getMyStarBalance"
+    }
+}
+
+impl GetMyStarBalance {
+    pub fn new() -> Self {
+        GetMyStarBalance
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::StarTransactions};
+
+/// Use this struct to get the bot's Telegram Star transactions in chronological order.
+/// On success, returns a `StarTransactions` object.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+pub struct GetStarTransactions {
+    /// Number of transactions to skip in the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+
+    /// The maximum number of transactions to be retrieved. Values between 1-100 are accepted. Defaults to 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+impl Request for GetStarTransactions {
+    type ResponseType = StarTransactions;
+
+    fn method(&self) -> &'static str {
+        "getStarTransactions"
+    }
+}
+
+impl GetStarTransactions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Transactions from `offset`, at most `limit` of them
+    pub fn new_page(offset: i64, limit: i64) -> Self {
+        Self {
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+}
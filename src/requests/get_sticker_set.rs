@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::StickerSet};
+
+/// Use this struct to get a sticker set. On success, a `StickerSet` object is returned.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetStickerSet<'a> {
+    /// Name of the sticker set
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> Request for GetStickerSet<'a> {
+    type ResponseType = StickerSet;
+
+    fn method(&self) -> &'static str {
+        "getStickerSet"
+    }
+}
+
+impl<'a> GetStickerSet<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self { name: name.into() }
+    }
+}
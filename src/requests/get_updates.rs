@@ -1,9 +1,11 @@
-use serde::Serialize;
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
 
 use crate::{requests::Request, responses::Update};
 
 /// Use this struct to receive incoming updates using long polling. An Array of `Update` objects is returned.
-#[derive(Serialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct GetUpdates<'a> {
     /// Identifier of the first update to be returned. Must be greater by one than the highest
     /// among the identifiers of previously received updates.
@@ -28,11 +30,11 @@ pub struct GetUpdates<'a> {
     /// See Update for a complete list of available update types. Specify an empty list to receive all updates regardless of type (default).
     /// If not specified, the previous setting will be used.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_updates: Option<&'a [UpdateKind]>,
+    pub allowed_updates: Option<Cow<'a, [UpdateKind]>>,
 }
 
 /// Enumeration of possible update types from telegram bot api
-#[derive(Serialize, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
 pub enum UpdateKind {
     Message,
@@ -44,6 +46,7 @@ pub enum UpdateKind {
     CallbackQuery,
     ShippingQuery,
     PreCheckoutQuery,
+    ChatJoinRequest,
 }
 
 impl<'a> Request for GetUpdates<'a> {
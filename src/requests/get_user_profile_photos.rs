@@ -1,9 +1,9 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{requests::Request, responses::UserProfilePhotos};
 
 /// Use this struct to send text messages. On success, the sent `Message` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GetUserProfilePhotos {
     /// Unique identifier of the target user
     pub user_id: i64,
@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::requests::{ChatId, Request};
 
@@ -9,7 +9,7 @@ use crate::requests::{ChatId, Request};
 ///
 /// Note: In regular groups (non-supergroups), this method will only work if the ‘All Members Are Admins’ setting is
 /// off in the target group. Otherwise members may only be removed by the group's creator or by the member that added them.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct KickChatMember<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
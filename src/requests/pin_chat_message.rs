@@ -1,6 +1,6 @@
 use std::ops::Not;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::requests::{ChatId, Request};
 
@@ -8,7 +8,7 @@ use crate::requests::{ChatId, Request};
 /// The bot must be an administrator in the chat for this to work and must have the ‘can_pin_messages’
 /// admin right in the supergroup or ‘can_edit_messages’ admin right in the channel.
 /// Returns `True` on success.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PinChatMessage<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
@@ -18,7 +18,7 @@ pub struct PinChatMessage<'a> {
 
     /// Pass True, if it is not necessary to send a notification to all chat members about
     /// the new pinned message. Notifications are always disabled in channels..
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_notification: bool,
 }
 
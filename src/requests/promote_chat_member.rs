@@ -1,11 +1,11 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::requests::{ChatId, Request};
 
 /// Use this struct to promote or demote a user in a supergroup or a channel.
 /// The bot must be an administrator in the chat for this to work and must have the appropriate admin rights.
 /// Pass False for all boolean parameters to demote a user. Returns `True` on success
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PromoteChatMember<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Refunds a successful payment in Telegram Stars. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RefundStarPayment<'a> {
+    /// Identifier of the user whose payment will be refunded
+    pub user_id: i64,
+
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: Cow<'a, str>,
+}
+
+impl<'a> Request for RefundStarPayment<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "refundStarPayment"
+    }
+}
+
+impl<'a> RefundStarPayment<'a> {
+    pub fn new(user_id: i64, telegram_payment_charge_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            user_id,
+            telegram_payment_charge_id: telegram_payment_charge_id.into(),
+        }
+    }
+}
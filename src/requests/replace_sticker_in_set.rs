@@ -0,0 +1,69 @@
+use std::borrow::Cow;
+
+use hyper::Body;
+use hyper_multipart_rfc7578::client::multipart::Form;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    requests::{
+        add_fields_to_form, add_file_to_form, add_form_body, add_json_body, InputSticker, Request,
+    },
+};
+
+/// Use this struct to replace an existing sticker in a sticker set with a new one.
+/// The method is equivalent to calling `DeleteStickerFromSet`, then `AddStickerToSet`,
+/// then `SetStickerPositionInSet`. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReplaceStickerInSet<'a> {
+    /// User identifier of the sticker set owner
+    pub user_id: i64,
+
+    /// Sticker set name
+    pub name: Cow<'a, str>,
+
+    /// File identifier of the replaced sticker
+    pub old_sticker: Cow<'a, str>,
+
+    /// Information about the added sticker. If exactly the same sticker had already been added to the set,
+    /// then the set remains unchanged
+    pub sticker: InputSticker<'a>,
+}
+
+impl<'a> Request for ReplaceStickerInSet<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "replaceStickerInSet"
+    }
+
+    fn set_http_request_body(
+        self,
+        request_builder: hyper::http::request::Builder,
+    ) -> Result<hyper::http::request::Request<Body>, Error> {
+        if self.sticker.sticker.is_input_file() {
+            let mut form = Form::default();
+            add_fields_to_form(&mut form, &self)?;
+            add_file_to_form(&mut form, self.sticker.sticker, None);
+            add_form_body(request_builder, form)
+        } else {
+            add_json_body(request_builder, &self)
+        }
+    }
+}
+
+impl<'a> ReplaceStickerInSet<'a> {
+    pub fn new(
+        user_id: i64,
+        name: impl Into<Cow<'a, str>>,
+        old_sticker: impl Into<Cow<'a, str>>,
+        sticker: InputSticker<'a>,
+    ) -> Self {
+        Self {
+            user_id,
+            name: name.into(),
+            old_sticker: old_sticker.into(),
+            sticker,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{ChatId, Request},
+    responses::ChatInviteLink,
+};
+
+/// Use this struct to revoke an invite link created by the bot. If the primary link is revoked, a new link is automatically generated.
+/// The bot must be an administrator in the chat for this to work and must have the appropriate administrator rights.
+/// Returns the revoked invite link as `ChatInviteLink` object.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RevokeChatInviteLink<'a> {
+    /// Unique identifier of the target chat or username of the target channel
+    pub chat_id: ChatId<'a>,
+
+    /// The invite link to revoke
+    pub invite_link: Cow<'a, str>,
+}
+
+impl<'a> Request for RevokeChatInviteLink<'a> {
+    type ResponseType = ChatInviteLink;
+
+    fn method(&self) -> &'static str {
+        "revokeChatInviteLink"
+    }
+}
+
+impl<'a> RevokeChatInviteLink<'a> {
+    pub fn new(chat_id: impl Into<ChatId<'a>>, invite_link: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            invite_link: invite_link.into(),
+        }
+    }
+}
@@ -1,11 +1,11 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::requests::{ChatId, Request};
 
 /// Use this struct when you need to tell the user that something is happening on the bot's side.
 /// The status is set for 5 seconds or less (when a message arrives from your bot, Telegram clients clear its typing status).
 /// Returns `True` on success.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SendChatAction<'a> {
     /// Unique identifier for the target chat
     pub chat_id: ChatId<'a>,
@@ -22,7 +22,7 @@ impl<'a> Request for SendChatAction<'a> {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum ChatAction {
     Typing,
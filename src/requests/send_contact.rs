@@ -1,6 +1,6 @@
-use std::ops::Not;
+use std::{borrow::Cow, ops::Not};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, ReplyMarkup, Request},
@@ -8,29 +8,29 @@ use crate::{
 };
 
 /// Use this struct to send phone contacts. On success, the sent `Message` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SendContact<'a> {
     /// Identifier for the target chat
     pub chat_id: ChatId<'a>,
 
     /// Contact's phone number
-    pub phone_number: &'a str,
+    pub phone_number: Cow<'a, str>,
 
     /// Contact's first name
-    pub first_name: &'a str,
+    pub first_name: Cow<'a, str>,
 
     /// Contact's last name
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_name: Option<&'a str>,
+    pub last_name: Option<Cow<'a, str>>,
 
     /// Additional data about the contact in the form of a
     /// [vCard](https://en.wikipedia.org/wiki/VCard), 0-2048 bytes
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vcard: Option<&'a str>,
+    pub vcard: Option<Cow<'a, str>>,
 
     /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
     /// Users will receive a notification with no sound.
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_notification: bool,
 
     /// If the message is a reply, ID of the original message
@@ -51,11 +51,15 @@ impl<'a> Request for SendContact<'a> {
 }
 
 impl<'a> SendContact<'a> {
-    pub fn new(chat_id: impl Into<ChatId<'a>>, phone_number: &'a str, first_name: &'a str) -> Self {
+    pub fn new(
+        chat_id: impl Into<ChatId<'a>>,
+        phone_number: impl Into<Cow<'a, str>>,
+        first_name: impl Into<Cow<'a, str>>,
+    ) -> Self {
         Self {
             chat_id: chat_id.into(),
-            phone_number,
-            first_name,
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
             last_name: None,
             vcard: None,
             disable_notification: false,
@@ -66,14 +70,14 @@ impl<'a> SendContact<'a> {
 
     pub fn new_reply(
         chat_id: impl Into<ChatId<'a>>,
-        phone_number: &'a str,
-        first_name: &'a str,
+        phone_number: impl Into<Cow<'a, str>>,
+        first_name: impl Into<Cow<'a, str>>,
         reply_to_message_id: i64,
     ) -> Self {
         Self {
             chat_id: chat_id.into(),
-            phone_number,
-            first_name,
+            phone_number: phone_number.into(),
+            first_name: first_name.into(),
             last_name: None,
             vcard: None,
             disable_notification: false,
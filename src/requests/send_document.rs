@@ -1,44 +1,48 @@
-use std::ops::Not;
+use std::{borrow::Cow, ops::Not};
 
 use hyper::Body;
 use hyper_multipart_rfc7578::client::multipart::Form;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     error::Error,
+    formatting::{FormattedText, Markup},
     requests::{
         add_fields_to_form, add_file_to_form, add_form_body, add_json_body, ChatId, FileKind,
         ParseMode, ReplyMarkup, Request,
     },
-    responses::Message,
+    responses::{Message, MessageEntity},
 };
 
 /// Use this struct to send general files. On success, the sent `Message` is returned.
 /// Bots can currently send files of any type of up to 50 MB in size, this limit may be changed in the future
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SendDocument<'a> {
     /// Identifier for the target chat
     pub chat_id: ChatId<'a>,
 
     /// File to send.
-    #[serde(skip_serializing_if = "FileKind::is_input_file")]
     pub document: FileKind<'a>,
 
     /// Document caption (may also be used when resending documents by file_id), 0-1024 characters
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub caption: Option<&'a str>,
+    pub caption: Option<Cow<'a, str>>,
 
     /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
     /// Users will receive a notification with no sound.
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_notification: bool,
 
-    /// Send `ParseMode::Markdown` or `ParseMode::Html`,
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
     /// if you want Telegram apps to show
     /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in the media caption.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
 
+    /// List of special entities that appear in the caption, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption_entities: Option<Cow<'a, [MessageEntity]>>,
+
     /// If the message is a reply, ID of the original message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message_id: Option<i64>,
@@ -78,6 +82,7 @@ impl<'a> SendDocument<'a> {
             caption: None,
             disable_notification: false,
             parse_mode: None,
+            caption_entities: None,
             reply_to_message_id: None,
             reply_markup: None,
         }
@@ -94,8 +99,31 @@ impl<'a> SendDocument<'a> {
             caption: None,
             disable_notification: false,
             parse_mode: None,
+            caption_entities: None,
             reply_to_message_id: Some(reply_to_message_id),
             reply_markup: None,
         }
     }
+
+    /// Set caption built by `formatting::MarkupBuilder`
+    pub fn with_caption_markup(self, markup: impl Into<Cow<'a, Markup>>) -> Self {
+        let (caption, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: Some(parse_mode),
+            caption_entities: None,
+            ..self
+        }
+    }
+
+    /// Set caption built with `formatting::FormattedText`
+    pub fn with_formatted_caption(self, formatted: impl Into<Cow<'a, FormattedText>>) -> Self {
+        let (caption, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            caption: Some(caption),
+            parse_mode: None,
+            caption_entities: Some(entities),
+            ..self
+        }
+    }
 }
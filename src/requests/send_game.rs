@@ -0,0 +1,64 @@
+use std::{borrow::Cow, ops::Not};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{ChatId, InlineKeyboard, Request},
+    responses::Message,
+};
+
+/// Use this struct to send a game. On success, the sent `Message` is returned.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendGame<'a> {
+    /// Identifier for the target chat
+    pub chat_id: ChatId<'a>,
+
+    /// Short name of the game, serves as the unique identifier for the game.
+    /// Set up your games via [@BotFather](https://t.me/botfather)
+    pub game_short_name: Cow<'a, str>,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub disable_notification: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i64>,
+
+    /// Inline keyboard. If empty, one 'Play game_title' button will be shown.
+    /// If not empty, the first button must launch the game
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+}
+
+impl<'a> Request for SendGame<'a> {
+    type ResponseType = Message;
+
+    fn method(&self) -> &'static str {
+        "sendGame"
+    }
+}
+
+impl<'a> SendGame<'a> {
+    pub fn new(chat_id: impl Into<ChatId<'a>>, game_short_name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            game_short_name: game_short_name.into(),
+            disable_notification: false,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn new_reply(
+        chat_id: impl Into<ChatId<'a>>,
+        game_short_name: impl Into<Cow<'a, str>>,
+        reply_to_message_id: i64,
+    ) -> Self {
+        Self {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..Self::new(chat_id, game_short_name)
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::{borrow::Cow, ops::Not};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{ChatId, InlineKeyboard, LabeledPrice, Request, STARS_CURRENCY},
+    responses::Message,
+};
+
+/// Use this struct to send invoices. On success, the sent `Message` is returned.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendInvoice<'a> {
+    /// Identifier for the target chat
+    pub chat_id: ChatId<'a>,
+
+    /// Product name, 1-32 characters
+    pub title: Cow<'a, str>,
+
+    /// Product description, 1-255 characters
+    pub description: Cow<'a, str>,
+
+    /// Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the user,
+    /// use it for your internal processes
+    pub payload: Cow<'a, str>,
+
+    /// Payment provider token, obtained via [@BotFather](https://t.me/botfather).
+    /// Pass `None` for payments in Telegram Stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_token: Option<Cow<'a, str>>,
+
+    /// Three-letter ISO 4217 currency code, see [more on currencies](https://core.telegram.org/bots/payments#supported-currencies).
+    /// Pass “XTR” for payments in Telegram Stars
+    pub currency: Cow<'a, str>,
+
+    /// Price breakdown (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.).
+    /// Must contain exactly one item for payments in Telegram Stars
+    pub prices: Cow<'a, [LabeledPrice<'a>]>,
+
+    /// The maximum accepted amount for tips in the smallest units of the currency
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tip_amount: Option<i64>,
+
+    /// Suggested amounts of tips in the smallest units of the currency, at most 4
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_tip_amounts: Option<Cow<'a, [i64]>>,
+
+    /// Unique deep-linking parameter. If left empty, forwarded copies of the sent message will have a Pay button,
+    /// allowing multiple users to pay directly from the forwarded message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_parameter: Option<Cow<'a, str>>,
+
+    /// JSON-serialized data about the invoice, which will be shared with the payment provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<Cow<'a, str>>,
+
+    /// Url of the product photo for the invoice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<Cow<'a, str>>,
+
+    /// Photo size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<i32>,
+
+    /// Photo width
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<i32>,
+
+    /// Photo height
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<i32>,
+
+    /// Pass true, if you require the user's full name to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_name: bool,
+
+    /// Pass true, if you require the user's phone number to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_phone_number: bool,
+
+    /// Pass true, if you require the user's email address to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_email: bool,
+
+    /// Pass true, if you require the user's shipping address to complete the order
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub need_shipping_address: bool,
+
+    /// Pass true, if the user's phone number should be sent to provider
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub send_phone_number_to_provider: bool,
+
+    /// Pass true, if the user's email address should be sent to provider
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub send_email_to_provider: bool,
+
+    /// Pass true, if the final price depends on the shipping method.
+    /// Shipping options are then requested with a `ShippingQuery`
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub is_flexible: bool,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub disable_notification: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i64>,
+
+    /// Inline keyboard. If empty, one 'Pay total price' button will be shown.
+    /// If not empty, the first button must be a Pay button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboard<'a>>,
+}
+
+impl<'a> Request for SendInvoice<'a> {
+    type ResponseType = Message;
+
+    fn method(&self) -> &'static str {
+        "sendInvoice"
+    }
+}
+
+impl<'a> SendInvoice<'a> {
+    pub fn new(
+        chat_id: impl Into<ChatId<'a>>,
+        title: impl Into<Cow<'a, str>>,
+        description: impl Into<Cow<'a, str>>,
+        payload: impl Into<Cow<'a, str>>,
+        currency: impl Into<Cow<'a, str>>,
+        prices: impl Into<Cow<'a, [LabeledPrice<'a>]>>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            title: title.into(),
+            description: description.into(),
+            payload: payload.into(),
+            provider_token: None,
+            currency: currency.into(),
+            prices: prices.into(),
+            max_tip_amount: None,
+            suggested_tip_amounts: None,
+            start_parameter: None,
+            provider_data: None,
+            photo_url: None,
+            photo_size: None,
+            photo_width: None,
+            photo_height: None,
+            need_name: false,
+            need_phone_number: false,
+            need_email: false,
+            need_shipping_address: false,
+            send_phone_number_to_provider: false,
+            send_email_to_provider: false,
+            is_flexible: false,
+            disable_notification: false,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    /// Invoice for a payment of `amount` Telegram Stars
+    pub fn new_stars(
+        chat_id: impl Into<ChatId<'a>>,
+        title: impl Into<Cow<'a, str>>,
+        description: impl Into<Cow<'a, str>>,
+        payload: impl Into<Cow<'a, str>>,
+        amount: i64,
+    ) -> Self {
+        let title = title.into();
+        let prices = vec![LabeledPrice::new(title.clone(), amount)];
+        Self::new(chat_id, title, description, payload, STARS_CURRENCY, prices)
+    }
+}
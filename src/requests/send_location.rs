@@ -1,6 +1,6 @@
 use std::ops::Not;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, ReplyMarkup, Request},
@@ -8,7 +8,7 @@ use crate::{
 };
 
 /// Use this struct to send point on the map. On success, the sent `Message` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SendLocation<'a> {
     /// Identifier for the target chat
     pub chat_id: ChatId<'a>,
@@ -25,7 +25,7 @@ pub struct SendLocation<'a> {
 
     /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
     /// Users will receive a notification with no sound.
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_notification: bool,
 
     /// If the message is a reply, ID of the original message
@@ -2,7 +2,7 @@ use std::ops::Not;
 
 use hyper::Body;
 use hyper_multipart_rfc7578::client::multipart::Form;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     error::Error,
@@ -13,7 +13,7 @@ use crate::{
     responses::Message,
 };
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
 pub enum InputMediaPhotoOrVideo<'a> {
     #[serde(rename = "video")]
@@ -40,7 +40,7 @@ impl<'a> InputMediaPhotoOrVideo<'a> {
 
 /// Use this struct to send a group of photos or videos as an album.
 /// On success, an array of the sent `Messages` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SendMediaGroup<'a> {
     /// Identifier for the target chat
     pub chat_id: ChatId<'a>,
@@ -50,7 +50,7 @@ pub struct SendMediaGroup<'a> {
 
     /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
     /// Users will receive a notification with no sound.
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_notification: bool,
 
     /// If the message is a reply, ID of the original message
@@ -1,34 +1,39 @@
-use std::ops::Not;
+use std::{borrow::Cow, ops::Not};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    formatting::{FormattedText, Markup},
     requests::{ChatId, ParseMode, ReplyMarkup, Request},
-    responses::Message,
+    responses::{Message, MessageEntity},
 };
 
 /// Use this struct to send text messages. On success, the sent `Message` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SendMessage<'a> {
     /// Identifier for the target chat
     pub chat_id: ChatId<'a>,
 
     /// Text of the message to be sent.
-    pub text: &'a str,
+    pub text: Cow<'a, str>,
 
     /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
     /// Users will receive a notification with no sound.
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_notification: bool,
 
-    /// Send `ParseMode::Markdown` or `ParseMode::Html`,
+    /// Send `ParseMode::MarkdownV2`, `ParseMode::Html` or `ParseMode::Markdown`,
     /// if you want Telegram apps to show
     /// [bold, italic, fixed-width text or inline URLs](https://core.telegram.org/bots/api#formatting-options) in your bot's message.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parse_mode: Option<ParseMode>,
 
+    /// List of special entities that appear in the message text, which can be specified instead of `parse_mode`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Cow<'a, [MessageEntity]>>,
+
     /// Disables link previews for links in this message
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_web_page_preview: bool,
 
     /// If the message is a reply, ID of the original message
@@ -49,12 +54,13 @@ impl<'a> Request for SendMessage<'a> {
 }
 
 impl<'a> SendMessage<'a> {
-    pub fn new(chat_id: impl Into<ChatId<'a>>, text: &'a str) -> Self {
+    pub fn new(chat_id: impl Into<ChatId<'a>>, text: impl Into<Cow<'a, str>>) -> Self {
         Self {
             chat_id: chat_id.into(),
-            text,
+            text: text.into(),
             disable_notification: false,
             parse_mode: None,
+            entities: None,
             disable_web_page_preview: false,
             reply_to_message_id: None,
             reply_markup: None,
@@ -63,17 +69,40 @@ impl<'a> SendMessage<'a> {
 
     pub fn new_reply(
         chat_id: impl Into<ChatId<'a>>,
-        text: &'a str,
+        text: impl Into<Cow<'a, str>>,
         reply_to_message_id: i64,
     ) -> Self {
         Self {
             chat_id: chat_id.into(),
-            text,
+            text: text.into(),
             disable_notification: false,
             parse_mode: None,
+            entities: None,
             disable_web_page_preview: false,
             reply_to_message_id: Some(reply_to_message_id),
             reply_markup: None,
         }
     }
+
+    /// Message with text built by `formatting::MarkupBuilder`
+    pub fn new_markup(chat_id: impl Into<ChatId<'a>>, markup: impl Into<Cow<'a, Markup>>) -> Self {
+        let (text, parse_mode) = Markup::into_parts(markup.into());
+        Self {
+            parse_mode: Some(parse_mode),
+            entities: None,
+            ..Self::new(chat_id, text)
+        }
+    }
+
+    /// Message with text and entities built with `formatting::FormattedText`
+    pub fn new_formatted(
+        chat_id: impl Into<ChatId<'a>>,
+        formatted: impl Into<Cow<'a, FormattedText>>,
+    ) -> Self {
+        let (text, entities) = FormattedText::into_parts(formatted.into());
+        Self {
+            entities: Some(entities),
+            ..Self::new(chat_id, text)
+        }
+    }
 }
@@ -1,6 +1,6 @@
-use std::ops::Not;
+use std::{borrow::Cow, ops::Not};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, ReplyMarkup, Request},
@@ -9,24 +9,24 @@ use crate::{
 
 /// Use this struct to send a native poll. A native poll can't be sent to a private chat.
 /// On success, the sent `Message` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SendPoll<'a> {
     /// Identifier for the target chat
     pub chat_id: ChatId<'a>,
 
     /// Poll question, 1-255 characters
-    pub question: &'a str,
+    pub question: Cow<'a, str>,
 
     /// List of answer options, 2-10 strings 1-100 characters each
-    pub options: &'a [&'a str],
+    pub options: Cow<'a, [Cow<'a, str>]>,
 
     /// Contact's last name
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_name: Option<&'a str>,
+    pub last_name: Option<Cow<'a, str>>,
 
     /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
     /// Users will receive a notification with no sound.
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_notification: bool,
 
     /// If the message is a reply, ID of the original message
@@ -47,11 +47,15 @@ impl<'a> Request for SendPoll<'a> {
 }
 
 impl<'a> SendPoll<'a> {
-    pub fn new(chat_id: impl Into<ChatId<'a>>, question: &'a str, options: &'a [&'a str]) -> Self {
+    pub fn new(
+        chat_id: impl Into<ChatId<'a>>,
+        question: impl Into<Cow<'a, str>>,
+        options: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> Self {
         Self {
             chat_id: chat_id.into(),
-            question,
-            options,
+            question: question.into(),
+            options: options.into_iter().map(Into::into).collect(),
             last_name: None,
             disable_notification: false,
             reply_to_message_id: None,
@@ -61,14 +65,14 @@ impl<'a> SendPoll<'a> {
 
     pub fn new_reply(
         chat_id: impl Into<ChatId<'a>>,
-        question: &'a str,
-        options: &'a [&'a str],
+        question: impl Into<Cow<'a, str>>,
+        options: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
         reply_to_message_id: i64,
     ) -> Self {
         Self {
             chat_id: chat_id.into(),
-            question,
-            options,
+            question: question.into(),
+            options: options.into_iter().map(Into::into).collect(),
             last_name: None,
             disable_notification: false,
             reply_to_message_id: Some(reply_to_message_id),
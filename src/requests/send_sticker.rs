@@ -0,0 +1,88 @@
+use std::{borrow::Cow, ops::Not};
+
+use hyper::Body;
+use hyper_multipart_rfc7578::client::multipart::Form;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    requests::{
+        add_fields_to_form, add_file_to_form, add_form_body, add_json_body, ChatId, FileKind,
+        ReplyMarkup, Request,
+    },
+    responses::Message,
+};
+
+/// Use this struct to send static .WEBP, animated .TGS, or video .WEBM stickers.
+/// On success, the sent `Message` is returned.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendSticker<'a> {
+    /// Identifier for the target chat
+    pub chat_id: ChatId<'a>,
+
+    /// Sticker to send. Video and animated stickers can't be sent via http url
+    pub sticker: FileKind<'a>,
+
+    /// Emoji associated with the sticker; only for just uploaded stickers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<Cow<'a, str>>,
+
+    /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
+    /// Users will receive a notification with no sound.
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub disable_notification: bool,
+
+    /// If the message is a reply, ID of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<i64>,
+
+    /// Additional interface options.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup<'a>>,
+}
+
+impl<'a> Request for SendSticker<'a> {
+    type ResponseType = Message;
+
+    fn method(&self) -> &'static str {
+        "sendSticker"
+    }
+
+    fn set_http_request_body(
+        self,
+        request_builder: hyper::http::request::Builder,
+    ) -> Result<hyper::http::request::Request<Body>, Error> {
+        if self.sticker.is_input_file() {
+            let mut form = Form::default();
+            add_fields_to_form(&mut form, &self)?;
+            add_file_to_form(&mut form, self.sticker, Some("sticker"));
+            add_form_body(request_builder, form)
+        } else {
+            add_json_body(request_builder, &self)
+        }
+    }
+}
+
+impl<'a> SendSticker<'a> {
+    pub fn new(chat_id: impl Into<ChatId<'a>>, sticker: FileKind<'a>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            sticker,
+            emoji: None,
+            disable_notification: false,
+            reply_to_message_id: None,
+            reply_markup: None,
+        }
+    }
+
+    pub fn new_reply(
+        chat_id: impl Into<ChatId<'a>>,
+        sticker: FileKind<'a>,
+        reply_to_message_id: i64,
+    ) -> Self {
+        Self {
+            reply_to_message_id: Some(reply_to_message_id),
+            ..Self::new(chat_id, sticker)
+        }
+    }
+}
@@ -1,6 +1,6 @@
-use std::ops::Not;
+use std::{borrow::Cow, ops::Not};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, ReplyMarkup, Request},
@@ -8,7 +8,7 @@ use crate::{
 };
 
 /// Use this struct to send information about a venue. On success, the sent `Message` is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SendVenue<'a> {
     /// Identifier for the target chat
     pub chat_id: ChatId<'a>,
@@ -20,22 +20,22 @@ pub struct SendVenue<'a> {
     pub longitude: f64,
 
     /// Name of the venue
-    pub title: &'a str,
+    pub title: Cow<'a, str>,
 
     /// Address of the venue
-    pub address: &'a str,
+    pub address: Cow<'a, str>,
 
     /// Foursquare identifier of the venue
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub foursquare_id: Option<&'a str>,
+    pub foursquare_id: Option<Cow<'a, str>>,
 
     /// Foursquare type of the venue, if known. (For example, “arts_entertainment/default”, “arts_entertainment/aquarium” or “food/icecream”.)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub foursquare_type: Option<&'a str>,
+    pub foursquare_type: Option<Cow<'a, str>>,
 
     /// Sends the message [silently](https://telegram.org/blog/channels-2-0#silent-messages).
     /// Users will receive a notification with no sound.
-    #[serde(skip_serializing_if = "Not::not")]
+    #[serde(default, skip_serializing_if = "Not::not")]
     pub disable_notification: bool,
 
     /// If the message is a reply, ID of the original message
@@ -60,15 +60,15 @@ impl<'a> SendVenue<'a> {
         chat_id: impl Into<ChatId<'a>>,
         latitude: f64,
         longitude: f64,
-        title: &'a str,
-        address: &'a str,
+        title: impl Into<Cow<'a, str>>,
+        address: impl Into<Cow<'a, str>>,
     ) -> Self {
         Self {
             chat_id: chat_id.into(),
             latitude,
             longitude,
-            title,
-            address,
+            title: title.into(),
+            address: address.into(),
             foursquare_id: None,
             foursquare_type: None,
             disable_notification: false,
@@ -81,16 +81,16 @@ impl<'a> SendVenue<'a> {
         chat_id: impl Into<ChatId<'a>>,
         latitude: f64,
         longitude: f64,
-        title: &'a str,
-        address: &'a str,
+        title: impl Into<Cow<'a, str>>,
+        address: impl Into<Cow<'a, str>>,
         reply_to_message_id: i64,
     ) -> Self {
         Self {
             chat_id: chat_id.into(),
             latitude,
             longitude,
-            title,
-            address,
+            title: title.into(),
+            address: address.into(),
             foursquare_id: None,
             foursquare_type: None,
             disable_notification: false,
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::requests::{MenuButton, Request};
+
+/// Use this struct to change the bot's menu button in a private chat, or the default menu button.
+/// Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SetChatMenuButton<'a> {
+    /// Unique identifier for the target private chat. If not specified, default bot's menu button will be changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<i64>,
+
+    /// The bot's new menu button. Defaults to `MenuButton::Default`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub menu_button: Option<MenuButton<'a>>,
+}
+
+impl<'a> Request for SetChatMenuButton<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setChatMenuButton"
+    }
+}
+
+impl<'a> SetChatMenuButton<'a> {
+    /// Change the default menu button of the bot
+    pub fn new(menu_button: MenuButton<'a>) -> Self {
+        Self {
+            chat_id: None,
+            menu_button: Some(menu_button),
+        }
+    }
+
+    /// Change the menu button in the given private chat
+    pub fn new_chat(chat_id: i64, menu_button: MenuButton<'a>) -> Self {
+        Self {
+            chat_id: Some(chat_id),
+            menu_button: Some(menu_button),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::io::Cursor;
 
 use hyper::Body;
 use hyper_multipart_rfc7578::client::multipart::Form;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     error::Error,
@@ -13,13 +13,13 @@ use crate::{
 /// The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns `True` on success.
 ///
 /// Note: In regular groups (non-supergroups), this method will only work if the ‘All Members Are Admins’ setting is off in the target group.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SetChatPhoto<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
 
     /// New chat photo content
-    #[serde(skip_serializing)]
+    #[serde(skip)]
     pub photo: Vec<u8>,
 }
 
@@ -1,4 +1,6 @@
-use serde::Serialize;
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
 
 use crate::requests::{ChatId, Request};
 
@@ -8,13 +10,13 @@ use crate::requests::{ChatId, Request};
 ///
 /// Note: In regular groups (non-supergroups), this method will only work if the
 /// ‘All Members Are Admins’ setting is off in the target group.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SetChatTitle<'a> {
     /// Unique identifier for the target group or username of the target supergroup or channel
     pub chat_id: ChatId<'a>,
 
     /// New chat title, 1-255 characters
-    pub title: &'a str,
+    pub title: Cow<'a, str>,
 }
 
 impl<'a> Request for SetChatTitle<'a> {
@@ -26,10 +28,10 @@ impl<'a> Request for SetChatTitle<'a> {
 }
 
 impl<'a> SetChatTitle<'a> {
-    pub fn new(chat_id: impl Into<ChatId<'a>>, title: &'a str) -> Self {
+    pub fn new(chat_id: impl Into<ChatId<'a>>, title: impl Into<Cow<'a, str>>) -> Self {
         Self {
             chat_id: chat_id.into(),
-            title,
+            title: title.into(),
         }
     }
 }
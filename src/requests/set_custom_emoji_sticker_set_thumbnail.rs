@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to set the thumbnail of a custom emoji sticker set. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetCustomEmojiStickerSetThumbnail<'a> {
+    /// Sticker set name
+    pub name: Cow<'a, str>,
+
+    /// Custom emoji identifier of a sticker from the sticker set.
+    /// Pass `None` to drop the thumbnail and use the first sticker as the thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for SetCustomEmojiStickerSetThumbnail<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setCustomEmojiStickerSetThumbnail"
+    }
+}
+
+impl<'a> SetCustomEmojiStickerSetThumbnail<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>, custom_emoji_id: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+            custom_emoji_id: Some(custom_emoji_id.into()),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::{borrow::Cow, ops::Not};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    requests::{ChatId, MessageOrInlineMessageId, Request},
+    responses::EditedMessage,
+};
+
+/// Use this struct to set the score of the specified user in a game message.
+/// On success, if the message is not an inline message, the `Message` is returned, otherwise `True` is returned.
+/// Returns an error, if the new score is not greater than the user's current score in the chat and `force` is false.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetGameScore<'a> {
+    /// User identifier
+    pub user_id: i64,
+
+    /// New score, must be non-negative
+    pub score: i64,
+
+    /// Pass true if the high score is allowed to decrease.
+    /// This can be useful when fixing mistakes or banning cheaters
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub force: bool,
+
+    /// Pass true if the game message should not be automatically edited to include the current scoreboard
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub disable_edit_message: bool,
+
+    /// Identifier of the game message in chat or identifier of the inline message
+    #[serde(flatten)]
+    pub message_or_inline_message_id: MessageOrInlineMessageId<'a>,
+}
+
+impl<'a> Request for SetGameScore<'a> {
+    type ResponseType = EditedMessage;
+
+    fn method(&self) -> &'static str {
+        "setGameScore"
+    }
+}
+
+impl<'a> SetGameScore<'a> {
+    pub fn new_inline_message(
+        inline_message_id: impl Into<Cow<'a, str>>,
+        user_id: i64,
+        score: i64,
+    ) -> Self {
+        Self {
+            user_id,
+            score,
+            force: false,
+            disable_edit_message: false,
+            message_or_inline_message_id: MessageOrInlineMessageId::Inline {
+                inline_message_id: inline_message_id.into(),
+            },
+        }
+    }
+
+    pub fn new_message(
+        chat_id: impl Into<ChatId<'a>>,
+        message_id: i64,
+        user_id: i64,
+        score: i64,
+    ) -> Self {
+        Self {
+            user_id,
+            score,
+            force: false,
+            disable_edit_message: false,
+            message_or_inline_message_id: MessageOrInlineMessageId::Chat {
+                chat_id: chat_id.into(),
+                message_id,
+            },
+        }
+    }
+}
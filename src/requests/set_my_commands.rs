@@ -0,0 +1,51 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::{BotCommand, BotCommandScope, Request};
+
+/// Use this struct to change the list of the bot's commands. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetMyCommands<'a> {
+    /// List of bot commands to be set as the list of the bot's commands. At most 100 commands can be specified
+    pub commands: Cow<'a, [BotCommand<'a>]>,
+
+    /// Scope of users for which the commands are relevant. Defaults to `BotCommandScope::Default`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<BotCommandScope<'a>>,
+
+    /// A two-letter ISO 639-1 language code. If empty, commands will be applied to all users from the given scope,
+    /// for whose language there are no dedicated commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for SetMyCommands<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setMyCommands"
+    }
+}
+
+impl<'a> SetMyCommands<'a> {
+    pub fn new(commands: impl Into<Cow<'a, [BotCommand<'a>]>>) -> Self {
+        Self {
+            commands: commands.into(),
+            scope: None,
+            language_code: None,
+        }
+    }
+
+    pub fn new_scoped(
+        commands: impl Into<Cow<'a, [BotCommand<'a>]>>,
+        scope: BotCommandScope<'a>,
+        language_code: Option<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            commands: commands.into(),
+            scope: Some(scope),
+            language_code,
+        }
+    }
+}
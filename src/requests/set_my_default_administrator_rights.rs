@@ -0,0 +1,37 @@
+use std::ops::Not;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::ChatAdministratorRights};
+
+/// Use this struct to change the default administrator rights requested by the bot when it's added as an administrator
+/// to groups or channels. These rights will be suggested to users, but they are free to modify the list before adding the bot.
+/// Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SetMyDefaultAdministratorRights {
+    /// New default administrator rights. If not specified, the default administrator rights will be cleared
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rights: Option<ChatAdministratorRights>,
+
+    /// Pass true to change the default administrator rights of the bot in channels.
+    /// Otherwise, the default administrator rights of the bot for groups and supergroups will be changed
+    #[serde(default, skip_serializing_if = "Not::not")]
+    pub for_channels: bool,
+}
+
+impl Request for SetMyDefaultAdministratorRights {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setMyDefaultAdministratorRights"
+    }
+}
+
+impl SetMyDefaultAdministratorRights {
+    pub fn new(rights: ChatAdministratorRights, for_channels: bool) -> Self {
+        Self {
+            rights: Some(rights),
+            for_channels,
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to change the bot's description, which is shown in the chat with the bot if the chat is empty.
+/// Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SetMyDescription<'a> {
+    /// New bot description; 0-512 characters. Pass `None` to remove the dedicated description for the given language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Cow<'a, str>>,
+
+    /// A two-letter ISO 639-1 language code. If empty, the description will be shown to all users
+    /// for whose language there is no dedicated description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for SetMyDescription<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setMyDescription"
+    }
+}
+
+impl<'a> SetMyDescription<'a> {
+    pub fn new(description: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            description: Some(description.into()),
+            language_code: None,
+        }
+    }
+
+    pub fn new_localized(
+        description: impl Into<Cow<'a, str>>,
+        language_code: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            description: Some(description.into()),
+            language_code: Some(language_code.into()),
+        }
+    }
+}
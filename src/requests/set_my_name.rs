@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to change the bot's name. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SetMyName<'a> {
+    /// New bot name; 0-64 characters. Pass `None` to remove the dedicated name for the given language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Cow<'a, str>>,
+
+    /// A two-letter ISO 639-1 language code. If empty, the name will be shown to all users
+    /// for whose language there is no dedicated name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for SetMyName<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setMyName"
+    }
+}
+
+impl<'a> SetMyName<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: Some(name.into()),
+            language_code: None,
+        }
+    }
+
+    pub fn new_localized(
+        name: impl Into<Cow<'a, str>>,
+        language_code: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            name: Some(name.into()),
+            language_code: Some(language_code.into()),
+        }
+    }
+}
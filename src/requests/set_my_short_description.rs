@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to change the bot's short description, which is shown on the bot's profile page
+/// and is sent together with the link when users share the bot. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SetMyShortDescription<'a> {
+    /// New bot short description; 0-120 characters. Pass `None` to remove the dedicated short description for the given language
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<Cow<'a, str>>,
+
+    /// A two-letter ISO 639-1 language code. If empty, the short description will be shown to all users
+    /// for whose language there is no dedicated short description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+}
+
+impl<'a> Request for SetMyShortDescription<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setMyShortDescription"
+    }
+}
+
+impl<'a> SetMyShortDescription<'a> {
+    pub fn new(short_description: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            short_description: Some(short_description.into()),
+            language_code: None,
+        }
+    }
+
+    pub fn new_localized(
+        short_description: impl Into<Cow<'a, str>>,
+        language_code: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        Self {
+            short_description: Some(short_description.into()),
+            language_code: Some(language_code.into()),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::EncryptedPassportElementType};
+
+/// Informs a user that some of the Telegram Passport elements they provided contains errors.
+/// The user will not be able to re-submit their Passport to you until the errors are fixed
+/// (the contents of the field for which you returned the error must change). Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetPassportDataErrors<'a> {
+    /// User identifier
+    pub user_id: i64,
+
+    /// Array describing the errors
+    pub errors: Cow<'a, [PassportElementError<'a>]>,
+}
+
+impl<'a> Request for SetPassportDataErrors<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setPassportDataErrors"
+    }
+}
+
+impl<'a> SetPassportDataErrors<'a> {
+    pub fn new(user_id: i64, errors: impl Into<Cow<'a, [PassportElementError<'a>]>>) -> Self {
+        Self {
+            user_id,
+            errors: errors.into(),
+        }
+    }
+}
+
+/// This object represents an error in the Telegram Passport element which was submitted that should be resolved by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum PassportElementError<'a> {
+    /// Represents an issue in one of the data fields that was provided by the user.
+    /// The error is considered resolved when the field's value changes
+    Data {
+        /// The section of the user's Telegram Passport which has the error, one of “personal_details”,
+        /// “passport”, “driver_license”, “identity_card”, “internal_passport”, “address”
+        #[serde(rename = "type")]
+        typ: EncryptedPassportElementType,
+
+        /// Name of the data field which has the error
+        field_name: Cow<'a, str>,
+
+        /// Base64-encoded data hash
+        data_hash: Cow<'a, str>,
+
+        /// Error message
+        message: Cow<'a, str>,
+    },
+
+    /// Represents an issue with the front side of a document.
+    /// The error is considered resolved when the file with the front side of the document changes
+    FrontSide {
+        /// The section of the user's Telegram Passport which has the issue, one of “passport”,
+        /// “driver_license”, “identity_card”, “internal_passport”
+        #[serde(rename = "type")]
+        typ: EncryptedPassportElementType,
+
+        /// Base64-encoded hash of the file with the front side of the document
+        file_hash: Cow<'a, str>,
+
+        /// Error message
+        message: Cow<'a, str>,
+    },
+
+    /// Represents an issue with the reverse side of a document.
+    /// The error is considered resolved when the file with reverse side of the document changes
+    ReverseSide {
+        /// The section of the user's Telegram Passport which has the issue, one of “driver_license”, “identity_card”
+        #[serde(rename = "type")]
+        typ: EncryptedPassportElementType,
+
+        /// Base64-encoded hash of the file with the reverse side of the document
+        file_hash: Cow<'a, str>,
+
+        /// Error message
+        message: Cow<'a, str>,
+    },
+
+    /// Represents an issue with the selfie with a document.
+    /// The error is considered resolved when the file with the selfie changes
+    Selfie {
+        /// The section of the user's Telegram Passport which has the issue, one of “passport”,
+        /// “driver_license”, “identity_card”, “internal_passport”
+        #[serde(rename = "type")]
+        typ: EncryptedPassportElementType,
+
+        /// Base64-encoded hash of the file with the selfie
+        file_hash: Cow<'a, str>,
+
+        /// Error message
+        message: Cow<'a, str>,
+    },
+
+    /// Represents an issue with a document scan.
+    /// The error is considered resolved when the file with the document scan changes
+    File {
+        /// The section of the user's Telegram Passport which has the issue, one of “utility_bill”,
+        /// “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
+        #[serde(rename = "type")]
+        typ: EncryptedPassportElementType,
+
+        /// Base64-encoded file hash
+        file_hash: Cow<'a, str>,
+
+        /// Error message
+        message: Cow<'a, str>,
+    },
+
+    /// Represents an issue with a list of scans.
+    /// The error is considered resolved when the list of files containing the scans changes
+    Files {
+        /// The section of the user's Telegram Passport which has the issue, one of “utility_bill”,
+        /// “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
+        #[serde(rename = "type")]
+        typ: EncryptedPassportElementType,
+
+        /// List of base64-encoded file hashes
+        file_hashes: Cow<'a, [Cow<'a, str>]>,
+
+        /// Error message
+        message: Cow<'a, str>,
+    },
+
+    /// Represents an issue with one of the files that constitute the translation of a document.
+    /// The error is considered resolved when the file changes
+    TranslationFile {
+        /// Type of element of the user's Telegram Passport which has the issue
+        #[serde(rename = "type")]
+        typ: EncryptedPassportElementType,
+
+        /// Base64-encoded file hash
+        file_hash: Cow<'a, str>,
+
+        /// Error message
+        message: Cow<'a, str>,
+    },
+
+    /// Represents an issue with the translated version of a document.
+    /// The error is considered resolved when a file with the document translation change
+    TranslationFiles {
+        /// Type of element of the user's Telegram Passport which has the issue
+        #[serde(rename = "type")]
+        typ: EncryptedPassportElementType,
+
+        /// List of base64-encoded file hashes
+        file_hashes: Cow<'a, [Cow<'a, str>]>,
+
+        /// Error message
+        message: Cow<'a, str>,
+    },
+
+    /// Represents an issue in an unspecified place. The error is considered resolved when new data is added
+    Unspecified {
+        /// Type of element of the user's Telegram Passport which has the issue
+        #[serde(rename = "type")]
+        typ: EncryptedPassportElementType,
+
+        /// Base64-encoded element hash
+        element_hash: Cow<'a, str>,
+
+        /// Error message
+        message: Cow<'a, str>,
+    },
+}
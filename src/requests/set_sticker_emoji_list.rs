@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to change the list of emoji assigned to a regular or custom emoji sticker.
+/// The sticker must belong to a sticker set created by the bot. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetStickerEmojiList<'a> {
+    /// File identifier of the sticker
+    pub sticker: Cow<'a, str>,
+
+    /// List of 1-20 emoji associated with the sticker
+    pub emoji_list: Cow<'a, [Cow<'a, str>]>,
+}
+
+impl<'a> Request for SetStickerEmojiList<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setStickerEmojiList"
+    }
+}
+
+impl<'a> SetStickerEmojiList<'a> {
+    pub fn new(
+        sticker: impl Into<Cow<'a, str>>,
+        emoji_list: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> Self {
+        Self {
+            sticker: sticker.into(),
+            emoji_list: emoji_list.into_iter().map(Into::into).collect(),
+        }
+    }
+}
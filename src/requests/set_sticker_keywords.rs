@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to change search keywords assigned to a regular or custom emoji sticker.
+/// The sticker must belong to a sticker set created by the bot. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetStickerKeywords<'a> {
+    /// File identifier of the sticker
+    pub sticker: Cow<'a, str>,
+
+    /// List of 0-20 search keywords for the sticker with total length of up to 64 characters.
+    /// Pass `None` to remove the keywords
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Cow<'a, [Cow<'a, str>]>>,
+}
+
+impl<'a> Request for SetStickerKeywords<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setStickerKeywords"
+    }
+}
+
+impl<'a> SetStickerKeywords<'a> {
+    pub fn new(
+        sticker: impl Into<Cow<'a, str>>,
+        keywords: impl IntoIterator<Item = impl Into<Cow<'a, str>>>,
+    ) -> Self {
+        Self {
+            sticker: sticker.into(),
+            keywords: Some(keywords.into_iter().map(Into::into).collect()),
+        }
+    }
+}
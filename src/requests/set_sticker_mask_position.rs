@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{requests::Request, responses::MaskPosition};
+
+/// Use this struct to change the mask position of a mask sticker.
+/// The sticker must belong to a sticker set that was created by the bot. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetStickerMaskPosition<'a> {
+    /// File identifier of the sticker
+    pub sticker: Cow<'a, str>,
+
+    /// New position where the mask should be placed on faces. Pass `None` to remove the mask position
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+}
+
+impl<'a> Request for SetStickerMaskPosition<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setStickerMaskPosition"
+    }
+}
+
+impl<'a> SetStickerMaskPosition<'a> {
+    pub fn new(sticker: impl Into<Cow<'a, str>>, mask_position: MaskPosition) -> Self {
+        Self {
+            sticker: sticker.into(),
+            mask_position: Some(mask_position),
+        }
+    }
+}
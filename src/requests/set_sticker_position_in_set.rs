@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to move a sticker in a set created by the bot to a specific position.
+/// Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetStickerPositionInSet<'a> {
+    /// File identifier of the sticker
+    pub sticker: Cow<'a, str>,
+
+    /// New sticker position in the set, zero-based
+    pub position: i64,
+}
+
+impl<'a> Request for SetStickerPositionInSet<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setStickerPositionInSet"
+    }
+}
+
+impl<'a> SetStickerPositionInSet<'a> {
+    pub fn new(sticker: impl Into<Cow<'a, str>>, position: i64) -> Self {
+        Self {
+            sticker: sticker.into(),
+            position,
+        }
+    }
+}
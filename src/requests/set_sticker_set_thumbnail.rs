@@ -0,0 +1,74 @@
+use std::borrow::Cow;
+
+use hyper::Body;
+use hyper_multipart_rfc7578::client::multipart::Form;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    requests::{
+        add_fields_to_form, add_file_to_form, add_form_body, add_json_body, FileKind, Request,
+        StickerFormat,
+    },
+};
+
+/// Use this struct to set the thumbnail of a regular or mask sticker set.
+/// The format of the thumbnail file must match the format of the stickers in the set. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetStickerSetThumbnail<'a> {
+    /// Sticker set name
+    pub name: Cow<'a, str>,
+
+    /// User identifier of the sticker set owner
+    pub user_id: i64,
+
+    /// A .WEBP or .PNG image with the thumbnail, a .TGS animation or a .WEBM video.
+    /// Animated and video sticker set thumbnails can't be uploaded via http url.
+    /// Pass `None` to drop the thumbnail and use the first sticker as the thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<FileKind<'a>>,
+
+    /// Format of the thumbnail
+    pub format: StickerFormat,
+}
+
+impl<'a> Request for SetStickerSetThumbnail<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setStickerSetThumbnail"
+    }
+
+    fn set_http_request_body(
+        self,
+        request_builder: hyper::http::request::Builder,
+    ) -> Result<hyper::http::request::Request<Body>, Error> {
+        match self.thumbnail {
+            Some(ref thumbnail) if thumbnail.is_input_file() => {
+                let mut form = Form::default();
+                add_fields_to_form(&mut form, &self)?;
+                if let Some(thumbnail) = self.thumbnail {
+                    add_file_to_form(&mut form, thumbnail, Some("thumbnail"));
+                }
+                add_form_body(request_builder, form)
+            }
+            _ => add_json_body(request_builder, &self),
+        }
+    }
+}
+
+impl<'a> SetStickerSetThumbnail<'a> {
+    pub fn new(
+        name: impl Into<Cow<'a, str>>,
+        user_id: i64,
+        thumbnail: FileKind<'a>,
+        format: StickerFormat,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            user_id,
+            thumbnail: Some(thumbnail),
+            format,
+        }
+    }
+}
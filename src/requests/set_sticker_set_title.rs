@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::requests::Request;
+
+/// Use this struct to set the title of a created sticker set. Returns `True` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SetStickerSetTitle<'a> {
+    /// Sticker set name
+    pub name: Cow<'a, str>,
+
+    /// Sticker set title, 1-64 characters
+    pub title: Cow<'a, str>,
+}
+
+impl<'a> Request for SetStickerSetTitle<'a> {
+    type ResponseType = bool;
+
+    fn method(&self) -> &'static str {
+        "setStickerSetTitle"
+    }
+}
+
+impl<'a> SetStickerSetTitle<'a> {
+    pub fn new(name: impl Into<Cow<'a, str>>, title: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            name: name.into(),
+            title: title.into(),
+        }
+    }
+}
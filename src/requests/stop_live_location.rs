@@ -1,4 +1,6 @@
-use serde::Serialize;
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, MessageOrInlineMessageId, ReplyMarkup, Request},
@@ -9,7 +11,7 @@ use crate::{
 /// A location can be edited until its live_period expires or editing is explicitly disabled by a
 /// call to `StopMessageLiveLocation`. On success, if the edited message was sent by the bot,
 /// the edited `EditLiveLocationResponse::Message` is returned, otherwise `EditLiveLocationResponse::True` is returned
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StopLiveLocation<'a> {
     /// Identifier where to stop live location
     #[serde(flatten)]
@@ -29,9 +31,11 @@ impl<'a> Request for StopLiveLocation<'a> {
 }
 
 impl<'a> StopLiveLocation<'a> {
-    pub fn new_inline(inline_message_id: &'a str) -> Self {
+    pub fn new_inline(inline_message_id: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            edit_location_in: MessageOrInlineMessageId::Inline { inline_message_id },
+            edit_location_in: MessageOrInlineMessageId::Inline {
+                inline_message_id: inline_message_id.into(),
+            },
             reply_markup: None,
         }
     }
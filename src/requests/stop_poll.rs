@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     requests::{ChatId, ReplyMarkup, Request},
@@ -6,7 +6,7 @@ use crate::{
 };
 
 /// Use this struct to stop a poll which was sent by the bot. On success, the stopped `Poll` with the final results is returned.
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StopPoll<'a> {
     /// Identifier for the target chat
     pub chat_id: ChatId<'a>,
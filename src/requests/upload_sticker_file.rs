@@ -0,0 +1,59 @@
+use hyper::Body;
+use hyper_multipart_rfc7578::client::multipart::Form;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    requests::{
+        add_fields_to_form, add_file_to_form, add_form_body, add_json_body, FileKind, Request,
+        StickerFormat,
+    },
+    responses::File,
+};
+
+/// Use this struct to upload a file with a sticker for later use in the `CreateNewStickerSet`,
+/// `AddStickerToSet` or `ReplaceStickerInSet` requests (the file can be used multiple times).
+/// Returns the uploaded `File` on success.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UploadStickerFile<'a> {
+    /// User identifier of sticker file owner
+    pub user_id: i64,
+
+    /// A file with the sticker in .WEBP, .PNG, .TGS, or .WEBM format
+    pub sticker: FileKind<'a>,
+
+    /// Format of the sticker
+    pub sticker_format: StickerFormat,
+}
+
+impl<'a> Request for UploadStickerFile<'a> {
+    type ResponseType = File;
+
+    fn method(&self) -> &'static str {
+        "uploadStickerFile"
+    }
+
+    fn set_http_request_body(
+        self,
+        request_builder: hyper::http::request::Builder,
+    ) -> Result<hyper::http::request::Request<Body>, Error> {
+        if self.sticker.is_input_file() {
+            let mut form = Form::default();
+            add_fields_to_form(&mut form, &self)?;
+            add_file_to_form(&mut form, self.sticker, Some("sticker"));
+            add_form_body(request_builder, form)
+        } else {
+            add_json_body(request_builder, &self)
+        }
+    }
+}
+
+impl<'a> UploadStickerFile<'a> {
+    pub fn new(user_id: i64, sticker: FileKind<'a>, sticker_format: StickerFormat) -> Self {
+        Self {
+            user_id,
+            sticker,
+            sticker_format,
+        }
+    }
+}
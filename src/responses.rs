@@ -1,37 +1,43 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub(crate) struct TgResponse<T> {
     /// If  equals true, the request was successful and the result
     /// of the query can be found in the ‘result’ field
     pub ok: bool,
 
     /// Response object
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<T>,
 
     /// Human-readable description of the result
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     /// Http error code
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub error_code: Option<i32>,
 
     /// This field can help to automatically handle the error
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<ResponseParameters>,
 }
 
 /// Contains information about why a request was unsuccessful.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ResponseParameters {
     /// The group has been migrated to a supergroup with the specified identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub migrate_to_chat_id: Option<i64>,
     /// In case of exceeding flood control, the number of seconds left to wait
     /// before the request can be repeated
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retry_after: Option<i64>,
 }
 
 /// This object represents an incoming update.
 /// At most one of the optional parameters can be present in any given update
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Update {
     /// The update‘s unique identifier. Update identifiers start from a certain positive number
     /// and increase sequentially. This ID becomes especially handy if you’re using [Webhooks],
@@ -41,28 +47,56 @@ pub struct Update {
     pub update_id: i64,
 
     /// New incoming message of any kind — text, photo, sticker, etc
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<Message>,
 
     /// New version of a message that is known to the bot and was edited
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_message: Option<Message>,
 
     /// New incoming channel post of any kind — text, photo, sticker, etc
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_post: Option<Message>,
 
     /// New version of a channel post that is known to the bot and was edited
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_channel_post: Option<Message>,
 
+    /// New incoming [inline](https://core.telegram.org/bots/api#inline-mode) query
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_query: Option<InlineQuery>,
+
+    /// The result of an [inline](https://core.telegram.org/bots/api#inline-mode) query
+    /// that was chosen by a user and sent to their chat partner
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chosen_inline_result: Option<ChosenInlineResult>,
+
     /// New incoming callback query
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub callback_query: Option<CallbackQuery>,
+
+    /// New incoming shipping query. Only for invoices with flexible price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_query: Option<ShippingQuery>,
+
+    /// New incoming pre-checkout query. Contains full information about checkout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_checkout_query: Option<PreCheckoutQuery>,
+
+    /// A request to join the chat has been sent. The bot must have the `can_invite_users`
+    /// administrator right in the chat to receive these updates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_join_request: Option<ChatJoinRequest>,
 }
 
 /// This object represents a message
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Message {
     /// Unique message identifier inside this chat
     pub message_id: i64,
 
     /// Sender, empty for messages sent to channels
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub from: Option<User>,
 
     /// Date the message was sent in Unix time
@@ -72,166 +106,445 @@ pub struct Message {
     pub chat: Chat,
 
     /// For forwarded messages, sender of the original message
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_from: Option<User>,
 
     /// For messages forwarded from channels, information about the original channel
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_from_chat: Option<Chat>,
 
     /// For messages forwarded from channels, identifier of the original message in the channel
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_from_message_id: Option<i64>,
 
     /// For messages forwarded from channels, signature of the post author if present
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_signature: Option<String>,
 
     /// For forwarded messages, date the original message was sent in Unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub forward_date: Option<i64>,
 
     /// For replies, the original message. Note that the Message object in this field will
     /// not contain further reply_to_message fields even if it itself is a reply
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to_message: Option<Box<Message>>,
 
     /// Date the message was last edited in Unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edit_date: Option<i64>,
 
     /// The unique identifier of a media message group this message belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub media_group_id: Option<String>,
 
     /// Signature of the post author for messages in channels
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_signature: Option<String>,
 
     /// For text messages, the actual UTF-8 text of the message, 0-4096 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
     /// For text messages, special entities like usernames, URLs, bot commands,
     /// etc. that appear in the text
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub entities: Option<Vec<MessageEntity>>,
 
     /// For messages with a caption, special entities like usernames, URLs, bot commands,
     /// etc. that appear in the caption
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption_entities: Option<Vec<MessageEntity>>,
 
     /// Message is an audio file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub audio: Option<Audio>,
 
     /// Message is a general file, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub document: Option<Document>,
 
     /// Message is an animation, information about the animation.
     /// For backward compatibility, when this field is set, the document field will also be set
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub animation: Option<Animation>,
 
     /// Message is a game, information about the game.
     /// [More about games](https://core.telegram.org/bots/api#games)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub game: Option<Game>,
 
     /// Message is a photo, available sizes of the photo
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo: Option<Vec<PhotoSize>>,
 
     /// Message is a sticker, information about the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sticker: Option<Sticker>,
 
     /// Message is a video, information about the video
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video: Option<Video>,
 
     /// Message is a voice message, information about the file
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub voice: Option<Voice>,
 
     /// Message is a [video note](https://telegram.org/blog/video-messages-and-telescope),
     /// information about the video message
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video_note: Option<VideoNote>,
 
     /// Caption for the animation, audio, document, photo, video or voice, 0-1024 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub caption: Option<String>,
 
     /// Message is a shared contact, information about the contact
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub contact: Option<Contact>,
 
     /// Message is a shared location, information about the location
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<Location>,
 
     /// Message is a venue, information about the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub venue: Option<Venue>,
 
     /// Message is a native poll, information about the poll
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub poll: Option<Poll>,
 
     /// New members that were added to the group or supergroup and information about them
     /// (the bot itself may be one of these members)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub new_chat_members: Option<Vec<User>>,
 
     /// A member was removed from the group, information about them (this member may be the bot itself)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub left_chat_member: Option<User>,
 
     /// A chat title was changed to this value
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub new_chat_title: Option<String>,
 
     /// A chat photo was change to this value
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub new_chat_photo: Option<Vec<PhotoSize>>,
 
     /// Service message: the chat photo was deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub delete_chat_photo: Option<bool>,
 
     /// Service message: the group has been created
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group_chat_created: Option<bool>,
 
     /// Service message: the supergroup has been created.
     /// This field can‘t be received in a message coming through updates, because bot can’t be a
     /// member of a supergroup when it is created. It can only be found in reply_to_message if
     /// someone replies to a very first message in a directly created supergroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub supergroup_chat_created: Option<bool>,
 
     /// Service message: the channel has been created. This field can‘t be received in a message
     /// coming through updates, because bot can’t be a member of a channel when it is created.
     /// It can only be found in reply_to_message if someone replies to a very first message in a channel
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_chat_created: Option<bool>,
 
     /// The group has been migrated to a supergroup with the specified identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub migrate_to_chat_id: Option<i64>,
 
     /// The supergroup has been migrated from a group with the specified identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub migrate_from_chat_id: Option<i64>,
 
     /// Specified message was pinned. Note that the Message object in this field will not
     /// contain further reply_to_message fields even if it is itself a reply
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pinned_message: Option<Box<Message>>,
 
     /// Message is an invoice for a [payment], information about the invoice.
     /// [More about payments](https://core.telegram.org/bots/api#payments)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub invoice: Option<Invoice>,
 
     /// Message is a service message about a successful payment, information about the payment.
     /// [More about payments](https://core.telegram.org/bots/api#payments)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub successful_payment: Option<SuccessfulPayment>,
 
+    /// Message is a service message about a refunded payment, information about the payment.
+    /// [More about payments](https://core.telegram.org/bots/api#payments)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refunded_payment: Option<RefundedPayment>,
+
     /// The domain name of the website on which the user has logged in.
     /// [More about Telegram Login](https://core.telegram.org/widgets/login)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub connected_website: Option<String>,
 
     /// Telegram Passport data
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub passport_data: Option<PassportData>,
+
+    /// Service message: users were shared with the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users_shared: Option<UsersShared>,
+
+    /// Service message: a chat was shared with the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_shared: Option<ChatShared>,
+
+    /// Service message: data sent by a Web App
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app_data: Option<WebAppData>,
+
+    /// Inline keyboard attached to the message. `login_url` buttons are represented as ordinary `url` buttons
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+impl Message {
+    /// Entities of the text and the caption together with their values.
+    /// Each of the texts is encoded to UTF-16 only once, unlike with `MessageEntity::extract_value`.
+    /// Entities of unknown types are skipped
+    pub fn entities_iter(&self) -> impl Iterator<Item = (&MessageEntity, MessageEntityValue<'_>)> {
+        let text = entities_with_values(self.text.as_deref(), self.entities.as_deref());
+        let caption =
+            entities_with_values(self.caption.as_deref(), self.caption_entities.as_deref());
+        text.chain(caption)
+    }
+
+    /// Bot commands in the message, e.g. `/start@jobs_bot`
+    pub fn bot_commands(&self) -> Vec<String> {
+        self.entities_iter()
+            .filter_map(|(_, value)| match value {
+                MessageEntityValue::BotCommand(command) => Some(command),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Mentions of users by username, e.g. `@username`
+    pub fn mentions(&self) -> Vec<String> {
+        self.entities_iter()
+            .filter_map(|(_, value)| match value {
+                MessageEntityValue::Mention(mention) => Some(mention),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Urls in the message, both written in the text and hidden behind clickable text
+    pub fn urls(&self) -> Vec<String> {
+        self.entities_iter()
+            .filter_map(|(_, value)| match value {
+                MessageEntityValue::Url(url) => Some(url),
+                MessageEntityValue::TextLink { link, .. } => Some(link.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Hashtags in the message, e.g. `#hashtag`
+    pub fn hashtags(&self) -> Vec<String> {
+        self.entities_iter()
+            .filter_map(|(_, value)| match value {
+                MessageEntityValue::Hashtag(hashtag) => Some(hashtag),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn entities_with_values<'a>(
+    text: Option<&str>,
+    entities: Option<&'a [MessageEntity]>,
+) -> impl Iterator<Item = (&'a MessageEntity, MessageEntityValue<'a>)> {
+    let utf16: Vec<u16> = match (text, entities) {
+        (Some(text), Some(_)) => text.encode_utf16().collect(),
+        _ => Vec::new(),
+    };
+    entities
+        .unwrap_or_default()
+        .iter()
+        .filter_map(move |entity| Some((entity, entity.value_from_utf16(&utf16)?)))
 }
 
 ///This object represents one special entity in a text message. For example, hashtags, usernames, URLs, etc
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MessageEntity {
-    /// Type of the entity. Can be mention (@username), hashtag, cashtag,
-    /// bot_command, url, email, phone_number, bold (bold text), italic (italic text),
-    /// code (monowidth string), pre (monowidth block), text_link (for clickable text URLs),
-    /// text_mention ([for users without usernames](https://telegram.org/blog/edit#new-mentions))
-    #[serde(rename = "type")]
-    pub typ: String,
+    /// Type of the entity together with its type-specific data
+    #[serde(flatten)]
+    pub kind: MessageEntityKind,
 
     /// Offset in UTF-16 code units to the start of the entity
     pub offset: i64,
 
     /// Length of the entity in UTF-16 code units
     pub length: i64,
+}
 
-    /// For “text_link” only, url that will be opened after user taps on the text
-    pub url: Option<String>,
+/// Type of the message entity
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "RawMessageEntityKind", into = "RawMessageEntityKind")]
+pub enum MessageEntityKind {
+    /// @username
+    Mention,
+    /// #hashtag
+    Hashtag,
+    /// $USD
+    Cashtag,
+    /// /start@jobs_bot
+    BotCommand,
+    /// https://telegram.org
+    Url,
+    /// do-not-reply@telegram.org
+    Email,
+    /// +1-212-555-0123
+    PhoneNumber,
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+    Blockquote,
+
+    /// Collapsed-by-default block quotation
+    ExpandableBlockquote,
+
+    /// Monowidth string
+    Code,
+
+    /// Monowidth block
+    Pre {
+        /// The programming language of the entity text
+        language: Option<String>,
+    },
+
+    /// Clickable text URL
+    TextLink {
+        /// Url that will be opened after user taps on the text
+        url: String,
+    },
+
+    /// Mention [for users without usernames](https://telegram.org/blog/edit#new-mentions)
+    TextMention {
+        /// The mentioned user
+        user: User,
+    },
+
+    /// Inline custom emoji sticker
+    CustomEmoji {
+        /// Unique identifier of the custom emoji
+        custom_emoji_id: String,
+    },
+
+    /// Type which is not supported by this library yet
+    Unknown(String),
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawMessageEntityKind {
+    #[serde(rename = "type")]
+    typ: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<User>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_emoji_id: Option<String>,
+}
 
-    /// For “text_mention” only, the mentioned user
-    pub user: Option<User>,
+impl RawMessageEntityKind {
+    fn new(typ: &str) -> Self {
+        Self {
+            typ: typ.to_string(),
+            url: None,
+            user: None,
+            language: None,
+            custom_emoji_id: None,
+        }
+    }
+}
+
+impl From<RawMessageEntityKind> for MessageEntityKind {
+    fn from(raw: RawMessageEntityKind) -> Self {
+        match (raw.typ.as_str(), raw.url, raw.user, raw.custom_emoji_id) {
+            ("mention", ..) => MessageEntityKind::Mention,
+            ("hashtag", ..) => MessageEntityKind::Hashtag,
+            ("cashtag", ..) => MessageEntityKind::Cashtag,
+            ("bot_command", ..) => MessageEntityKind::BotCommand,
+            ("url", ..) => MessageEntityKind::Url,
+            ("email", ..) => MessageEntityKind::Email,
+            ("phone_number", ..) => MessageEntityKind::PhoneNumber,
+            ("bold", ..) => MessageEntityKind::Bold,
+            ("italic", ..) => MessageEntityKind::Italic,
+            ("underline", ..) => MessageEntityKind::Underline,
+            ("strikethrough", ..) => MessageEntityKind::Strikethrough,
+            ("spoiler", ..) => MessageEntityKind::Spoiler,
+            ("blockquote", ..) => MessageEntityKind::Blockquote,
+            ("expandable_blockquote", ..) => MessageEntityKind::ExpandableBlockquote,
+            ("code", ..) => MessageEntityKind::Code,
+            ("pre", ..) => MessageEntityKind::Pre {
+                language: raw.language,
+            },
+            ("text_link", Some(url), ..) => MessageEntityKind::TextLink { url },
+            ("text_mention", _, Some(user), _) => MessageEntityKind::TextMention { user },
+            ("custom_emoji", _, _, Some(custom_emoji_id)) => {
+                MessageEntityKind::CustomEmoji { custom_emoji_id }
+            }
+            _ => MessageEntityKind::Unknown(raw.typ),
+        }
+    }
+}
+
+impl From<MessageEntityKind> for RawMessageEntityKind {
+    fn from(kind: MessageEntityKind) -> Self {
+        match kind {
+            MessageEntityKind::Mention => Self::new("mention"),
+            MessageEntityKind::Hashtag => Self::new("hashtag"),
+            MessageEntityKind::Cashtag => Self::new("cashtag"),
+            MessageEntityKind::BotCommand => Self::new("bot_command"),
+            MessageEntityKind::Url => Self::new("url"),
+            MessageEntityKind::Email => Self::new("email"),
+            MessageEntityKind::PhoneNumber => Self::new("phone_number"),
+            MessageEntityKind::Bold => Self::new("bold"),
+            MessageEntityKind::Italic => Self::new("italic"),
+            MessageEntityKind::Underline => Self::new("underline"),
+            MessageEntityKind::Strikethrough => Self::new("strikethrough"),
+            MessageEntityKind::Spoiler => Self::new("spoiler"),
+            MessageEntityKind::Blockquote => Self::new("blockquote"),
+            MessageEntityKind::ExpandableBlockquote => Self::new("expandable_blockquote"),
+            MessageEntityKind::Code => Self::new("code"),
+            MessageEntityKind::Pre { language } => Self {
+                language,
+                ..Self::new("pre")
+            },
+            MessageEntityKind::TextLink { url } => Self {
+                url: Some(url),
+                ..Self::new("text_link")
+            },
+            MessageEntityKind::TextMention { user } => Self {
+                user: Some(user),
+                ..Self::new("text_mention")
+            },
+            MessageEntityKind::CustomEmoji { custom_emoji_id } => Self {
+                custom_emoji_id: Some(custom_emoji_id),
+                ..Self::new("custom_emoji")
+            },
+            MessageEntityKind::Unknown(typ) => Self::new(&typ),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -245,51 +558,85 @@ pub enum MessageEntityValue<'a> {
     PhoneNumber(String),
     Bold(String),
     Italic(String),
+    Underline(String),
+    Strikethrough(String),
+    Spoiler(String),
+    Blockquote(String),
+    ExpandableBlockquote(String),
     Code(String),
-    Pre(String),
-    TextLink { text: String, link: &'a String },
-    TextMention { mention: String, user: &'a User },
+    Pre {
+        code: String,
+        language: Option<&'a str>,
+    },
+    TextLink {
+        text: String,
+        link: &'a String,
+    },
+    TextMention {
+        mention: String,
+        user: &'a User,
+    },
+    CustomEmoji {
+        emoji: String,
+        custom_emoji_id: &'a str,
+    },
 }
 
 impl MessageEntity {
-    /// Try to extract correct messageEntity from text message.
-    pub fn extract_value(&self, text: &str) -> Option<MessageEntityValue> {
-        let utf16_capture: Vec<u16> = text
-            .encode_utf16()
-            .skip(self.offset as usize)
-            .take(self.length as usize)
-            .collect();
-        let captured = String::from_utf16_lossy(&utf16_capture);
-        match self.typ.as_ref() {
-            "mention" => Some(MessageEntityValue::Mention(captured)),
-            "hashtag" => Some(MessageEntityValue::Hashtag(captured)),
-            "cashtag" => Some(MessageEntityValue::Cashtag(captured)),
-            "bot_command" => Some(MessageEntityValue::BotCommand(captured)),
-            "url" => Some(MessageEntityValue::Url(captured)),
-            "email" => Some(MessageEntityValue::Email(captured)),
-            "phone_number" => Some(MessageEntityValue::PhoneNumber(captured)),
-            "bold" => Some(MessageEntityValue::Bold(captured)),
-            "italic" => Some(MessageEntityValue::Italic(captured)),
-            "code" => Some(MessageEntityValue::Code(captured)),
-            "pre" => Some(MessageEntityValue::Pre(captured)),
-            "text_link" => self.url.as_ref().map(|link| MessageEntityValue::TextLink {
+    /// Try to extract correct messageEntity from text message. Returns `None` for unknown entity types
+    pub fn extract_value(&self, text: &str) -> Option<MessageEntityValue<'_>> {
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        self.value_from_utf16(&utf16)
+    }
+
+    fn value_from_utf16(&self, utf16: &[u16]) -> Option<MessageEntityValue<'_>> {
+        let clamp = |x: i64| x.clamp(0, utf16.len() as i64) as usize;
+        let start = clamp(self.offset);
+        let end = clamp(self.offset.saturating_add(self.length)).max(start);
+        let captured = String::from_utf16_lossy(&utf16[start..end]);
+        match &self.kind {
+            MessageEntityKind::Mention => Some(MessageEntityValue::Mention(captured)),
+            MessageEntityKind::Hashtag => Some(MessageEntityValue::Hashtag(captured)),
+            MessageEntityKind::Cashtag => Some(MessageEntityValue::Cashtag(captured)),
+            MessageEntityKind::BotCommand => Some(MessageEntityValue::BotCommand(captured)),
+            MessageEntityKind::Url => Some(MessageEntityValue::Url(captured)),
+            MessageEntityKind::Email => Some(MessageEntityValue::Email(captured)),
+            MessageEntityKind::PhoneNumber => Some(MessageEntityValue::PhoneNumber(captured)),
+            MessageEntityKind::Bold => Some(MessageEntityValue::Bold(captured)),
+            MessageEntityKind::Italic => Some(MessageEntityValue::Italic(captured)),
+            MessageEntityKind::Underline => Some(MessageEntityValue::Underline(captured)),
+            MessageEntityKind::Strikethrough => Some(MessageEntityValue::Strikethrough(captured)),
+            MessageEntityKind::Spoiler => Some(MessageEntityValue::Spoiler(captured)),
+            MessageEntityKind::Blockquote => Some(MessageEntityValue::Blockquote(captured)),
+            MessageEntityKind::ExpandableBlockquote => {
+                Some(MessageEntityValue::ExpandableBlockquote(captured))
+            }
+            MessageEntityKind::Code => Some(MessageEntityValue::Code(captured)),
+            MessageEntityKind::Pre { language } => Some(MessageEntityValue::Pre {
+                code: captured,
+                language: language.as_deref(),
+            }),
+            MessageEntityKind::TextLink { url } => Some(MessageEntityValue::TextLink {
                 text: captured,
-                link,
+                link: url,
+            }),
+            MessageEntityKind::TextMention { user } => Some(MessageEntityValue::TextMention {
+                mention: captured,
+                user,
             }),
-            "text_mention" => self
-                .user
-                .as_ref()
-                .map(|user| MessageEntityValue::TextMention {
-                    mention: captured,
-                    user,
-                }),
-            _ => None,
+            MessageEntityKind::CustomEmoji { custom_emoji_id } => {
+                Some(MessageEntityValue::CustomEmoji {
+                    emoji: captured,
+                    custom_emoji_id,
+                })
+            }
+            MessageEntityKind::Unknown(_) => None,
         }
     }
 }
 
 /// This object represent a user's profile pictures.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct UserProfilePhotos {
     /// Total number of profile pictures the target user has
     pub total_count: i64,
@@ -299,7 +646,7 @@ pub struct UserProfilePhotos {
 }
 
 /// This object contains information about a poll.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Poll {
     /// Unique poll identifier
     pub id: String,
@@ -315,7 +662,7 @@ pub struct Poll {
 }
 
 /// This object contains information about one answer option in a poll.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PollOption {
     /// Option text, 1-100 characters
     pub text: String,
@@ -325,7 +672,7 @@ pub struct PollOption {
 }
 
 /// This object contains information about one member of a chat.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ChatMember {
     /// Information about the user
     pub user: User,
@@ -334,55 +681,70 @@ pub struct ChatMember {
     pub status: String,
 
     /// Restricted and kicked only. Date when restrictions will be lifted for this user, unix time
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub until_date: Option<i64>,
 
     /// Administrators only. True, if the bot is allowed to edit administrator privileges of that user
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_be_edited: Option<bool>,
 
     /// Administrators only. True, if the administrator can change the chat title, photo and other settings
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_change_info: Option<bool>,
 
     /// Administrators only. True, if the administrator can post in the channel, channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_post_messages: Option<bool>,
 
     /// Administrators only. True, if the administrator can edit messages of other users and can pin messages, channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_edit_messages: Option<bool>,
 
     /// Administrators only. True, if the administrator can delete messages of other users
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_delete_messages: Option<bool>,
 
     /// Administrators only. True, if the administrator can invite new users to the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_invite_users: Option<bool>,
 
     /// Administrators only. True, if the administrator can restrict, ban or unban chat members
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_restrict_members: Option<bool>,
 
     /// Administrators only. True, if the administrator can pin messages, groups and supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_pin_messages: Option<bool>,
 
     /// Administrators only. True, if the administrator can add new administrators with a subset
     /// of his own privileges or demote administrators that he has promoted, directly
     /// or indirectly (promoted by administrators that were appointed by the user)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_promote_members: Option<bool>,
 
     /// Restricted only. True, if the user is a member of the chat at the moment of the request
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_member: Option<bool>,
 
     /// Restricted only. True, if the user can send text messages, contacts, locations and venues
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_messages: Option<bool>,
 
     /// Restricted only. True, if the user can send audios, documents, photos, videos, video notes and voice notes, implies can_send_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_media_messages: Option<bool>,
 
     /// Restricted only. True, if the user can send animations, games, stickers and use inline bots, implies can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_other_messages: Option<bool>,
 
     /// Restricted only. True, if user may add web page previews to his messages, implies can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_add_web_page_previews: Option<bool>,
 }
 
 /// This object represents an audio file to be treated as music by the Telegram clients
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Audio {
     /// Unique identifier for this file
     pub file_id: String,
@@ -391,18 +753,23 @@ pub struct Audio {
     pub duration: i64,
 
     /// Performer of the audio as defined by sender or by audio tags
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub performer: Option<String>,
 
     /// Title of the audio as defined by sender or by audio tags
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
     /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 
     /// Thumbnail of the album cover to which the music file belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
 }
 
@@ -410,26 +777,30 @@ pub struct Audio {
 /// [photos](https://core.telegram.org/bots/api#photosize),
 /// [voice messages](https://core.telegram.org/bots/api#voice) and
 /// [audio files](https://core.telegram.org/bots/api#audio))
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Document {
     /// Unique file identifier
     pub file_id: String,
 
     /// Document thumbnail as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
 
     /// Original filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_name: Option<String>,
 
     /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 }
 
 /// This object represents an animation file (GIF or H.264/MPEG-4 AVC video without sound)
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Animation {
     /// Unique file identifier
     pub file_id: String,
@@ -444,21 +815,25 @@ pub struct Animation {
     pub duration: i64,
 
     /// Animation thumbnail as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
 
     /// Original animation filename as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_name: Option<String>,
 
     /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 }
 
 /// This object represents a game. Use BotFather to create and edit games,
 /// their short names will act as unique identifiers
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Game {
     /// Title of the game
     pub title: String,
@@ -471,22 +846,38 @@ pub struct Game {
 
     /// Brief description of the game or high scores included in the game message.
     /// Can be automatically edited to include current high scores for the game when the bot
-    /// calls [set_game_score], or manually edited using
+    /// calls [crate::requests::SetGameScore], or manually edited using
     /// [crate::requests::EditMessageText]. 0-4096 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
     /// Special entities that appear in text, such as usernames, URLs, bot commands, etc
-    pub text_entities: Option<MessageEntity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_entities: Option<Vec<MessageEntity>>,
 
     /// Animation that will be displayed in the game message in chats. Upload via
     /// [BotFather](https://t.me/botfather)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub animation: Option<Animation>,
 }
 
+/// This object represents one row of the high scores table for a game
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GameHighScore {
+    /// Position in high score table for the game
+    pub position: i64,
+
+    /// User
+    pub user: User,
+
+    /// Score
+    pub score: i64,
+}
+
 /// This object represents one size of a photo or a
 /// [file](https://core.telegram.org/bots/api#document) /
 /// [sticker](https://core.telegram.org/bots/api#sticker) thumbnail
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PhotoSize {
     /// Unique identifier for this file
     pub file_id: String,
@@ -498,39 +889,93 @@ pub struct PhotoSize {
     pub height: i64,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 }
 
+/// Type of stickers in a sticker set
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StickerType {
+    Regular,
+    Mask,
+    CustomEmoji,
+}
+
+/// This object represents a sticker set
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StickerSet {
+    /// Sticker set name
+    pub name: String,
+
+    /// Sticker set title
+    pub title: String,
+
+    /// Type of stickers in the set
+    pub sticker_type: StickerType,
+
+    /// List of all set stickers
+    pub stickers: Vec<Sticker>,
+
+    /// Sticker set thumbnail in the .webp, .tgs, or .webm format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<PhotoSize>,
+}
+
 /// This object represents a sticker
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Sticker {
     /// Unique identifier for this file
     pub file_id: String,
 
+    /// Type of the sticker. The type of the sticker is independent from its format
+    #[serde(rename = "type")]
+    pub typ: StickerType,
+
     /// Sticker width
     pub width: i64,
 
     /// Sticker height
     pub height: i64,
 
+    /// True, if the sticker is [animated](https://telegram.org/blog/animated-stickers)
+    pub is_animated: bool,
+
+    /// True, if the sticker is a [video sticker](https://telegram.org/blog/video-stickers-better-reactions)
+    pub is_video: bool,
+
     /// Sticker thumbnail in the .webp or .jpg format
+    #[serde(alias = "thumbnail", skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
 
     /// Emoji associated with the sticker
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub emoji: Option<String>,
 
     /// Name of the sticker set to which the sticker belongs
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub set_name: Option<String>,
 
     /// For mask stickers, the position where the mask should be placed
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mask_position: Option<MaskPosition>,
 
+    /// For custom emoji stickers, unique identifier of the custom emoji
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_emoji_id: Option<String>,
+
+    /// True, if the sticker must be repainted to a text color in messages, the color of the Telegram Premium badge
+    /// in emoji status, white color on chat photos, or another appropriate color in other places
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs_repainting: Option<bool>,
+
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 }
 
 /// This object describes the position on faces where a mask should be placed by default
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MaskPosition {
     /// The part of the face relative to which the mask should be placed.
     /// One of “forehead”, “eyes”, “mouth”, or “chin”.
@@ -549,7 +994,7 @@ pub struct MaskPosition {
 }
 
 /// This object represents a video file
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Video {
     /// Unique identifier for this file
     pub file_id: String,
@@ -564,17 +1009,20 @@ pub struct Video {
     pub duration: i64,
 
     /// Video thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
 
     /// Mime type of a file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 }
 
 /// This object represents a voice note
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Voice {
     /// Unique identifier for this file
     pub file_id: String,
@@ -583,15 +1031,17 @@ pub struct Voice {
     pub duration: i64,
 
     /// MIME type of the file as defined by sender
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mime_type: Option<String>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 }
 
 /// This object represents a [video message](https://telegram.org/blog/video-messages-and-telescope)
 /// (available in Telegram apps as of [v.4.0](https://telegram.org/blog/video-messages-and-telescope)
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct VideoNote {
     /// Unique identifier for this file
     pub file_id: String,
@@ -603,14 +1053,16 @@ pub struct VideoNote {
     pub duration: i64,
 
     /// Video thumbnail
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb: Option<PhotoSize>,
 
     /// File size
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 }
 
 /// This object represents a phone contact
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Contact {
     /// Contact's phone number
     pub phone_number: String,
@@ -619,18 +1071,21 @@ pub struct Contact {
     pub first_name: String,
 
     /// Contact's last name
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
 
     /// Contact's user identifier in Telegram
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_d: Option<i64>,
 
     /// Additional data about the contact in the
     /// form of a [vCard](https://en.wikipedia.org/wiki/VCard)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub vcard: Option<String>,
 }
 
 /// This object represents a point on the map
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Location {
     /// Longitude as defined by sender
     pub longitude: f32,
@@ -639,7 +1094,7 @@ pub struct Location {
 }
 
 /// This object represents a venue
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Venue {
     /// Venue location
     pub location: Location,
@@ -651,38 +1106,447 @@ pub struct Venue {
     pub address: String,
 
     /// Foursquare identifier of the venue
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foursquare_id: Option<String>,
 
     /// Foursquare type of the venue. (For example, “arts_entertainment/default”,
     /// “arts_entertainment/aquarium” or “food/icecream”.)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub foursquare_type: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-pub struct Invoice {}
+/// This object contains basic information about an invoice
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Invoice {
+    /// Product name
+    pub title: String,
+
+    /// Product description
+    pub description: String,
+
+    /// Unique bot deep-linking parameter that can be used to generate this invoice
+    pub start_parameter: String,
+
+    /// Three-letter ISO 4217 [currency](https://core.telegram.org/bots/payments#supported-currencies) code,
+    /// or “XTR” for payments in Telegram Stars
+    pub currency: String,
+
+    /// Total price in the smallest units of the currency (integer, not float/double).
+    /// For example, for a price of US$ 1.45 `total_amount` = 145
+    pub total_amount: i64,
+}
+
+/// This object contains basic information about a successful payment
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SuccessfulPayment {
+    /// Three-letter ISO 4217 currency code, or “XTR” for payments in Telegram Stars
+    pub currency: String,
+
+    /// Total price in the smallest units of the currency
+    pub total_amount: i64,
 
-#[derive(Deserialize, Debug, Clone)]
-pub struct SuccessfulPayment {}
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+
+    /// Expiration date of the subscription in Unix time, for recurring payments only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_expiration_date: Option<i64>,
+
+    /// True, if the payment is a recurring payment for a subscription
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_recurring: Option<bool>,
+
+    /// True, if the payment is the first payment for a subscription
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_first_recurring: Option<bool>,
+
+    /// Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_option_id: Option<String>,
+
+    /// Order information provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_info: Option<OrderInfo>,
+
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+
+    /// Provider payment identifier
+    pub provider_payment_charge_id: String,
+}
+
+/// This object contains basic information about a refunded payment
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RefundedPayment {
+    /// Three-letter ISO 4217 currency code, or “XTR” for payments in Telegram Stars
+    pub currency: String,
+
+    /// Total refunded price in the smallest units of the currency
+    pub total_amount: i64,
+
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+
+    /// Telegram payment identifier
+    pub telegram_payment_charge_id: String,
+
+    /// Provider payment identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_payment_charge_id: Option<String>,
+}
+
+/// This object contains information about an incoming shipping query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ShippingQuery {
+    /// Unique query identifier
+    pub id: String,
+
+    /// User who sent the query
+    pub from: User,
+
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+
+    /// User specified shipping address
+    pub shipping_address: ShippingAddress,
+}
+
+/// This object contains information about an incoming pre-checkout query
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PreCheckoutQuery {
+    /// Unique query identifier
+    pub id: String,
+
+    /// User who sent the query
+    pub from: User,
+
+    /// Three-letter ISO 4217 currency code, or “XTR” for payments in Telegram Stars
+    pub currency: String,
+
+    /// Total price in the smallest units of the currency
+    pub total_amount: i64,
+
+    /// Bot specified invoice payload
+    pub invoice_payload: String,
+
+    /// Identifier of the shipping option chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_option_id: Option<String>,
+
+    /// Order information provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_info: Option<OrderInfo>,
+}
+
+/// Describes an amount of Telegram Stars
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StarAmount {
+    /// Integer amount of Telegram Stars, rounded to 0; can be negative
+    pub amount: i64,
+
+    /// The number of 1/1000000000 shares of Telegram Stars; from -999999999 to 999999999;
+    /// can be negative if and only if `amount` is non-positive
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nanostar_amount: Option<i64>,
+}
+
+/// Contains a list of Telegram Star transactions
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StarTransactions {
+    /// The list of transactions
+    pub transactions: Vec<StarTransaction>,
+}
+
+/// Describes a Telegram Star transaction. Incoming transactions have `source`, outgoing ones have `receiver`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StarTransaction {
+    /// Unique identifier of the transaction. Coincides with the identifier of the original transaction
+    /// for refund transactions. Coincides with `SuccessfulPayment::telegram_payment_charge_id`
+    /// for successful incoming payments from users
+    pub id: String,
+
+    /// Integer amount of Telegram Stars transferred by the transaction
+    pub amount: i64,
+
+    /// The number of 1/1000000000 shares of Telegram Stars transferred by the transaction; from 0 to 999999999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nanostar_amount: Option<i64>,
+
+    /// Date the transaction was created in Unix time
+    pub date: i64,
+
+    /// Source of an incoming transaction. Only for incoming transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<TransactionPartner>,
+
+    /// Receiver of an outgoing transaction. Only for outgoing transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver: Option<TransactionPartner>,
+}
+
+/// Source of an incoming or receiver of an outgoing Star transaction
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum TransactionPartner {
+    /// Transaction with a user
+    User(TransactionPartnerUser),
+
+    /// Transaction with a chat
+    Chat(TransactionPartnerChat),
+
+    /// Affiliate program that received a commission from the transaction
+    AffiliateProgram(TransactionPartnerAffiliateProgram),
+
+    /// Withdrawal transaction with Fragment
+    Fragment(TransactionPartnerFragment),
+
+    /// Withdrawal transaction to the Telegram Ads platform
+    TelegramAds,
+
+    /// Transaction with payment for [paid broadcasting](https://core.telegram.org/bots/api#paid-broadcasts)
+    TelegramApi(TransactionPartnerTelegramApi),
+
+    /// Transaction with an unknown source or recipient
+    #[serde(other)]
+    Other,
+}
+
+/// Transaction with a user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransactionPartnerUser {
+    /// Type of the transaction, currently one of “invoice_payment”, “paid_media_payment”,
+    /// “gift_purchase”, “premium_purchase” or “business_account_transfer”
+    pub transaction_type: String,
+
+    /// Information about the user
+    pub user: User,
+
+    /// Information about the affiliate that received a commission via this transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affiliate: Option<AffiliateInfo>,
+
+    /// Bot-specified invoice payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_payload: Option<String>,
+
+    /// The duration of the paid subscription
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_period: Option<i64>,
+
+    /// Bot-specified paid media payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid_media_payload: Option<String>,
+
+    /// Number of months the gifted Telegram Premium subscription will be active for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_subscription_duration: Option<i64>,
+}
+
+/// Transaction with a chat
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransactionPartnerChat {
+    /// Information about the chat
+    pub chat: Chat,
+}
+
+/// Affiliate program that received a commission from the transaction
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransactionPartnerAffiliateProgram {
+    /// Information about the bot that sponsored the affiliate program
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sponsor_user: Option<User>,
+
+    /// The number of Telegram Stars received by the bot for each 1000 Telegram Stars received
+    /// by the affiliate program sponsor from referred users
+    pub commission_per_mille: i64,
+}
+
+/// Withdrawal transaction with Fragment
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransactionPartnerFragment {
+    /// State of the transaction if the transaction is outgoing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdrawal_state: Option<RevenueWithdrawalState>,
+}
+
+/// Transaction with payment for paid broadcasting
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransactionPartnerTelegramApi {
+    /// The number of successful requests that exceeded regular limits and were therefore billed
+    pub request_count: i64,
+}
+
+/// State of a revenue withdrawal operation
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RevenueWithdrawalState {
+    /// The withdrawal is in progress
+    Pending,
+
+    /// The withdrawal succeeded
+    Succeeded {
+        /// Date the withdrawal was completed in Unix time
+        date: i64,
+
+        /// An HTTPS URL that can be used to see transaction details
+        url: String,
+    },
+
+    /// The withdrawal failed and the transaction was refunded
+    Failed,
+}
 
-#[derive(Deserialize, Debug, Clone)]
-pub struct PassportData {}
+/// Information about the affiliate that received a commission via a transaction
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AffiliateInfo {
+    /// The bot or the user that received an affiliate commission if it was received by a bot or a user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affiliate_user: Option<User>,
+
+    /// The chat that received an affiliate commission if it was received by a chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affiliate_chat: Option<Chat>,
+
+    /// The number of Telegram Stars received by the affiliate for each 1000 Telegram Stars received by the bot
+    /// from referred users
+    pub commission_per_mille: i64,
+
+    /// Integer amount of Telegram Stars received by the affiliate from the transaction, rounded to 0;
+    /// can be negative for refunds
+    pub amount: i64,
+
+    /// The number of 1/1000000000 shares of Telegram Stars received by the affiliate; can be negative for refunds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nanostar_amount: Option<i64>,
+}
+
+/// Contains information about Telegram Passport data shared with the bot by the user.
+/// Use `passport::PassportKey` to decrypt it (requires the `passport` feature)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportData {
+    /// Array with information about documents and other Telegram Passport elements that was shared with the bot
+    pub data: Vec<EncryptedPassportElement>,
+
+    /// Encrypted credentials required to decrypt the data
+    pub credentials: EncryptedCredentials,
+}
+
+/// Type of Telegram Passport element
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptedPassportElementType {
+    PersonalDetails,
+    Passport,
+    DriverLicense,
+    IdentityCard,
+    InternalPassport,
+    Address,
+    UtilityBill,
+    BankStatement,
+    RentalAgreement,
+    PassportRegistration,
+    TemporaryRegistration,
+    PhoneNumber,
+    Email,
+}
+
+/// Describes documents or other Telegram Passport elements shared with the bot by the user
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EncryptedPassportElement {
+    /// Element type
+    #[serde(rename = "type")]
+    pub typ: EncryptedPassportElementType,
+
+    /// Base64-encoded encrypted Telegram Passport element data provided by the user;
+    /// available only for “personal_details”, “passport”, “driver_license”, “identity_card”,
+    /// “internal_passport” and “address” types
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+
+    /// User's verified phone number; available only for “phone_number” type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    /// User's verified email address; available only for “email” type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Array of encrypted files with documents provided by the user; available only for “utility_bill”,
+    /// “bank_statement”, “rental_agreement”, “passport_registration” and “temporary_registration” types
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<PassportFile>>,
+
+    /// Encrypted file with the front side of the document, provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub front_side: Option<PassportFile>,
+
+    /// Encrypted file with the reverse side of the document, provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reverse_side: Option<PassportFile>,
+
+    /// Encrypted file with the selfie of the user holding a document, provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selfie: Option<PassportFile>,
+
+    /// Array of encrypted files with translated versions of documents provided by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation: Option<Vec<PassportFile>>,
+
+    /// Base64-encoded element hash for using in `PassportElementError::Unspecified`
+    pub hash: String,
+}
+
+/// This object represents a file uploaded to Telegram Passport.
+/// Currently all Telegram Passport files are in JPEG format when decrypted and don't exceed 10MB
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PassportFile {
+    /// Identifier for this file, which can be used to download the file
+    pub file_id: String,
+
+    /// Unique identifier for this file, which is supposed to be the same over time and for different bots
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_unique_id: Option<String>,
+
+    /// File size in bytes
+    pub file_size: i64,
+
+    /// Unix time when the file was uploaded
+    pub file_date: i64,
+}
+
+/// Describes data required for decrypting and authenticating `EncryptedPassportElement`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EncryptedCredentials {
+    /// Base64-encoded encrypted JSON-serialized data with unique user's payload,
+    /// data hashes and secrets required for `EncryptedPassportElement` decryption and authentication
+    pub data: String,
+
+    /// Base64-encoded data hash for data authentication
+    pub hash: String,
+
+    /// Base64-encoded secret, encrypted with the bot's public RSA key, required for data decryption
+    pub secret: String,
+}
 
 /// This object represents a file ready to be downloaded. The file can be downloaded via method
 /// `download_file`
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct File {
     /// Unique identifier for this file
     pub file_id: String,
 
     /// File size, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_size: Option<i64>,
 
     /// File path. Pass it to `download_file` method to download it
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_path: Option<String>,
 }
 
 /// This object represents a chat
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Chat {
     /// Unique identifier for this chat.
     pub id: i64,
@@ -692,43 +1556,54 @@ pub struct Chat {
     pub typ: String,
 
     /// Title, for supergroups, channels and group chats
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
 
     /// Username, for private chats, supergroups and channels if available
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 
     /// First name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub first_name: Option<String>,
 
     /// Last name of the other party in a private chat
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
 
     /// True if a group has ‘All Members Are Admins’ enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub all_members_are_administrators: Option<bool>,
 
     /// Chat photo. Returned only in [`crate::requests::GetChat`]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub photo: Option<ChatPhoto>,
 
     /// Description, for supergroups and channel chats. Returned only in [`crate::requests::GetChat`]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 
     /// Chat invite link, for supergroups and channel chats. Each administrator in a chat generates
     /// their own invite links, so the bot must first generate the link using [crate::requests::ExportChatInviteLink].
     /// Returned only in [`crate::requests::GetChat`]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub invite_link: Option<String>,
 
     /// Pinned message, for supergroups and channel chats. Returned only in [`crate::requests::GetChat`]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pinned_message: Option<Box<Message>>,
 
     /// For supergroups, name of group sticker set. Returned only in [`crate::requests::GetChat`]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sticker_set_name: Option<String>,
 
     /// True, if the bot can change the group sticker set. Returned only in [`crate::requests::GetChat`]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_set_sticker_set: Option<bool>,
 }
 
 /// This object represents a chat photo.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ChatPhoto {
     /// Unique file identifier of small (160x160) chat photo. This file_id can be used only
     /// for photo download
@@ -740,7 +1615,7 @@ pub struct ChatPhoto {
 }
 
 /// This object represents a shipping address
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ShippingAddress {
     /// ISO 3166-1 alpha-2 country code
     pub country_code: String,
@@ -762,23 +1637,27 @@ pub struct ShippingAddress {
 }
 
 /// This object represents information about an order
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct OrderInfo {
     /// User name
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
     /// User's phone number
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub phone_number: Option<String>,
 
     /// User email
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
 
     /// User shipping address
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub shipping_address: Option<ShippingAddress>,
 }
 
 /// This object represents a Telegram user or bot.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct User {
     /// Unique identifier for this user or bot
     pub id: i64,
@@ -790,15 +1669,66 @@ pub struct User {
     pub first_name: String,
 
     /// User‘s or bot’s last name
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
 
     /// User‘s or bot’s username
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 
     /// [IETF language tag](https://en.wikipedia.org/wiki/IETF_language_tag) of the user's language
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub language_code: Option<String>,
 }
 
+/// This object represents an incoming inline query.
+/// When the user sends an empty query, your bot could return some default or trending results
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineQuery {
+    /// Unique identifier for this query
+    pub id: String,
+
+    /// Sender
+    pub from: User,
+
+    /// Text of the query (up to 256 characters)
+    pub query: String,
+
+    /// Offset of the results to be returned, can be controlled by the bot
+    pub offset: String,
+
+    /// Type of the chat from which the inline query was sent. Can be either “sender” for a private chat
+    /// with the inline query sender, “private”, “group”, “supergroup”, or “channel”
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_type: Option<String>,
+
+    /// Sender location, only for bots that request user location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+}
+
+/// Represents a result of an inline query that was chosen by the user and sent to their chat partner
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChosenInlineResult {
+    /// The unique identifier for the result that was chosen
+    pub result_id: String,
+
+    /// The user that chose the result
+    pub from: User,
+
+    /// Sender location, only for bots that require user location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+
+    /// Identifier of the sent inline message. Available only if there is an inline keyboard attached to the message.
+    /// Will be also received in callback queries and can be used to edit the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+
+    /// The query that was used to obtain the result
+    pub query: String,
+}
+
 /// This object represents an incoming callback query from a callback button in an
 /// [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating).
 /// If the button that originated the query was attached to a message sent by the bot,
@@ -806,7 +1736,7 @@ pub struct User {
 /// (in
 /// [inline mode](https://core.telegram.org/bots/api#inline-mode)), the field inline_message_id will be present.
 /// Exactly one of the fields data or game_short_name will be present
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CallbackQuery {
     /// Unique identifier for this query
     pub id: String,
@@ -816,9 +1746,11 @@ pub struct CallbackQuery {
 
     /// Message with the callback button that originated the query. Note that message content
     /// and message date will not be available if the message is too old
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<Message>,
 
     /// Identifier of the message sent via the bot in inline mode, that originated the query
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inline_message_id: Option<String>,
 
     /// Global identifier, uniquely corresponding to the chat to which the message with the
@@ -828,18 +1760,357 @@ pub struct CallbackQuery {
 
     /// Data associated with the callback button. Be aware that a bad client can send
     /// arbitrary data in this field
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<String>,
 
     /// Short name of a Game to be returned, serves as the unique identifier for the game
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub game_short_name: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
-#[cfg_attr(feature = "cargo-clippy", allow(clippy::large_enum_variant))]
+#[allow(clippy::large_enum_variant)]
 pub enum EditedMessage {
     /// Returns if edited was not sent by the bot
     True(bool),
     /// Returns if edited message was sent by the bot
     Message(Message),
 }
+
+/// This object represents an [inline keyboard](https://core.telegram.org/bots#inline-keyboards-and-on-the-fly-updating)
+/// attached to a received message
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineKeyboardMarkup {
+    /// Array of button rows, each represented by an Array of `InlineKeyboardButton` objects
+    pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
+}
+
+/// This object represents one button of an inline keyboard attached to a received message.
+/// Exactly one of the optional fields is present
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InlineKeyboardButton {
+    /// Label text on the button
+    pub text: String,
+
+    /// HTTP or tg:// url to be opened when the button is pressed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Data to be sent in a callback query to the bot when the button is pressed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+
+    /// Web App that will be launched when the user presses the button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_app: Option<WebAppInfo>,
+
+    /// HTTP URL used to automatically authorize the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_url: Option<LoginUrl>,
+
+    /// Inline query inserted into the input field of the chat chosen by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_inline_query: Option<String>,
+
+    /// Inline query inserted into the input field of the current chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_inline_query_current_chat: Option<String>,
+
+    /// Inline query inserted into the input field of a chat of the chosen type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_inline_query_chosen_chat: Option<SwitchInlineQueryChosenChat>,
+
+    /// Text copied to the clipboard when the button is pressed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copy_text: Option<CopyTextButton>,
+
+    /// Game that will be launched when the user presses the button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_game: Option<CallbackGame>,
+
+    /// True, if the button is a pay button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pay: Option<bool>,
+}
+
+/// Describes a [Web App](https://core.telegram.org/bots/webapps)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct WebAppInfo {
+    /// An HTTPS URL of a Web App to be opened
+    pub url: String,
+}
+
+/// This object represents a parameter of the inline keyboard button used to automatically
+/// authorize a user. See [Telegram Login Widget](https://core.telegram.org/widgets/login)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoginUrl {
+    /// An HTTPS URL to be opened with user authorization data added to the query string
+    /// when the button is pressed
+    pub url: String,
+
+    /// New text of the button in forwarded messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward_text: Option<String>,
+
+    /// Username of a bot, which will be used for user authorization. Defaults to the current bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_username: Option<String>,
+
+    /// Pass true to request the permission for the bot to send messages to the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_write_access: Option<bool>,
+}
+
+/// This object represents an inline button that switches the current user to inline mode
+/// in a chosen chat, with an optional default inline query
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct SwitchInlineQueryChosenChat {
+    /// The default inline query to be inserted in the input field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+
+    /// True, if private chats with users can be chosen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_user_chats: Option<bool>,
+
+    /// True, if private chats with bots can be chosen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_bot_chats: Option<bool>,
+
+    /// True, if group and supergroup chats can be chosen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_group_chats: Option<bool>,
+
+    /// True, if channel chats can be chosen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_channel_chats: Option<bool>,
+}
+
+/// This object represents an inline keyboard button that copies specified text to the clipboard
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CopyTextButton {
+    /// The text to be copied to the clipboard, 1-256 characters
+    pub text: String,
+}
+
+/// A placeholder, currently holds no information. Use [BotFather](https://t.me/botfather)
+/// to set up your game
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CallbackGame {}
+
+/// Represents the rights of an administrator in a chat
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChatAdministratorRights {
+    /// True, if the user's presence in the chat is hidden
+    pub is_anonymous: bool,
+
+    /// True, if the administrator can access the chat event log, get boost list, see hidden supergroup
+    /// and channel members, report spam messages and ignore slow mode
+    pub can_manage_chat: bool,
+
+    /// True, if the administrator can delete messages of other users
+    pub can_delete_messages: bool,
+
+    /// True, if the administrator can manage video chats
+    pub can_manage_video_chats: bool,
+
+    /// True, if the administrator can restrict, ban or unban chat members, or access supergroup statistics
+    pub can_restrict_members: bool,
+
+    /// True, if the administrator can add new administrators with a subset of their own privileges
+    pub can_promote_members: bool,
+
+    /// True, if the user is allowed to change the chat title, photo and other settings
+    pub can_change_info: bool,
+
+    /// True, if the user is allowed to invite new users to the chat
+    pub can_invite_users: bool,
+
+    /// True, if the administrator can post stories to the chat
+    pub can_post_stories: bool,
+
+    /// True, if the administrator can edit stories posted by other users
+    pub can_edit_stories: bool,
+
+    /// True, if the administrator can delete stories posted by other users
+    pub can_delete_stories: bool,
+
+    /// True, if the administrator can post messages in the channel; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_post_messages: Option<bool>,
+
+    /// True, if the administrator can edit messages of other users and can pin messages; channels only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_edit_messages: Option<bool>,
+
+    /// True, if the user is allowed to pin messages; groups and supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+
+    /// True, if the user is allowed to create, rename, close, and reopen forum topics; supergroups only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_manage_topics: Option<bool>,
+}
+
+/// This object contains information about the users whose identifiers were shared with the bot
+/// using a `KeyboardButton::RequestUsers` button
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UsersShared {
+    /// Identifier of the request
+    pub request_id: i64,
+
+    /// Information about users shared with the bot
+    pub users: Vec<SharedUser>,
+}
+
+/// This object contains information about a user that was shared with the bot
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SharedUser {
+    /// Identifier of the shared user. The bot may not have access to the user and could be unable
+    /// to use this identifier, unless the user is already known to the bot by some other means
+    pub user_id: i64,
+
+    /// First name of the user, if the name was requested by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+
+    /// Last name of the user, if the name was requested by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// Username of the user, if the username was requested by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Available sizes of the chat photo, if the photo was requested by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Vec<PhotoSize>>,
+}
+
+/// This object contains information about a chat that was shared with the bot
+/// using a `KeyboardButton::RequestChat` button
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatShared {
+    /// Identifier of the request
+    pub request_id: i64,
+
+    /// Identifier of the shared chat. The bot may not have access to the chat and could be unable
+    /// to use this identifier, unless the chat is already known to the bot by some other means
+    pub chat_id: i64,
+
+    /// Title of the chat, if the title was requested by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Username of the chat, if the username was requested by the bot and available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Available sizes of the chat photo, if the photo was requested by the bot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Vec<PhotoSize>>,
+}
+
+/// Describes data sent from a [Web App](https://core.telegram.org/bots/webapps) to the bot
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WebAppData {
+    /// The data. Be aware that a bad client can send arbitrary data in this field
+    pub data: String,
+
+    /// Text of the `web_app` keyboard button from which the Web App was opened.
+    /// Be aware that a bad client can send arbitrary data in this field
+    pub button_text: String,
+}
+
+/// This object represents the bot's name
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotName {
+    /// The bot's name
+    pub name: String,
+}
+
+/// This object represents the bot's description
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotDescription {
+    /// The bot's description
+    pub description: String,
+}
+
+/// This object represents the bot's short description
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BotShortDescription {
+    /// The bot's short description
+    pub short_description: String,
+}
+
+/// Represents an invite link for a chat
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatInviteLink {
+    /// The invite link. If the link was created by another chat administrator,
+    /// then the second part of the link will be replaced with “…”
+    pub invite_link: String,
+
+    /// Creator of the link
+    pub creator: User,
+
+    /// True, if users joining the chat via the link need to be approved by chat administrators
+    pub creates_join_request: bool,
+
+    /// True, if the link is primary
+    pub is_primary: bool,
+
+    /// True, if the link is revoked
+    pub is_revoked: bool,
+
+    /// Invite link name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Point in time (Unix timestamp) when the link will expire or has been expired
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_date: Option<i64>,
+
+    /// The maximum number of users that can be members of the chat simultaneously
+    /// after joining the chat via this invite link; 1-99999
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member_limit: Option<i32>,
+
+    /// Number of pending join requests created using this link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_join_request_count: Option<i32>,
+
+    /// The number of seconds the subscription will be active for before the next payment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_period: Option<i32>,
+
+    /// The amount of Telegram Stars a user must pay initially and after each subsequent subscription period
+    /// to be a member of the chat using the link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription_price: Option<i64>,
+}
+
+/// Represents a join request sent to a chat
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChatJoinRequest {
+    /// Chat to which the request was sent
+    pub chat: Chat,
+
+    /// User that sent the join request
+    pub from: User,
+
+    /// Identifier of a private chat with the user who sent the join request.
+    /// The bot can use this identifier for 5 minutes to send messages until the join request is processed
+    pub user_chat_id: i64,
+
+    /// Date the request was sent in Unix time
+    pub date: i64,
+
+    /// Bio of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+
+    /// Chat invite link that was used by the user to send the join request
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invite_link: Option<ChatInviteLink>,
+}
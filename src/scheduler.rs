@@ -0,0 +1,420 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    path::PathBuf,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use chrono::{TimeZone, Utc};
+use futures_util::future::{ready, BoxFuture, FutureExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::Notify;
+
+use crate::{
+    client::{flood_wait, Rutebot},
+    error::Error,
+    requests::Request,
+};
+
+const MAX_FLOOD_RETRIES: usize = 3;
+
+/// When a scheduled request should be sent
+#[derive(Debug, Clone)]
+pub enum When {
+    /// Send once at the specified time
+    At(SystemTime),
+
+    /// Send once after the delay
+    After(Duration),
+
+    /// Send periodically according to the cron expression in UTC.
+    /// The expression has seconds as the first field, e.g. `0 30 9 * * Mon-Fri` for 9:30 on weekdays
+    Cron(String),
+}
+
+/// Request stored in `JobStorage`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Job {
+    /// Unique identifier of the job
+    pub id: String,
+
+    /// Telegram bot api method
+    pub method: String,
+
+    /// Serialized request
+    pub payload: Value,
+
+    /// Unix time in milliseconds when the request should be sent next time
+    pub next_run: u64,
+
+    /// Cron expression for periodic jobs
+    pub cron: Option<String>,
+}
+
+impl Job {
+    fn next_cron_run(&self, after: u64) -> Result<Option<u64>, Error> {
+        match &self.cron {
+            Some(cron) => next_cron_run(cron, after).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Storage for scheduled jobs
+pub trait JobStorage: Send + Sync {
+    /// Insert or replace the job
+    fn save(&self, job: Job) -> BoxFuture<'_, Result<(), Error>>;
+
+    /// Replace the job only if it still exists, atomically with respect to `remove`.
+    /// Returns `false` if there was no job with such id
+    fn replace(&self, job: Job) -> BoxFuture<'_, Result<bool, Error>>;
+
+    /// Remove the job. Returns `false` if there was no job with such id
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, Error>>;
+
+    /// Get all jobs
+    fn load_all(&self) -> BoxFuture<'_, Result<Vec<Job>, Error>>;
+}
+
+/// `JobStorage` which keeps jobs in memory. All jobs are lost on restart
+#[derive(Default)]
+pub struct InMemoryJobStorage {
+    jobs: Mutex<HashMap<String, Job>>,
+}
+
+impl InMemoryJobStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl JobStorage for InMemoryJobStorage {
+    fn save(&self, job: Job) -> BoxFuture<'_, Result<(), Error>> {
+        self.jobs.lock().unwrap().insert(job.id.clone(), job);
+        ready(Ok(())).boxed()
+    }
+
+    fn replace(&self, job: Job) -> BoxFuture<'_, Result<bool, Error>> {
+        let replaced = match self.jobs.lock().unwrap().get_mut(&job.id) {
+            Some(stored) => {
+                *stored = job;
+                true
+            }
+            None => false,
+        };
+        ready(Ok(replaced)).boxed()
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, Error>> {
+        let removed = self.jobs.lock().unwrap().remove(id).is_some();
+        ready(Ok(removed)).boxed()
+    }
+
+    fn load_all(&self) -> BoxFuture<'_, Result<Vec<Job>, Error>> {
+        let jobs = self.jobs.lock().unwrap().values().cloned().collect();
+        ready(Ok(jobs)).boxed()
+    }
+}
+
+/// `JobStorage` which keeps jobs in memory and writes all of them to a JSON file on every change
+pub struct JsonFileJobStorage {
+    path: PathBuf,
+    jobs: tokio::sync::Mutex<HashMap<String, Job>>,
+}
+
+impl JsonFileJobStorage {
+    /// Open storage, loading jobs from the file if it exists
+    pub async fn open<P: Into<PathBuf>>(path: P) -> Result<Self, Error> {
+        let path = path.into();
+        let jobs = match tokio::fs::read(&path).await {
+            Ok(content) => serde_json::from_slice(&content).map_err(Error::Serde)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(err) => return Err(Error::IO(err)),
+        };
+        Ok(Self {
+            path,
+            jobs: tokio::sync::Mutex::new(jobs),
+        })
+    }
+
+    async fn write(&self, jobs: &HashMap<String, Job>) -> Result<(), Error> {
+        let content = serde_json::to_vec(jobs).map_err(Error::Serde)?;
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        tokio::fs::write(&tmp_path, content)
+            .await
+            .map_err(Error::IO)?;
+        tokio::fs::rename(&tmp_path, &self.path)
+            .await
+            .map_err(Error::IO)
+    }
+}
+
+impl JobStorage for JsonFileJobStorage {
+    fn save(&self, job: Job) -> BoxFuture<'_, Result<(), Error>> {
+        async move {
+            let mut jobs = self.jobs.lock().await;
+            jobs.insert(job.id.clone(), job);
+            self.write(&jobs).await
+        }
+        .boxed()
+    }
+
+    fn replace(&self, job: Job) -> BoxFuture<'_, Result<bool, Error>> {
+        async move {
+            let mut jobs = self.jobs.lock().await;
+            match jobs.get_mut(&job.id) {
+                Some(stored) => {
+                    *stored = job;
+                    self.write(&jobs).await?;
+                    Ok(true)
+                }
+                None => Ok(false),
+            }
+        }
+        .boxed()
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, Error>> {
+        async move {
+            let mut jobs = self.jobs.lock().await;
+            if jobs.remove(id).is_some() {
+                self.write(&jobs).await?;
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+        .boxed()
+    }
+
+    fn load_all(&self) -> BoxFuture<'_, Result<Vec<Job>, Error>> {
+        async move { Ok(self.jobs.lock().await.values().cloned().collect()) }.boxed()
+    }
+}
+
+type ErrorHandler = dyn Fn(Job, Error) -> BoxFuture<'static, ()> + Send + Sync;
+
+struct Inner<St> {
+    rutebot: Rutebot,
+    storage: St,
+    wake: Notify,
+    counter: AtomicU64,
+    error_handler: Box<ErrorHandler>,
+}
+
+/// Sends requests at the specified time, after a delay or periodically.
+/// Jobs are kept in `JobStorage`, so with a persistent storage they survive restarts.
+/// If telegram asks to wait because of flood control, the request is repeated after the requested delay.
+///
+/// Requests are stored serialized to JSON, so requests which upload files with `FileKind::InputFile`
+/// can't be scheduled and are rejected by `schedule`. Use file ids or urls instead.
+///
+/// ## Example
+/// ```no_run
+/// # use rutebot::{client::Rutebot, requests::SendMessage, scheduler::{InMemoryJobStorage, Scheduler, When}};
+/// # use std::time::Duration;
+/// # async fn run() -> Result<(), rutebot::error::Error> {
+/// let scheduler = Scheduler::new(Rutebot::new("token"), InMemoryJobStorage::new());
+/// let reminder = SendMessage::new(42, "Don't forget about the meeting");
+/// scheduler.schedule(reminder, When::After(Duration::from_secs(3600))).await?;
+/// let digest = SendMessage::new(42, "Daily digest");
+/// scheduler.schedule(digest, When::Cron("0 0 9 * * *".to_string())).await?;
+/// scheduler.run().await
+/// # }
+/// ```
+pub struct Scheduler<St> {
+    inner: Arc<Inner<St>>,
+}
+
+impl<St> Clone for Scheduler<St> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<St: JobStorage + 'static> Scheduler<St> {
+    pub fn new(rutebot: Rutebot, storage: St) -> Self {
+        let inner = Inner {
+            rutebot,
+            storage,
+            wake: Notify::new(),
+            counter: AtomicU64::new(0),
+            error_handler: Box::new(|job, err| {
+                eprintln!("Error has occurred while sending job {}: {}", job.id, err);
+                ready(()).boxed()
+            }),
+        };
+        Self {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Set handler for errors of sending jobs. By default errors are printed to stderr.
+    /// Must be called before the scheduler is cloned
+    pub fn error_handler<F, Fut>(mut self, error_handler: F) -> Self
+    where
+        F: Fn(Job, Error) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Arc::get_mut(&mut self.inner)
+            .expect("Scheduler must not be changed after it is cloned")
+            .error_handler = Box::new(move |job, err| error_handler(job, err).boxed());
+        self
+    }
+
+    /// Schedule the request. Returns identifier of the job which can be used to cancel it.
+    /// Fails with `Error::RequestBuilt` if the request uploads files
+    pub async fn schedule<R: Request>(&self, request: R, when: When) -> Result<String, Error> {
+        let payload = serde_json::to_value(&request).map_err(Error::Serde)?;
+        if has_attached_file(&payload) {
+            return Err(Error::RequestBuilt(
+                "Requests uploading files can't be scheduled".to_string(),
+            ));
+        }
+        let now = now_millis();
+        let (next_run, cron) = match when {
+            When::At(time) => (to_millis(time), None),
+            When::After(delay) => (now + delay.as_millis() as u64, None),
+            When::Cron(cron) => (next_cron_run(&cron, now)?, Some(cron)),
+        };
+        let job = Job {
+            id: self.next_id(),
+            method: request.method().to_string(),
+            payload,
+            next_run,
+            cron,
+        };
+        let id = job.id.clone();
+        self.inner.storage.save(job).await?;
+        self.inner.wake.notify_one();
+        Ok(id)
+    }
+
+    /// Cancel the job. Returns `false` if there was no job with such id
+    pub async fn cancel(&self, id: &str) -> Result<bool, Error> {
+        let removed = self.inner.storage.remove(id).await?;
+        self.inner.wake.notify_one();
+        Ok(removed)
+    }
+
+    /// Get all scheduled jobs
+    pub async fn jobs(&self) -> Result<Vec<Job>, Error> {
+        self.inner.storage.load_all().await
+    }
+
+    /// Send jobs when they are due. Returns only on storage errors
+    pub async fn run(&self) -> Result<(), Error> {
+        loop {
+            let now = now_millis();
+            let mut next_wake = None::<u64>;
+            for job in self.inner.storage.load_all().await? {
+                if job.next_run > now {
+                    next_wake = Some(next_wake.map_or(job.next_run, |x| x.min(job.next_run)));
+                    continue;
+                }
+                // The job may have been cancelled after it was loaded,
+                // so it is sent only if it is still in the storage
+                let still_scheduled = match job.next_cron_run(now) {
+                    Ok(Some(next_run)) => {
+                        self.inner
+                            .storage
+                            .replace(Job {
+                                next_run,
+                                ..job.clone()
+                            })
+                            .await?
+                    }
+                    Ok(None) => self.inner.storage.remove(&job.id).await?,
+                    Err(err) => {
+                        self.inner.storage.remove(&job.id).await?;
+                        (self.inner.error_handler)(job, err).await;
+                        continue;
+                    }
+                };
+                if !still_scheduled {
+                    continue;
+                }
+                let scheduler = self.clone();
+                tokio::spawn(async move { scheduler.send(job).await });
+            }
+            let sleep = next_wake.map_or(Duration::from_secs(3600), |x| {
+                Duration::from_millis(x.saturating_sub(now_millis()))
+            });
+            let _ = tokio::time::timeout(sleep, self.inner.wake.notified()).await;
+        }
+    }
+
+    async fn send(&self, job: Job) {
+        let mut retries = 0;
+        loop {
+            let result = self
+                .inner
+                .rutebot
+                .prepare_json_request(&job.method, &job.payload)
+                .send()
+                .await;
+            match result.as_ref().err().and_then(flood_wait) {
+                Some(retry_after) if retries < MAX_FLOOD_RETRIES => {
+                    retries += 1;
+                    tokio::time::sleep(retry_after).await;
+                }
+                _ => {
+                    if let Err(err) = result {
+                        (self.inner.error_handler)(job, err).await;
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    fn next_id(&self) -> String {
+        let counter = self.inner.counter.fetch_add(1, Ordering::Relaxed);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_nanos() as u64);
+        format!("{:x}{:x}", nanos, counter)
+    }
+}
+
+fn next_cron_run(cron: &str, after: u64) -> Result<u64, Error> {
+    let schedule = cron::Schedule::from_str(cron)
+        .map_err(|x| Error::RequestBuilt(format!("Invalid cron expression {:?}: {}", cron, x)))?;
+    let after = Utc
+        .timestamp_millis_opt(after as i64)
+        .single()
+        .unwrap_or_else(Utc::now);
+    schedule
+        .after(&after)
+        .next()
+        .map(|x| x.timestamp_millis() as u64)
+        .ok_or_else(|| Error::RequestBuilt(format!("Cron expression {:?} never fires", cron)))
+}
+
+/// Uploaded files are serialized as `attach://<name>`, their content is not stored
+fn has_attached_file(payload: &Value) -> bool {
+    match payload {
+        Value::String(s) => s.starts_with("attach://"),
+        Value::Array(values) => values.iter().any(has_attached_file),
+        Value::Object(map) => map.values().any(has_attached_file),
+        _ => false,
+    }
+}
+
+fn to_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |x| x.as_millis() as u64)
+}
+
+fn now_millis() -> u64 {
+    to_millis(SystemTime::now())
+}
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+
+use rutebot::{
+    ask::{Asker, Question},
+    client::Rutebot,
+    error::Error,
+    requests::InlineKeyboard,
+    responses::Update,
+};
+
+fn text_update(chat_id: i64, user_id: i64, text: &str) -> Update {
+    serde_json::from_value(serde_json::json!({
+        "update_id": 1,
+        "message": {
+            "message_id": 1,
+            "date": 0,
+            "chat": { "id": chat_id, "type": "group" },
+            "from": { "id": user_id, "is_bot": false, "first_name": "user" },
+            "text": text
+        }
+    }))
+    .unwrap()
+}
+
+fn press_update(chat_id: i64, user_id: i64, message_id: i64) -> Update {
+    serde_json::from_value(serde_json::json!({
+        "update_id": 2,
+        "callback_query": {
+            "id": "query",
+            "from": { "id": user_id, "is_bot": false, "first_name": "user" },
+            "message": {
+                "message_id": message_id,
+                "date": 0,
+                "chat": { "id": chat_id, "type": "group" }
+            },
+            "chat_instance": "instance",
+            "data": "yes"
+        }
+    }))
+    .unwrap()
+}
+
+fn asker() -> Asker {
+    Asker::new(Rutebot::new("token"))
+}
+
+#[tokio::test]
+async fn updates_without_waiters_are_returned() {
+    let asker = asker();
+
+    let update = asker.feed(text_update(1, 2, "hi"));
+
+    assert_eq!(update.and_then(|x| x.message?.text), Some("hi".to_string()));
+}
+
+#[tokio::test]
+async fn message_is_taken_only_from_awaited_user_in_awaited_chat() {
+    let asker = asker();
+    let reply = asker.wait_message(1, 2, Duration::from_secs(5));
+
+    let other_chat = asker.feed(text_update(3, 2, "other chat"));
+    let other_user = asker.feed(text_update(1, 4, "other user"));
+    let awaited = asker.feed(text_update(1, 2, "answer"));
+
+    assert!(other_chat.is_some());
+    assert!(other_user.is_some());
+    assert!(awaited.is_none());
+    assert_eq!(reply.await.unwrap().text, Some("answer".to_string()));
+}
+
+#[tokio::test]
+async fn callback_query_is_taken_only_for_awaited_message() {
+    let asker = asker();
+    let press = asker.wait_callback_query(1, 2, 10, Duration::from_secs(5));
+
+    let other_message = asker.feed(press_update(1, 2, 11));
+    let other_user = asker.feed(press_update(1, 4, 10));
+    let message = asker.feed(text_update(1, 2, "text"));
+    let awaited = asker.feed(press_update(1, 2, 10));
+
+    assert!(other_message.is_some());
+    assert!(other_user.is_some());
+    assert!(message.is_some());
+    assert!(awaited.is_none());
+    assert_eq!(press.await.unwrap().data, Some("yes".to_string()));
+}
+
+#[tokio::test]
+async fn waiting_times_out() {
+    let asker = asker();
+
+    let reply = asker.wait_message(1, 2, Duration::from_millis(10)).await;
+
+    assert!(matches!(reply, Err(Error::Timeout)));
+}
+
+#[tokio::test]
+async fn timed_out_waiter_does_not_take_updates() {
+    let asker = asker();
+    let _ = asker.wait_message(1, 2, Duration::from_millis(10)).await;
+
+    let update = asker.feed(text_update(1, 2, "late"));
+
+    assert!(update.is_some());
+}
+
+#[tokio::test]
+async fn press_is_held_back_while_keyboard_question_is_sent() {
+    let asker = asker();
+    let question = Question::new(1, 2, "Sure?");
+    let mut answer = Box::pin(asker.ask_with_keyboard(question, InlineKeyboard::new()));
+    assert!(futures_util::poll!(&mut answer).is_pending());
+
+    let press = asker.feed(press_update(1, 2, 10));
+    let other_chat = asker.feed(press_update(3, 2, 10));
+    drop(answer);
+    let released = asker.feed(text_update(1, 2, "text"));
+
+    assert!(press.is_none());
+    assert!(other_chat.is_some());
+    assert!(released.is_some());
+    assert_eq!(asker.take_released().len(), 1);
+}
@@ -1,6 +1,7 @@
 use pretty_assertions::assert_eq;
 
 use rutebot::{
+    formatting::{send_with_caption, FormattedText},
     requests::{
         ChatAction, DeleteChatPhoto, DeleteMessage, EditLiveLocation, EditMessageCaption,
         EditMessageMedia, EditMessageText, ExportChatInviteLink, FileKind, ForwardMessage, GetChat,
@@ -17,7 +18,7 @@ use rutebot::{
         Poll, Update, User, UserProfilePhotos, Venue, Video, VideoNote, Voice,
     },
 };
-use std::{fs::File, io::Read, time::Instant};
+use std::{borrow::Cow, fs::File, io::Read, time::Instant};
 
 mod common;
 
@@ -93,18 +94,18 @@ async fn send_document_works() {
     let rutebot = common::create_client();
     let chat_id = common::get_chat_id();
     let keyboard = InlineKeyboard {
-        inline_keyboard: &[vec![InlineKeyboardButton::CallbackData {
-            text: "text",
-            callback_data: "data",
-        }]],
+        inline_keyboard: Cow::Borrowed(&[vec![InlineKeyboardButton::CallbackData {
+            text: "text".into(),
+            callback_data: "data".into(),
+        }]]),
     };
     let request = SendDocument {
-        caption: Some("random file"),
+        caption: Some("random file".into()),
         reply_markup: Some(ReplyMarkup::InlineKeyboard(keyboard)),
         ..SendDocument::new(
             chat_id,
             FileKind::InputFile {
-                name: "superfile",
+                name: "superfile".into(),
                 content: vec![1, 2, 3, 4, 5],
                 thumb: None,
             },
@@ -143,11 +144,11 @@ async fn send_document_with_thumb_works() {
         .read_to_end(&mut photo_content)
         .unwrap();
     let request = SendDocument {
-        caption: Some("random file with thumb"),
+        caption: Some("random file with thumb".into()),
         ..SendDocument::new(
             chat_id,
             FileKind::InputFile {
-                name: "superfile",
+                name: "superfile".into(),
                 content: vec![1, 2, 3, 4, 5],
                 thumb: Some(photo_content),
             },
@@ -179,7 +180,7 @@ async fn send_photo_works() {
     let request = SendPhoto::new(
         chat_id,
         FileKind::InputFile {
-            name: "superphoto",
+            name: "superphoto".into(),
             content: photo_content,
             thumb: None,
         },
@@ -190,6 +191,39 @@ async fn send_photo_works() {
     assert_eq!(response.photo.is_some(), true);
 }
 
+#[tokio::test]
+async fn send_photo_with_long_caption_works() {
+    let rutebot = common::create_client();
+    let chat_id = common::get_chat_id();
+    let mut photo_content = Vec::new();
+    File::open("./tests/photo_test.jpg")
+        .unwrap()
+        .read_to_end(&mut photo_content)
+        .unwrap();
+    let request = SendPhoto::new(
+        chat_id,
+        FileKind::InputFile {
+            name: "superphoto".into(),
+            content: photo_content,
+            thumb: None,
+        },
+    );
+    let caption = FormattedText::new()
+        .bold("long caption")
+        .text(&" word".repeat(300));
+
+    let messages = send_with_caption(&rutebot, request, &caption)
+        .await
+        .unwrap();
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].photo.is_some(), true);
+    assert_eq!(
+        messages[1].reply_to_message.as_ref().map(|x| x.message_id),
+        Some(messages[0].message_id)
+    );
+}
+
 #[tokio::test]
 async fn send_audio_works() {
     let rutebot = common::create_client();
@@ -201,11 +235,11 @@ async fn send_audio_works() {
         .unwrap();
     let audio_size = audio_content.len();
     let request = SendAudio {
-        performer: Some("Dark_Tranquility"),
+        performer: Some("Dark_Tranquility".into()),
         ..SendAudio::new(
             chat_id,
             FileKind::InputFile {
-                name: "superaudio",
+                name: "superaudio".into(),
                 content: audio_content,
                 thumb: None,
             },
@@ -245,7 +279,7 @@ async fn send_video_works() {
     let request = SendVideo::new(
         chat_id,
         FileKind::InputFile {
-            name: "supervideo",
+            name: "supervideo".into(),
             content: video_content,
             thumb: None,
         },
@@ -286,7 +320,7 @@ async fn send_animation_works() {
         ..SendAnimation::new(
             chat_id,
             FileKind::InputFile {
-                name: "supergif",
+                name: "supergif".into(),
                 content: gif_content,
                 thumb: None,
             },
@@ -311,7 +345,7 @@ async fn send_voice_works() {
     let request = SendVoice::new(
         chat_id,
         FileKind::InputFile {
-            name: "supervoice",
+            name: "supervoice".into(),
             content: voice_content,
             thumb: None,
         },
@@ -346,7 +380,7 @@ async fn send_video_note_works() {
     let request = SendVideoNote::new(
         chat_id,
         FileKind::InputFile {
-            name: "supervideonote",
+            name: "supervideonote".into(),
             content: video_note_content,
             thumb: None,
         },
@@ -386,12 +420,12 @@ async fn send_media_group_works() {
         chat_id,
         vec![
             InputMediaPhotoOrVideo::Video(InputMediaVideo::new(FileKind::InputFile {
-                name: "video",
+                name: "video".into(),
                 content: video_note_content,
                 thumb: None,
             })),
             InputMediaPhotoOrVideo::Photo(InputMediaPhoto::new(FileKind::InputFile {
-                name: "photo",
+                name: "photo".into(),
                 content: photo_content,
                 thumb: None,
             })),
@@ -495,7 +529,7 @@ async fn send_poll_works() {
     let request = SendPoll::new(
         chat_id,
         "to be or not to be",
-        &["to be", "not to be", "see results"],
+        ["to be", "not to be", "see results"],
     );
 
     let poll: Poll = (rutebot.prepare_api_request(request).send().await.unwrap())
@@ -684,11 +718,11 @@ async fn edit_message_caption_works() {
     let request = SendAnimation {
         width: Some(808),
         height: Some(538),
-        caption: Some("old caption"),
+        caption: Some("old caption".into()),
         ..SendAnimation::new(
             chat_id,
             FileKind::InputFile {
-                name: "supergif",
+                name: "supergif".into(),
                 content: gif_content,
                 thumb: None,
             },
@@ -733,7 +767,7 @@ async fn edit_message_media_works() {
     let request = SendVideo::new(
         chat_id,
         FileKind::InputFile {
-            name: "supervideo",
+            name: "supervideo".into(),
             content: old_video,
             thumb: None,
         },
@@ -743,7 +777,7 @@ async fn edit_message_media_works() {
         chat_id,
         response.message_id,
         InputMedia::Video(InputMediaVideo::new(FileKind::InputFile {
-            name: "supervideo",
+            name: "supervideo".into(),
             content: new_video,
             thumb: Some(photo_content),
         })),
@@ -769,7 +803,7 @@ async fn stop_poll_works() {
     let request = SendPoll::new(
         chat_id,
         "to be or not to be",
-        &["to be", "do not to be", "see results"],
+        ["to be", "do not to be", "see results"],
     );
     let msg_with_poll: Message = rutebot.prepare_api_request(request).send().await.unwrap();
     let stop_poll_request = StopPoll::new(chat_id, msg_with_poll.message_id);
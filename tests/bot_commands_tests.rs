@@ -0,0 +1,75 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{
+    BotCommand, BotCommandScope, DeleteMyCommands, GetMyCommands, Request, SetMyCommands,
+};
+
+#[test]
+fn commands_are_set_for_scope_and_language() {
+    let commands = [
+        BotCommand::new("ban", "Забанить пользователя"),
+        BotCommand::new("stats", "Статистика чата"),
+    ];
+    let request = SetMyCommands::new_scoped(
+        &commands[..],
+        BotCommandScope::ChatAdministrators {
+            chat_id: "@cats".into(),
+        },
+        Some("ru".into()),
+    );
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "commands": [
+                {"command": "ban", "description": "Забанить пользователя"},
+                {"command": "stats", "description": "Статистика чата"}
+            ],
+            "scope": {"type": "chat_administrators", "chat_id": "@cats"},
+            "language_code": "ru"
+        })
+    );
+}
+
+#[test]
+fn scopes_are_serialized_with_type() {
+    let scopes = [
+        BotCommandScope::Default,
+        BotCommandScope::AllPrivateChats,
+        BotCommandScope::AllGroupChats,
+        BotCommandScope::AllChatAdministrators,
+        BotCommandScope::Chat { chat_id: 1.into() },
+        BotCommandScope::ChatMember {
+            chat_id: 1.into(),
+            user_id: 7,
+        },
+    ];
+
+    assert_eq!(
+        serde_json::to_value(&scopes[..]).unwrap(),
+        json!([
+            {"type": "default"},
+            {"type": "all_private_chats"},
+            {"type": "all_group_chats"},
+            {"type": "all_chat_administrators"},
+            {"type": "chat", "chat_id": 1},
+            {"type": "chat_member", "chat_id": 1, "user_id": 7}
+        ])
+    );
+}
+
+#[test]
+fn commands_are_read_and_deleted() {
+    let get = GetMyCommands::new_scoped(BotCommandScope::AllPrivateChats, None);
+    let delete = DeleteMyCommands::new();
+    let commands: <GetMyCommands as Request>::ResponseType =
+        serde_json::from_value(json!([{"command": "start", "description": "Start"}])).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&get).unwrap(),
+        json!({"scope": {"type": "all_private_chats"}})
+    );
+    assert_eq!(serde_json::to_value(&delete).unwrap(), json!({}));
+    assert_eq!(commands, vec![BotCommand::new("start", "Start")]);
+}
@@ -0,0 +1,148 @@
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+
+use rutebot::callback_data::{
+    CallbackData, CallbackDataCodec, InMemoryCallbackDataStore, MAX_CALLBACK_DATA_LEN,
+};
+
+#[derive(Serialize, Deserialize, CallbackData, Debug, PartialEq)]
+enum Action {
+    Like,
+    Rate(u8),
+    Move { from: i32, to: i32 },
+}
+
+#[derive(Serialize, Deserialize, CallbackData, Debug, PartialEq)]
+struct ButtonData {
+    id: i64,
+    action: Action,
+    note: Option<String>,
+}
+
+#[test]
+fn struct_is_encoded_without_field_names() {
+    let data = ButtonData {
+        id: 7,
+        action: Action::Move { from: 1, to: 2 },
+        note: None,
+    };
+
+    let encoded = data.encode().unwrap();
+
+    assert_eq!(encoded, r#"[7,{"Move":[1,2]},null]"#);
+    assert_eq!(ButtonData::decode(&encoded).unwrap(), data);
+}
+
+#[test]
+fn enum_variants_round_trip() {
+    for action in [Action::Like, Action::Rate(5)] {
+        let encoded = action.encode().unwrap();
+
+        assert_eq!(Action::decode(&encoded).unwrap(), action);
+    }
+}
+
+#[test]
+fn too_large_data_is_rejected() {
+    let data = ButtonData {
+        id: 1,
+        action: Action::Like,
+        note: Some("x".repeat(MAX_CALLBACK_DATA_LEN)),
+    };
+
+    assert!(data.encode().is_err());
+}
+
+#[test]
+fn garbage_data_is_rejected() {
+    assert!(ButtonData::decode("[1,").is_err());
+    assert!(ButtonData::decode(r#"["str",1]"#).is_err());
+}
+
+#[tokio::test]
+async fn codec_stores_large_data() {
+    let codec = CallbackDataCodec::new(InMemoryCallbackDataStore::new(10));
+    let data = ButtonData {
+        id: 1,
+        action: Action::Like,
+        note: Some("x".repeat(MAX_CALLBACK_DATA_LEN * 2)),
+    };
+
+    let encoded = codec.encode(&data).await.unwrap();
+    let decoded: ButtonData = codec.decode(&encoded).await.unwrap();
+
+    assert!(encoded.len() <= MAX_CALLBACK_DATA_LEN);
+    assert_eq!(decoded, data);
+}
+
+#[tokio::test]
+async fn codec_keeps_small_data_inline() {
+    let codec = CallbackDataCodec::new(InMemoryCallbackDataStore::new(10));
+
+    let encoded = codec.encode(&Action::Rate(3)).await.unwrap();
+
+    assert_eq!(encoded, r#"{"Rate":3}"#);
+}
+
+#[tokio::test]
+async fn codec_evicts_oldest_data() {
+    let codec = CallbackDataCodec::new(InMemoryCallbackDataStore::new(1));
+    let large = "x".repeat(MAX_CALLBACK_DATA_LEN * 2);
+
+    let first = codec.encode(&large).await.unwrap();
+    let second = codec.encode(&large).await.unwrap();
+
+    assert!(codec.decode::<String>(&first).await.is_err());
+    assert_eq!(codec.decode::<String>(&second).await.unwrap(), large);
+}
+
+// Deriving `CallbackData` for it would be a compile error, but the codec accepts any serde type
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Skipping {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<u8>,
+}
+
+// The check is done only in debug builds
+#[cfg(debug_assertions)]
+#[tokio::test]
+async fn skipped_fields_must_be_options() {
+    let codec = CallbackDataCodec::new(InMemoryCallbackDataStore::new(10));
+    let optional = Skipping {
+        note: None,
+        tags: vec![1],
+    };
+    let required = Skipping {
+        note: Some("x".into()),
+        tags: vec![],
+    };
+
+    let encoded = codec.encode(&optional).await.unwrap();
+
+    assert_eq!(codec.decode::<Skipping>(&encoded).await.unwrap(), optional);
+    assert!(matches!(
+        codec.encode(&required).await,
+        Err(rutebot::error::Error::CallbackData(x)) if x.contains("skip_serializing_if")
+    ));
+}
+
+#[derive(Serialize, Deserialize, CallbackData, Debug, PartialEq)]
+struct Page<T> {
+    items: Vec<T>,
+    number: u32,
+}
+
+#[test]
+fn generic_types_can_be_derived() {
+    let page = Page {
+        items: vec!['a', 'b'],
+        number: 2,
+    };
+
+    let encoded = page.encode().unwrap();
+
+    assert_eq!(encoded, r#"[["a","b"],2]"#);
+    assert_eq!(Page::<char>::decode(&encoded).unwrap(), page);
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+
+use rutebot::{
+    dialogue::{Dialogue, DialogueKey, InMemoryStorage, JsonFileStorage, Transition},
+    error::Error,
+    responses::Update,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+enum State {
+    #[default]
+    Start,
+    AskedName,
+    Named(String),
+}
+
+fn text_update(chat_id: i64, user_id: i64, text: &str) -> Update {
+    serde_json::from_value(serde_json::json!({
+        "update_id": 1,
+        "message": {
+            "message_id": 1,
+            "date": 0,
+            "chat": { "id": chat_id, "type": "group" },
+            "from": { "id": user_id, "is_bot": false, "first_name": "user" },
+            "text": text
+        }
+    }))
+    .unwrap()
+}
+
+fn edited_update(chat_id: i64, user_id: i64, text: &str) -> Update {
+    let mut update = text_update(chat_id, user_id, text);
+    update.edited_message = update.message.take();
+    update
+}
+
+async fn registration(state: State, update: Update) -> Result<Transition<State>, Error> {
+    let text = update.message.and_then(|x| x.text).unwrap_or_default();
+    Ok(match state {
+        State::Start => Transition::Next(State::AskedName),
+        State::AskedName => Transition::Next(State::Named(text)),
+        State::Named(_) => Transition::Exit,
+    })
+}
+
+#[test]
+fn key_is_extracted_from_message() {
+    let key = DialogueKey::from_update(&text_update(-10, 5, "hi"));
+
+    assert_eq!(key, Some(DialogueKey::new(-10, Some(5))));
+}
+
+#[tokio::test]
+async fn states_are_tracked_per_user() {
+    let dialogue = Dialogue::new(InMemoryStorage::new());
+
+    dialogue
+        .handle(text_update(1, 1, "hi"), registration)
+        .await
+        .unwrap();
+    dialogue
+        .handle(text_update(1, 1, "Ilya"), registration)
+        .await
+        .unwrap();
+    dialogue
+        .handle(text_update(1, 2, "hi"), registration)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        dialogue.state(DialogueKey::new(1, Some(1))).await.unwrap(),
+        State::Named("Ilya".to_string())
+    );
+    assert_eq!(
+        dialogue.state(DialogueKey::new(1, Some(2))).await.unwrap(),
+        State::AskedName
+    );
+}
+
+#[tokio::test]
+async fn exit_resets_state() {
+    let dialogue = Dialogue::new(InMemoryStorage::new());
+    let key = DialogueKey::new(1, Some(1));
+    dialogue
+        .update(key, State::Named("x".to_string()))
+        .await
+        .unwrap();
+
+    dialogue
+        .handle(text_update(1, 1, "bye"), registration)
+        .await
+        .unwrap();
+
+    assert_eq!(dialogue.state(key).await.unwrap(), State::Start);
+}
+
+#[tokio::test]
+async fn stale_state_is_reset() {
+    let dialogue = Dialogue::new(InMemoryStorage::new()).with_timeout(Duration::from_millis(10));
+    let key = DialogueKey::new(1, None);
+    dialogue.update(key, State::AskedName).await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(dialogue.state(key).await.unwrap(), State::Start);
+}
+
+#[tokio::test]
+async fn json_file_storage_survives_reopen() {
+    let path = std::env::temp_dir().join(format!("rutebot_dialogue_{}.json", std::process::id()));
+    let key = DialogueKey::new(42, Some(7));
+    {
+        let dialogue = Dialogue::new(JsonFileStorage::open(&path).await.unwrap());
+        dialogue.update(key, State::AskedName).await.unwrap();
+    }
+
+    let dialogue: Dialogue<State, _> = Dialogue::new(JsonFileStorage::open(&path).await.unwrap());
+    let state = dialogue.state(key).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(state, State::AskedName);
+}
+
+#[tokio::test]
+async fn concurrent_updates_of_same_conversation_are_serialized() {
+    let dialogue = Dialogue::new(InMemoryStorage::new());
+    let slow_registration = |state, update| async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        registration(state, update).await
+    };
+
+    let (first, second) = tokio::join!(
+        dialogue.handle(text_update(1, 1, "hi"), slow_registration),
+        dialogue.handle(text_update(1, 1, "Ilya"), slow_registration)
+    );
+    first.unwrap();
+    second.unwrap();
+
+    assert_eq!(
+        dialogue.state(DialogueKey::new(1, Some(1))).await.unwrap(),
+        State::Named("Ilya".to_string())
+    );
+}
+
+#[tokio::test]
+async fn edited_messages_are_ignored_by_default() {
+    let dialogue = Dialogue::new(InMemoryStorage::new());
+    let with_edits = Dialogue::new(InMemoryStorage::new()).with_edited_messages();
+    let key = DialogueKey::new(1, Some(1));
+
+    dialogue
+        .handle(edited_update(1, 1, "hi"), registration)
+        .await
+        .unwrap();
+    with_edits
+        .handle(edited_update(1, 1, "hi"), registration)
+        .await
+        .unwrap();
+
+    assert_eq!(dialogue.state(key).await.unwrap(), State::Start);
+    assert_eq!(with_edits.state(key).await.unwrap(), State::AskedName);
+}
@@ -0,0 +1,250 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use pretty_assertions::assert_eq;
+
+use futures_util::future::{ready, BoxFuture, FutureExt};
+use rutebot::{
+    client::Rutebot,
+    dispatcher::{
+        AllowList, BanList, Context, Dispatcher, HandlerError, HandlerResult, Middleware,
+    },
+    error::Error,
+    responses::Update,
+};
+
+fn update_from(update_id: i64, user_id: i64) -> Update {
+    serde_json::from_value(serde_json::json!({
+        "update_id": update_id,
+        "message": {
+            "message_id": 1,
+            "date": 0,
+            "chat": { "id": 1, "type": "private" },
+            "from": { "id": user_id, "is_bot": false, "first_name": "user" },
+            "text": "hi"
+        }
+    }))
+    .unwrap()
+}
+
+fn recording_dispatcher(handled: Arc<Mutex<Vec<i64>>>) -> Dispatcher {
+    Dispatcher::new(Rutebot::new("token"), move |ctx: Context| {
+        handled.lock().unwrap().push(ctx.update.update_id);
+        async { Ok(()) }
+    })
+}
+
+struct Role(&'static str);
+
+struct RoleLoader;
+
+impl Middleware for RoleLoader {
+    fn before<'a>(&'a self, ctx: &'a mut Context) -> BoxFuture<'a, Result<bool, HandlerError>> {
+        ctx.insert(Role("admin"));
+        ready(Ok(true)).boxed()
+    }
+}
+
+struct Recorder {
+    name: &'static str,
+    pass: Result<bool, &'static str>,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Recorder {
+    fn before<'a>(&'a self, _ctx: &'a mut Context) -> BoxFuture<'a, Result<bool, HandlerError>> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("before {}", self.name));
+        ready(self.pass.map_err(Into::into)).boxed()
+    }
+
+    fn after<'a>(&'a self, _ctx: &'a Context, result: &'a HandlerResult) -> BoxFuture<'a, ()> {
+        let result = match result {
+            Ok(()) => "ok".to_string(),
+            Err(err) => err.to_string(),
+        };
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("after {} {}", self.name, result));
+        ready(()).boxed()
+    }
+}
+
+fn recorders(
+    calls: &Arc<Mutex<Vec<String>>>,
+    second: Result<bool, &'static str>,
+) -> (Recorder, Recorder, Recorder) {
+    let recorder = |name, pass| Recorder {
+        name,
+        pass,
+        calls: calls.clone(),
+    };
+    (
+        recorder("a", Ok(true)),
+        recorder("b", second),
+        recorder("c", Ok(true)),
+    )
+}
+
+#[tokio::test]
+async fn ban_list_skips_banned_users() {
+    let handled = Arc::new(Mutex::new(Vec::new()));
+    let bans = BanList::new();
+    let dispatcher = recording_dispatcher(handled.clone()).middleware(bans.clone());
+
+    bans.ban(2);
+    dispatcher.handle(update_from(1, 1)).await;
+    dispatcher.handle(update_from(2, 2)).await;
+    bans.unban(2);
+    dispatcher.handle(update_from(3, 2)).await;
+
+    assert_eq!(*handled.lock().unwrap(), vec![1, 3]);
+}
+
+#[tokio::test]
+async fn allow_list_passes_only_listed_users() {
+    let handled = Arc::new(Mutex::new(Vec::new()));
+    let dispatcher = recording_dispatcher(handled.clone()).middleware(AllowList::new(vec![10]));
+
+    dispatcher.handle(update_from(1, 10)).await;
+    dispatcher.handle(update_from(2, 11)).await;
+
+    assert_eq!(*handled.lock().unwrap(), vec![1]);
+}
+
+#[tokio::test]
+async fn shared_and_injected_data_are_available() {
+    let seen = Arc::new(Mutex::new(None));
+    let seen_by_handler = seen.clone();
+    let dispatcher = Dispatcher::new(Rutebot::new("token"), move |ctx: Context| {
+        let limit = *ctx.data::<u32>().unwrap();
+        let role = ctx.data::<Role>().unwrap().0;
+        *seen_by_handler.lock().unwrap() = Some((limit, role));
+        async { Ok(()) }
+    })
+    .data(5u32)
+    .middleware(RoleLoader);
+
+    dispatcher.handle(update_from(1, 1)).await;
+
+    assert_eq!(*seen.lock().unwrap(), Some((5, "admin")));
+}
+
+#[tokio::test]
+async fn errors_are_passed_to_error_handler_with_update() {
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let recorded = errors.clone();
+    let dispatcher = Dispatcher::new(Rutebot::new("token"), |_ctx: Context| async {
+        Err("handler failed".into())
+    })
+    .error_handler(move |err, update| {
+        recorded
+            .lock()
+            .unwrap()
+            .push((err.to_string(), update.update_id));
+        async {}
+    });
+
+    dispatcher.handle(update_from(7, 1)).await;
+
+    assert_eq!(
+        *errors.lock().unwrap(),
+        vec![("handler failed".to_string(), 7)]
+    );
+}
+
+#[tokio::test]
+async fn after_is_called_for_middlewares_before_skipping_one() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handled = Arc::new(Mutex::new(Vec::new()));
+    let (a, b, c) = recorders(&calls, Ok(false));
+    let dispatcher = recording_dispatcher(handled.clone())
+        .middleware(a)
+        .middleware(b)
+        .middleware(c);
+
+    dispatcher.handle(update_from(1, 1)).await;
+
+    assert!(handled.lock().unwrap().is_empty());
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec!["before a", "before b", "after b ok", "after a ok"]
+    );
+}
+
+#[tokio::test]
+async fn after_is_called_for_middlewares_before_failed_one() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let recorded = errors.clone();
+    let (a, b, c) = recorders(&calls, Err("b failed"));
+    let dispatcher = recording_dispatcher(Arc::new(Mutex::new(Vec::new())))
+        .middleware(a)
+        .middleware(b)
+        .middleware(c)
+        .error_handler(move |err, _| {
+            recorded.lock().unwrap().push(err.to_string());
+            async {}
+        });
+
+    dispatcher.handle(update_from(1, 1)).await;
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec![
+            "before a",
+            "before b",
+            "after b b failed",
+            "after a b failed"
+        ]
+    );
+    assert_eq!(*errors.lock().unwrap(), vec!["b failed"]);
+}
+
+#[tokio::test]
+async fn stream_errors_do_not_stop_dispatcher() {
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let recorded = errors.clone();
+    let dispatcher = Dispatcher::new(Rutebot::new("token"), move |ctx: Context| {
+        sender.send(ctx.update.update_id).unwrap();
+        async { Ok(()) }
+    })
+    .stream_error_handler(move |err| {
+        recorded.lock().unwrap().push(err.to_string());
+        async {}
+    });
+    let updates = futures_util::stream::iter(vec![
+        Ok(update_from(1, 1)),
+        Err(Error::Timeout),
+        Ok(update_from(2, 1)),
+    ]);
+
+    dispatcher.run(updates).await;
+
+    let mut handled = vec![receiver.recv().await, receiver.recv().await];
+    handled.sort();
+    assert_eq!(handled, vec![Some(1), Some(2)]);
+    assert_eq!(errors.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn consecutive_stream_errors_back_off() {
+    let dispatcher =
+        recording_dispatcher(Arc::new(Mutex::new(Vec::new()))).stream_error_handler(|_| async {});
+    let updates = futures_util::stream::iter(vec![
+        Err(Error::Timeout),
+        Err(Error::Timeout),
+        Err(Error::Timeout),
+    ]);
+    let started = Instant::now();
+
+    dispatcher.run(updates).await;
+
+    assert!(started.elapsed() >= Duration::from_millis(700));
+}
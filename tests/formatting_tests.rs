@@ -0,0 +1,276 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::formatting::{
+    escape_html, escape_markdown_v2, render_html, render_markdown_v2, FormattedText, MarkupBuilder,
+    Style,
+};
+use rutebot::requests::{ParseMode, SendMessage, SendPhoto};
+use rutebot::responses::{MessageEntity, MessageEntityKind};
+
+#[test]
+fn markdown_v2_special_characters_are_escaped() {
+    let escaped = escape_markdown_v2(r"_*[]()~`>#+-=|{}.!\ plain");
+
+    assert_eq!(escaped, r"\_\*\[\]\(\)\~\`\>\#\+\-\=\|\{\}\.\!\\ plain");
+}
+
+#[test]
+fn html_special_characters_are_escaped() {
+    assert_eq!(
+        escape_html(r#"<b>"Tom" & Jerry</b>"#),
+        "&lt;b&gt;&quot;Tom&quot; &amp; Jerry&lt;/b&gt;"
+    );
+}
+
+#[test]
+fn markdown_v2_builder_escapes_text_and_urls() {
+    let markup = MarkupBuilder::markdown_v2()
+        .bold("a*b")
+        .text(" ")
+        .link("docs (v2)", "https://example.com/a_(b)")
+        .text(" ")
+        .code("x `y` \\ z.")
+        .text(" ")
+        .mention("user", 42)
+        .build();
+
+    assert_eq!(markup.parse_mode, ParseMode::MarkdownV2);
+    assert_eq!(
+        markup.text,
+        r"*a\*b* [docs \(v2\)](https://example.com/a_(b\)) `x \`y\` \\ z.` [user](tg://user?id=42)"
+    );
+}
+
+#[test]
+fn html_builder_builds_nested_styles() {
+    let markup = MarkupBuilder::html()
+        .styled(Style::Bold, |b| b.text("1 < 2 ").italic("&"))
+        .pre("fn main() {}", Some("rust"))
+        .custom_emoji("👍", "5368324170671202286")
+        .expandable_blockquote("quote")
+        .build();
+
+    assert_eq!(markup.parse_mode, ParseMode::Html);
+    assert_eq!(
+        markup.text,
+        concat!(
+            "<b>1 &lt; 2 <i>&amp;</i></b>",
+            "<pre><code class=\"language-rust\">fn main() {}</code></pre>",
+            "<tg-emoji emoji-id=\"5368324170671202286\">👍</tg-emoji>",
+            "<blockquote expandable>quote</blockquote>"
+        )
+    );
+}
+
+#[test]
+fn markdown_v2_italic_inside_underline_is_separated() {
+    let markup = MarkupBuilder::markdown_v2()
+        .styled(Style::Underline, |b| b.text("a ").italic("b"))
+        .build();
+
+    assert_eq!(markup.text, "__a _b_\r__");
+}
+
+#[test]
+fn markdown_v2_blockquote_lines_are_prefixed() {
+    let markup = MarkupBuilder::markdown_v2()
+        .blockquote("first\nsecond")
+        .text("\n")
+        .expandable_blockquote("one\ntwo")
+        .pre("a\nb", None)
+        .build();
+
+    assert_eq!(markup.text, ">first\n>second\n**>one\n>two||\n```\na\nb```");
+}
+
+#[test]
+fn markdown_v2_blockquote_starts_and_ends_line() {
+    let markup = MarkupBuilder::markdown_v2()
+        .text("a")
+        .blockquote("b")
+        .text("c")
+        .build();
+
+    assert_eq!(markup.text, "a\n>b\nc");
+}
+
+#[test]
+fn markdown_v2_expandable_blockquote_starts_and_ends_line() {
+    let markup = MarkupBuilder::markdown_v2()
+        .text("a")
+        .expandable_blockquote("q")
+        .bold("after")
+        .build();
+
+    assert_eq!(markup.text, "a\n**>q||\n*after*");
+}
+
+#[test]
+fn markdown_v2_adjacent_blockquotes_are_separated() {
+    let markup = MarkupBuilder::markdown_v2()
+        .text("a\n")
+        .blockquote("b")
+        .expandable_blockquote("c")
+        .text("\nd")
+        .build();
+
+    assert_eq!(markup.text, "a\n>b\n**>c||\nd");
+}
+
+#[test]
+fn requests_take_markup() {
+    let markup = MarkupBuilder::markdown_v2().spoiler("secret").build();
+
+    let message = SendMessage::new_markup(1, &markup);
+    let photo = SendPhoto::new(1, rutebot::requests::FileKind::FileId("id".into()))
+        .with_caption_markup(&markup);
+
+    assert_eq!(message.text, "||secret||");
+    assert_eq!(message.parse_mode, Some(ParseMode::MarkdownV2));
+    assert_eq!(photo.caption.as_deref(), Some("||secret||"));
+    assert_eq!(photo.parse_mode, Some(ParseMode::MarkdownV2));
+}
+
+#[test]
+fn formatted_text_offsets_are_in_utf16_code_units() {
+    let formatted = FormattedText::new()
+        .text("😀 ")
+        .styled(Style::Bold, |t| t.text("a ").pre("b", Some("rust")))
+        .mention("𝕏", 42);
+
+    assert_eq!(formatted.text, "😀 a b𝕏");
+    assert_eq!(formatted.utf16_len(), 8);
+    assert_eq!(
+        serde_json::to_value(&formatted.entities).unwrap(),
+        json!([
+            {"type": "bold", "offset": 3, "length": 3},
+            {"type": "pre", "offset": 5, "length": 1, "language": "rust"},
+            {"type": "text_link", "offset": 6, "length": 2, "url": "tg://user?id=42"}
+        ])
+    );
+}
+
+#[test]
+fn requests_take_formatted_text() {
+    let formatted = FormattedText::new()
+        .link("site", "https://example.com")
+        .text(" *not bold*");
+
+    let message = SendMessage::new_formatted(1, &formatted);
+    let photo = SendPhoto::new(1, rutebot::requests::FileKind::FileId("id".into()))
+        .with_formatted_caption(&formatted);
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({
+            "chat_id": 1,
+            "text": "site *not bold*",
+            "entities": [{"type": "text_link", "offset": 0, "length": 4, "url": "https://example.com"}]
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&photo).unwrap()["caption_entities"][0]["type"],
+        "text_link"
+    );
+}
+
+fn greeting(name: &str) -> (SendMessage<'static>, SendPhoto<'static>) {
+    let markup = MarkupBuilder::html().text("Hello, ").bold(name).build();
+    let formatted = FormattedText::new().text("Hi, ").italic(name);
+    let message = SendMessage::new_markup(1, markup);
+    let photo = SendPhoto::new(1, rutebot::requests::FileKind::FileId("id".into()))
+        .with_formatted_caption(formatted);
+    (message, photo)
+}
+
+#[test]
+fn requests_can_own_markup_and_formatted_text() {
+    let (message, photo) = greeting("<Bob>");
+
+    assert_eq!(message.text, "Hello, <b>&lt;Bob&gt;</b>");
+    assert_eq!(message.parse_mode, Some(ParseMode::Html));
+    assert_eq!(photo.caption.as_deref(), Some("Hi, <Bob>"));
+    assert_eq!(photo.caption_entities.map(|x| x.len()), Some(1));
+}
+
+fn entity(kind: MessageEntityKind, offset: i64, length: i64) -> MessageEntity {
+    MessageEntity {
+        kind,
+        offset,
+        length,
+    }
+}
+
+#[test]
+fn rendered_entities_match_builder_output() {
+    let formatted = FormattedText::new()
+        .text("🎉 Hi, ")
+        .styled(Style::Bold, |t| t.text("dear ").italic("friend"))
+        .text("! ")
+        .link("a.b", "https://example.com/(x)")
+        .code(" `1` ");
+    let markdown = MarkupBuilder::markdown_v2()
+        .text("🎉 Hi, ")
+        .styled(Style::Bold, |b| b.text("dear ").italic("friend"))
+        .text("! ")
+        .link("a.b", "https://example.com/(x)")
+        .code(" `1` ")
+        .build();
+
+    let rendered = render_markdown_v2(&formatted.text, &formatted.entities);
+
+    assert_eq!(rendered, markdown);
+}
+
+#[test]
+fn overlapping_entities_are_split() {
+    let entities = [
+        entity(MessageEntityKind::Bold, 0, 5),
+        entity(MessageEntityKind::Italic, 3, 5),
+    ];
+
+    let rendered = render_html("abcdefgh", &entities);
+
+    assert_eq!(rendered.text, "<b>abc<i>de</i></b><i>fgh</i>");
+}
+
+#[test]
+fn entities_detected_by_telegram_are_rendered_as_text() {
+    let user =
+        serde_json::from_value(json!({"id": 7, "is_bot": false, "first_name": "Bob"})).unwrap();
+    let entities = [
+        entity(MessageEntityKind::TextMention { user }, 0, 3),
+        entity(MessageEntityKind::Hashtag, 5, 4),
+        entity(MessageEntityKind::Underline, 10, 1),
+        entity(MessageEntityKind::Italic, 10, 1),
+    ];
+
+    let rendered = render_markdown_v2("Bob! #tag x.", &entities);
+
+    assert_eq!(
+        rendered.text,
+        "[Bob](tg://user?id=7)\\! \\#tag __\r_x_\r__\\."
+    );
+}
+
+#[test]
+fn rendered_blockquote_lines_are_prefixed() {
+    let entities = [
+        entity(MessageEntityKind::Blockquote, 0, 7),
+        entity(MessageEntityKind::Pre { language: None }, 8, 3),
+    ];
+
+    let rendered = render_markdown_v2("one\ntwo\na\\b", &entities);
+
+    assert_eq!(rendered.text, ">one\n>two\n```\na\\\\b```");
+}
+
+#[test]
+fn rendered_blockquote_inside_line_is_put_on_separate_line() {
+    let entities = [entity(MessageEntityKind::ExpandableBlockquote, 2, 5)];
+
+    let rendered = render_markdown_v2("a quote b", &entities);
+
+    assert_eq!(rendered.text, "a \n**>quote||\n b");
+}
@@ -0,0 +1,101 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{
+    AnswerCallbackQuery, GetGameHighScores, InlineKeyboard, InlineKeyboardButton, SendGame,
+    SetGameScore,
+};
+use rutebot::responses::{CallbackGame, GameHighScore, Message, Update};
+
+#[test]
+fn game_is_sent_with_play_button() {
+    let game = SendGame {
+        reply_markup: Some(
+            InlineKeyboard::new().button(InlineKeyboardButton::CallbackGame {
+                text: "Play".into(),
+                callback_game: CallbackGame {},
+            }),
+        ),
+        ..SendGame::new_reply(1, "snake", 5)
+    };
+
+    assert_eq!(
+        serde_json::to_value(&game).unwrap(),
+        json!({
+            "chat_id": 1,
+            "game_short_name": "snake",
+            "reply_to_message_id": 5,
+            "reply_markup": {"inline_keyboard": [[{"text": "Play", "callback_game": {}}]]}
+        })
+    );
+}
+
+#[test]
+fn game_message_is_parsed() {
+    let message: Message = serde_json::from_value(json!({
+        "message_id": 1,
+        "date": 0,
+        "chat": {"id": 1, "type": "private"},
+        "game": {
+            "title": "Snake",
+            "description": "Eat apples",
+            "photo": [{"file_id": "p", "width": 90, "height": 90}],
+            "text": "Bob scored 10",
+            "text_entities": [{"type": "bold", "offset": 0, "length": 3}]
+        }
+    }))
+    .unwrap();
+
+    let game = message.game.unwrap();
+    assert_eq!(game.title, "Snake");
+    assert_eq!(game.text_entities.unwrap().len(), 1);
+}
+
+#[test]
+fn game_callback_is_answered_with_url() {
+    let update: Update = serde_json::from_value(json!({
+        "update_id": 1,
+        "callback_query": {
+            "id": "42",
+            "from": {"id": 7, "is_bot": false, "first_name": "Bob"},
+            "inline_message_id": "im1",
+            "chat_instance": "instance",
+            "game_short_name": "snake"
+        }
+    }))
+    .unwrap();
+    let query = update.callback_query.unwrap();
+
+    let answer = AnswerCallbackQuery::new_game_url(
+        query.id.as_str(),
+        format!("https://example.com/snake?user={}", query.from.id),
+    );
+
+    assert_eq!(
+        serde_json::to_value(&answer).unwrap(),
+        json!({"callback_query_id": "42", "url": "https://example.com/snake?user=7"})
+    );
+}
+
+#[test]
+fn scores_are_set_and_read_for_chat_and_inline_messages() {
+    let inline = SetGameScore {
+        force: true,
+        ..SetGameScore::new_inline_message("im1", 7, 10)
+    };
+    let chat = GetGameHighScores::new_message(1, 2, 7);
+    let scores: Vec<GameHighScore> = serde_json::from_value(json!([
+        {"position": 1, "user": {"id": 7, "is_bot": false, "first_name": "Bob"}, "score": 10}
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&inline).unwrap(),
+        json!({"user_id": 7, "score": 10, "force": true, "inline_message_id": "im1"})
+    );
+    assert_eq!(
+        serde_json::to_value(&chat).unwrap(),
+        json!({"user_id": 7, "chat_id": 1, "message_id": 2})
+    );
+    assert_eq!(scores[0].user.id, 7);
+}
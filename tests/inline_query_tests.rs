@@ -0,0 +1,154 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{
+    AnswerInlineQuery, InlineKeyboard, InlineKeyboardButton, InlineQueryResult,
+    InlineQueryResultArticle, InlineQueryResultCachedPhoto, InlineQueryResultLocation,
+    InlineQueryResultsButton, InputInvoiceMessageContent, InputMessageContent,
+    InputTextMessageContent, LabeledPrice, ParseMode,
+};
+use rutebot::responses::Update;
+
+#[test]
+fn inline_query_and_chosen_result_are_parsed_from_updates() {
+    let updates: Vec<Update> = serde_json::from_value(json!([
+        {
+            "update_id": 1,
+            "inline_query": {
+                "id": "q1",
+                "from": {"id": 7, "is_bot": false, "first_name": "Bob"},
+                "query": "cats",
+                "offset": "20",
+                "chat_type": "sender"
+            }
+        },
+        {
+            "update_id": 2,
+            "chosen_inline_result": {
+                "result_id": "r1",
+                "from": {"id": 7, "is_bot": false, "first_name": "Bob"},
+                "inline_message_id": "im1",
+                "query": "cats"
+            }
+        }
+    ]))
+    .unwrap();
+
+    let query = updates[0].inline_query.as_ref().unwrap();
+    let chosen = updates[1].chosen_inline_result.as_ref().unwrap();
+    assert_eq!(query.offset, "20");
+    assert_eq!(query.chat_type.as_deref(), Some("sender"));
+    assert_eq!(chosen.inline_message_id.as_deref(), Some("im1"));
+}
+
+#[test]
+fn answer_is_serialized_with_typed_results() {
+    let content = InputTextMessageContent {
+        parse_mode: Some(ParseMode::Html),
+        ..InputTextMessageContent::new("<b>cat</b>")
+    };
+    let results = vec![
+        InlineQueryResultArticle {
+            reply_markup: Some(InlineKeyboard::new().button(InlineKeyboardButton::Url {
+                text: "more".into(),
+                url: "https://example.com".into(),
+            })),
+            ..InlineQueryResultArticle::new("1", "Cat", content.into())
+        }
+        .into(),
+        InlineQueryResultCachedPhoto {
+            caption: Some("photo".into()),
+            ..InlineQueryResultCachedPhoto::new("2", "AgAD")
+        }
+        .into(),
+    ];
+    let answer = AnswerInlineQuery {
+        cache_time: Some(0),
+        is_personal: true,
+        button: Some(InlineQueryResultsButton::StartParameter {
+            text: "Settings".into(),
+            start_parameter: "settings".into(),
+        }),
+        ..AnswerInlineQuery::new_page("q1", results, "2")
+    };
+
+    assert_eq!(
+        serde_json::to_value(&answer).unwrap(),
+        json!({
+            "inline_query_id": "q1",
+            "results": [
+                {
+                    "type": "article",
+                    "id": "1",
+                    "title": "Cat",
+                    "input_message_content": {"message_text": "<b>cat</b>", "parse_mode": "Html"},
+                    "reply_markup": {"inline_keyboard": [[{"text": "more", "url": "https://example.com"}]]}
+                },
+                {"type": "photo", "id": "2", "photo_file_id": "AgAD", "caption": "photo"}
+            ],
+            "cache_time": 0,
+            "is_personal": true,
+            "next_offset": "2",
+            "button": {"text": "Settings", "start_parameter": "settings"}
+        })
+    );
+}
+
+#[test]
+fn results_sharing_a_type_are_deserialized_by_their_fields() {
+    let results: Vec<InlineQueryResult> = serde_json::from_value(json!([
+        {"type": "photo", "id": "1", "photo_file_id": "AgAD"},
+        {"type": "photo", "id": "2", "photo_url": "https://a/b.jpg", "thumbnail_url": "https://a/t.jpg"},
+        {"type": "venue", "id": "3", "latitude": 1.0, "longitude": 2.0, "title": "Bar", "address": "Main st."},
+        {
+            "type": "location", "id": "4", "latitude": 1.0, "longitude": 2.0, "title": "Here",
+            "input_message_content": {"message_text": "here"}
+        },
+        {"type": "sticker", "id": "5", "sticker_file_id": "CAAD"}
+    ]))
+    .unwrap();
+
+    assert!(matches!(results[0], InlineQueryResult::CachedPhoto(_)));
+    assert!(matches!(results[1], InlineQueryResult::Photo(_)));
+    assert!(matches!(results[2], InlineQueryResult::Venue(_)));
+    assert!(matches!(
+        &results[3],
+        InlineQueryResult::Location(InlineQueryResultLocation {
+            input_message_content: Some(InputMessageContent::Text(_)),
+            ..
+        })
+    ));
+    assert!(matches!(results[4], InlineQueryResult::CachedSticker(_)));
+    assert_eq!(
+        serde_json::to_value(&results[0]).unwrap()["type"],
+        json!("photo")
+    );
+}
+
+#[test]
+fn invoice_content_is_serialized_with_prices() {
+    let prices = [LabeledPrice::new("Cat", 100), LabeledPrice::new("Tax", 5)];
+    let content: InputMessageContent = InputInvoiceMessageContent {
+        need_email: true,
+        ..InputInvoiceMessageContent::new("Cat", "A cat", "cat-1", "XTR", &prices[..])
+    }
+    .into();
+
+    let json = serde_json::to_value(&content).unwrap();
+
+    assert_eq!(
+        json,
+        json!({
+            "title": "Cat",
+            "description": "A cat",
+            "payload": "cat-1",
+            "currency": "XTR",
+            "prices": [{"label": "Cat", "amount": 100}, {"label": "Tax", "amount": 5}],
+            "need_email": true
+        })
+    );
+    assert!(matches!(
+        serde_json::from_value(json).unwrap(),
+        InputMessageContent::Invoice(_)
+    ));
+}
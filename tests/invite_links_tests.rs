@@ -0,0 +1,111 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{
+    ApproveChatJoinRequest, CreateChatInviteLink, CreateChatSubscriptionInviteLink,
+    DeclineChatJoinRequest, EditChatInviteLink, GetUpdates, RevokeChatInviteLink, UpdateKind,
+};
+use rutebot::responses::{ChatInviteLink, Update};
+
+#[test]
+fn invite_links_are_serialized() {
+    let create = CreateChatInviteLink {
+        name: Some("Members".into()),
+        expire_date: Some(1_700_000_000),
+        ..CreateChatInviteLink::new_join_request("@cats")
+    };
+    let edit = EditChatInviteLink {
+        member_limit: Some(10),
+        ..EditChatInviteLink::new(1, "https://t.me/+abc")
+    };
+    let subscription = CreateChatSubscriptionInviteLink::new(1, 50);
+
+    assert_eq!(
+        serde_json::to_value(&create).unwrap(),
+        json!({
+            "chat_id": "@cats",
+            "name": "Members",
+            "expire_date": 1_700_000_000,
+            "creates_join_request": true
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&edit).unwrap(),
+        json!({"chat_id": 1, "invite_link": "https://t.me/+abc", "member_limit": 10})
+    );
+    assert_eq!(
+        serde_json::to_value(RevokeChatInviteLink::new(1, "https://t.me/+abc")).unwrap(),
+        json!({"chat_id": 1, "invite_link": "https://t.me/+abc"})
+    );
+    assert_eq!(
+        serde_json::to_value(&subscription).unwrap(),
+        json!({"chat_id": 1, "subscription_period": 2_592_000, "subscription_price": 50})
+    );
+}
+
+#[test]
+fn subscription_invite_link_is_parsed() {
+    let link: ChatInviteLink = serde_json::from_value(json!({
+        "invite_link": "https://t.me/+sub",
+        "creator": {"id": 1, "is_bot": true, "first_name": "Bot"},
+        "creates_join_request": false,
+        "is_primary": false,
+        "is_revoked": false,
+        "subscription_period": 2_592_000,
+        "subscription_price": 50
+    }))
+    .unwrap();
+
+    assert_eq!(link.subscription_price, Some(50));
+    assert_eq!(link.member_limit, None);
+}
+
+#[test]
+fn join_requests_are_received_and_answered() {
+    let get_updates = GetUpdates {
+        allowed_updates: Some(vec![UpdateKind::ChatJoinRequest].into()),
+        ..GetUpdates::new()
+    };
+    let update: Update = serde_json::from_value(json!({
+        "update_id": 1,
+        "chat_join_request": {
+            "chat": {"id": -100, "type": "supergroup", "title": "Cats"},
+            "from": {"id": 7, "is_bot": false, "first_name": "Bob"},
+            "user_chat_id": 7,
+            "date": 1_700_000_000,
+            "bio": "I like cats",
+            "invite_link": {
+                "invite_link": "https://t.me/+abc",
+                "creator": {"id": 1, "is_bot": true, "first_name": "Bot"},
+                "creates_join_request": true,
+                "is_primary": false,
+                "is_revoked": false,
+                "pending_join_request_count": 3
+            }
+        }
+    }))
+    .unwrap();
+    let request = update.chat_join_request.unwrap();
+
+    let answer = if request.bio.as_deref() == Some("I like cats") {
+        serde_json::to_value(ApproveChatJoinRequest::new(
+            request.chat.id,
+            request.from.id,
+        ))
+    } else {
+        serde_json::to_value(DeclineChatJoinRequest::new(
+            request.chat.id,
+            request.from.id,
+        ))
+    };
+
+    assert_eq!(
+        serde_json::to_value(&get_updates).unwrap(),
+        json!({"allowed_updates": ["chat_join_request"]})
+    );
+    assert_eq!(
+        request.invite_link.unwrap().pending_join_request_count,
+        Some(3)
+    );
+    assert_eq!(answer.unwrap(), json!({"chat_id": -100, "user_id": 7}));
+}
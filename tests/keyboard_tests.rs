@@ -0,0 +1,310 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{
+    InlineKeyboard, InlineKeyboardButton, KeyboardButton, KeyboardButtonPollType,
+    KeyboardButtonRequestChat, KeyboardButtonRequestUsers, ReplyKeyboardMarkup, ReplyMarkup,
+};
+use rutebot::responses::{
+    CallbackGame, ChatAdministratorRights, CopyTextButton, LoginUrl, Message,
+    SwitchInlineQueryChosenChat, WebAppInfo,
+};
+
+fn callback(text: &str) -> InlineKeyboardButton<'_> {
+    InlineKeyboardButton::CallbackData {
+        text: text.into(),
+        callback_data: text.into(),
+    }
+}
+
+fn labels(keyboard: &InlineKeyboard) -> Vec<Vec<String>> {
+    let json = serde_json::to_value(keyboard).unwrap();
+    json["inline_keyboard"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| {
+            row.as_array()
+                .unwrap()
+                .iter()
+                .map(|x| x["text"].as_str().unwrap().to_string())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn keyboard_is_built_with_rows_and_buttons() {
+    let keyboard = InlineKeyboard::new()
+        .button(callback("a"))
+        .button(callback("b"))
+        .row()
+        .button(callback("c"));
+
+    assert_eq!(labels(&keyboard), [vec!["a", "b"], vec!["c"]]);
+}
+
+#[test]
+fn grid_fills_rows_of_given_width() {
+    let keyboard = InlineKeyboard::grid(["1", "2", "3", "4", "5"].map(callback), 2);
+
+    assert_eq!(
+        labels(&keyboard),
+        [vec!["1", "2"], vec!["3", "4"], vec!["5"]]
+    );
+}
+
+#[test]
+fn rows_and_buttons_are_appended_and_removed() {
+    let rows = [vec![callback("a")], vec![callback("b"), callback("c")]];
+    let mut keyboard =
+        InlineKeyboard::from(&rows[..]).append(InlineKeyboard::grid([callback("d")], 1));
+
+    let removed_button = keyboard.remove_button(0, 0);
+    let removed_row = keyboard.remove_row(1);
+
+    assert!(matches!(
+        removed_button,
+        Some(InlineKeyboardButton::CallbackData { text, .. }) if text == "a"
+    ));
+    assert_eq!(removed_row.map(|x| x.len()), Some(1));
+    assert!(keyboard.remove_button(5, 0).is_none());
+    assert_eq!(labels(&keyboard), [vec!["b", "c"]]);
+    assert_eq!(rows.len(), 2);
+}
+
+fn build_menu(items: &[&str]) -> ReplyKeyboardMarkup<'static> {
+    ReplyKeyboardMarkup {
+        resize_keyboard: true,
+        ..ReplyKeyboardMarkup::grid(
+            items
+                .iter()
+                .map(|x| KeyboardButton::Text(x.to_string().into())),
+            3,
+        )
+    }
+}
+
+#[test]
+fn reply_keyboard_is_returned_from_function_and_converted_into_reply_markup() {
+    let reply_markup: ReplyMarkup = build_menu(&["a", "b", "c", "d"]).into();
+
+    assert_eq!(
+        serde_json::to_value(&reply_markup).unwrap(),
+        json!({"keyboard": [["a", "b", "c"], ["d"]], "resize_keyboard": true})
+    );
+}
+
+#[test]
+fn all_inline_button_kinds_are_serialized() {
+    let keyboard = InlineKeyboard::grid(
+        [
+            InlineKeyboardButton::CallbackGame {
+                text: "play".into(),
+                callback_game: CallbackGame {},
+            },
+            InlineKeyboardButton::WebApp {
+                text: "app".into(),
+                web_app: WebAppInfo {
+                    url: "https://example.com/app".to_string(),
+                },
+            },
+            InlineKeyboardButton::LoginUrl {
+                text: "login".into(),
+                login_url: LoginUrl {
+                    url: "https://example.com/login".to_string(),
+                    forward_text: None,
+                    bot_username: None,
+                    request_write_access: Some(true),
+                },
+            },
+            InlineKeyboardButton::SwitchInlineQuery {
+                text: "share".into(),
+                switch_inline_query: "".into(),
+            },
+            InlineKeyboardButton::SwitchInlineQueryCurrentChat {
+                text: "search".into(),
+                switch_inline_query_current_chat: "cats".into(),
+            },
+            InlineKeyboardButton::SwitchInlineQueryChosenChat {
+                text: "choose".into(),
+                switch_inline_query_chosen_chat: SwitchInlineQueryChosenChat {
+                    query: Some("dogs".to_string()),
+                    allow_group_chats: Some(true),
+                    ..SwitchInlineQueryChosenChat::default()
+                },
+            },
+            InlineKeyboardButton::CopyText {
+                text: "copy".into(),
+                copy_text: CopyTextButton {
+                    text: "promo".to_string(),
+                },
+            },
+            InlineKeyboardButton::Pay {
+                text: "pay".into(),
+                pay: true,
+            },
+        ],
+        1,
+    );
+
+    assert_eq!(
+        serde_json::to_value(&keyboard).unwrap(),
+        json!({"inline_keyboard": [
+            [{"text": "play", "callback_game": {}}],
+            [{"text": "app", "web_app": {"url": "https://example.com/app"}}],
+            [{"text": "login", "login_url": {"url": "https://example.com/login", "request_write_access": true}}],
+            [{"text": "share", "switch_inline_query": ""}],
+            [{"text": "search", "switch_inline_query_current_chat": "cats"}],
+            [{"text": "choose", "switch_inline_query_chosen_chat": {"query": "dogs", "allow_group_chats": true}}],
+            [{"text": "copy", "copy_text": {"text": "promo"}}],
+            [{"text": "pay", "pay": true}]
+        ]})
+    );
+}
+
+#[test]
+fn inline_keyboard_of_received_message_is_deserialized() {
+    let message: Message = serde_json::from_value(json!({
+        "message_id": 1,
+        "date": 0,
+        "chat": { "id": 1, "type": "private" },
+        "text": "menu",
+        "reply_markup": {"inline_keyboard": [[
+            {"text": "a", "callback_data": "x"},
+            {"text": "b", "web_app": {"url": "https://example.com"}},
+            {"text": "c", "copy_text": {"text": "y"}}
+        ]]}
+    }))
+    .unwrap();
+
+    let row = &message.reply_markup.unwrap().inline_keyboard[0];
+    assert_eq!(row[0].callback_data.as_deref(), Some("x"));
+    assert_eq!(
+        row[1].web_app.as_ref().map(|x| x.url.as_str()),
+        Some("https://example.com")
+    );
+    assert_eq!(
+        row[2].copy_text.as_ref().map(|x| x.text.as_str()),
+        Some("y")
+    );
+}
+
+#[test]
+fn request_buttons_are_serialized() {
+    let keyboard = ReplyKeyboardMarkup {
+        is_persistent: true,
+        input_field_placeholder: Some("Choose".into()),
+        ..ReplyKeyboardMarkup::grid(
+            [
+                KeyboardButton::RequestContact {
+                    text: "contact".into(),
+                    request_contact: true,
+                },
+                KeyboardButton::RequestLocation {
+                    text: "location".into(),
+                    request_location: true,
+                },
+                KeyboardButton::RequestPoll {
+                    text: "quiz".into(),
+                    request_poll: KeyboardButtonPollType {
+                        typ: Some("quiz".into()),
+                    },
+                },
+                KeyboardButton::RequestUsers {
+                    text: "users".into(),
+                    request_users: KeyboardButtonRequestUsers {
+                        max_quantity: Some(3),
+                        request_name: true,
+                        ..KeyboardButtonRequestUsers::new(1)
+                    },
+                },
+                KeyboardButton::RequestChat {
+                    text: "chat".into(),
+                    request_chat: KeyboardButtonRequestChat {
+                        bot_administrator_rights: Some(ChatAdministratorRights {
+                            can_invite_users: true,
+                            ..ChatAdministratorRights::default()
+                        }),
+                        ..KeyboardButtonRequestChat::new(2, false)
+                    },
+                },
+                KeyboardButton::WebApp {
+                    text: "app".into(),
+                    web_app: WebAppInfo {
+                        url: "https://example.com".to_string(),
+                    },
+                },
+            ],
+            2,
+        )
+    };
+
+    let json = serde_json::to_value(&keyboard).unwrap();
+
+    assert_eq!(json["is_persistent"], true);
+    assert_eq!(json["input_field_placeholder"], "Choose");
+    assert_eq!(
+        json["keyboard"],
+        json!([
+            [
+                {"text": "contact", "request_contact": true},
+                {"text": "location", "request_location": true}
+            ],
+            [
+                {"text": "quiz", "request_poll": {"type": "quiz"}},
+                {"text": "users", "request_users": {"request_id": 1, "max_quantity": 3, "request_name": true}}
+            ],
+            [
+                {"text": "chat", "request_chat": {
+                    "request_id": 2,
+                    "chat_is_channel": false,
+                    "bot_administrator_rights": {
+                        "is_anonymous": false,
+                        "can_manage_chat": false,
+                        "can_delete_messages": false,
+                        "can_manage_video_chats": false,
+                        "can_restrict_members": false,
+                        "can_promote_members": false,
+                        "can_change_info": false,
+                        "can_invite_users": true,
+                        "can_post_stories": false,
+                        "can_edit_stories": false,
+                        "can_delete_stories": false
+                    }
+                }},
+                {"text": "app", "web_app": {"url": "https://example.com"}}
+            ]
+        ])
+    );
+}
+
+#[test]
+fn shared_users_chat_and_web_app_data_are_deserialized() {
+    let message = |content: serde_json::Value| -> Message {
+        let mut message = json!({
+            "message_id": 1,
+            "date": 0,
+            "chat": { "id": 1, "type": "private" }
+        });
+        message
+            .as_object_mut()
+            .unwrap()
+            .extend(content.as_object().unwrap().clone());
+        serde_json::from_value(message).unwrap()
+    };
+
+    let users = message(
+        json!({"users_shared": {"request_id": 1, "users": [{"user_id": 5, "first_name": "A"}]}}),
+    );
+    let chat =
+        message(json!({"chat_shared": {"request_id": 2, "chat_id": -100, "title": "Group"}}));
+    let web_app = message(json!({"web_app_data": {"data": "{}", "button_text": "app"}}));
+
+    let users = users.users_shared.unwrap();
+    assert_eq!((users.request_id, users.users[0].user_id), (1, 5));
+    assert_eq!(users.users[0].first_name.as_deref(), Some("A"));
+    let chat = chat.chat_shared.unwrap();
+    assert_eq!((chat.chat_id, chat.title.as_deref()), (-100, Some("Group")));
+    assert_eq!(web_app.web_app_data.unwrap().button_text, "app");
+}
@@ -0,0 +1,141 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::responses::{Message, MessageEntity, MessageEntityKind, MessageEntityValue};
+
+#[test]
+fn all_entity_kinds_are_deserialized() {
+    let entities: Vec<MessageEntity> = serde_json::from_value(json!([
+        {"type": "underline", "offset": 0, "length": 1},
+        {"type": "strikethrough", "offset": 0, "length": 1},
+        {"type": "spoiler", "offset": 0, "length": 1},
+        {"type": "blockquote", "offset": 0, "length": 1},
+        {"type": "expandable_blockquote", "offset": 0, "length": 1},
+        {"type": "pre", "offset": 0, "length": 1, "language": "rust"},
+        {"type": "custom_emoji", "offset": 0, "length": 2, "custom_emoji_id": "123"},
+        {"type": "text_mention", "offset": 0, "length": 1, "user": {"id": 1, "is_bot": false, "first_name": "A"}},
+        {"type": "date_time", "offset": 0, "length": 1, "unix_time": 0}
+    ]))
+    .unwrap();
+
+    let kinds: Vec<_> = entities.into_iter().map(|x| x.kind).collect();
+    assert!(matches!(
+        kinds.as_slice(),
+        [
+            MessageEntityKind::Underline,
+            MessageEntityKind::Strikethrough,
+            MessageEntityKind::Spoiler,
+            MessageEntityKind::Blockquote,
+            MessageEntityKind::ExpandableBlockquote,
+            MessageEntityKind::Pre { language: Some(language) },
+            MessageEntityKind::CustomEmoji { custom_emoji_id },
+            MessageEntityKind::TextMention { user },
+            MessageEntityKind::Unknown(unknown),
+        ] if language == "rust" && custom_emoji_id == "123" && user.id == 1 && unknown == "date_time"
+    ));
+}
+
+#[test]
+fn entity_is_serialized_back_with_its_data() {
+    let entity =
+        json!({"type": "text_link", "offset": 2, "length": 3, "url": "https://example.com"});
+
+    let deserialized: MessageEntity = serde_json::from_value(entity.clone()).unwrap();
+
+    assert_eq!(serde_json::to_value(&deserialized).unwrap(), entity);
+}
+
+#[test]
+fn values_of_new_entity_kinds_are_extracted() {
+    let text = "🙂 fn x";
+    let custom_emoji = MessageEntity {
+        kind: MessageEntityKind::CustomEmoji {
+            custom_emoji_id: "42".to_string(),
+        },
+        offset: 0,
+        length: 2,
+    };
+    let pre = MessageEntity {
+        kind: MessageEntityKind::Pre {
+            language: Some("rust".to_string()),
+        },
+        offset: 3,
+        length: 4,
+    };
+    let unknown = MessageEntity {
+        kind: MessageEntityKind::Unknown("date_time".to_string()),
+        offset: 0,
+        length: 1,
+    };
+
+    match custom_emoji.extract_value(text) {
+        Some(MessageEntityValue::CustomEmoji {
+            emoji,
+            custom_emoji_id,
+        }) => {
+            assert_eq!(emoji, "🙂");
+            assert_eq!(custom_emoji_id, "42");
+        }
+        x => panic!("wrong message entity: {:?}", x),
+    }
+    match pre.extract_value(text) {
+        Some(MessageEntityValue::Pre { code, language }) => {
+            assert_eq!(code, "fn x");
+            assert_eq!(language, Some("rust"));
+        }
+        x => panic!("wrong message entity: {:?}", x),
+    }
+    assert!(unknown.extract_value(text).is_none());
+}
+
+fn message_with_caption() -> Message {
+    serde_json::from_value(json!({
+        "message_id": 1,
+        "date": 0,
+        "chat": { "id": 1, "type": "private" },
+        "caption": "😀 /start@bot #rust @alice see https://a.com and docs",
+        "caption_entities": [
+            {"type": "bot_command", "offset": 3, "length": 10},
+            {"type": "hashtag", "offset": 14, "length": 5},
+            {"type": "mention", "offset": 20, "length": 6},
+            {"type": "url", "offset": 31, "length": 13},
+            {"type": "text_link", "offset": 49, "length": 4, "url": "https://docs.rs"},
+            {"type": "date_time", "offset": 0, "length": 2}
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn caption_entities_are_iterated_with_values() {
+    let message = message_with_caption();
+
+    let values: Vec<_> = message
+        .entities_iter()
+        .map(|(entity, value)| (entity.offset, format!("{:?}", value)))
+        .collect();
+
+    assert_eq!(
+        values,
+        [
+            (3, r#"BotCommand("/start@bot")"#.to_string()),
+            (14, r##"Hashtag("#rust")"##.to_string()),
+            (20, r#"Mention("@alice")"#.to_string()),
+            (31, r#"Url("https://a.com")"#.to_string()),
+            (
+                49,
+                r#"TextLink { text: "docs", link: "https://docs.rs" }"#.to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn helpers_collect_values_of_their_kind() {
+    let message = message_with_caption();
+
+    assert_eq!(message.bot_commands(), ["/start@bot"]);
+    assert_eq!(message.hashtags(), ["#rust"]);
+    assert_eq!(message.mentions(), ["@alice"]);
+    assert_eq!(message.urls(), ["https://a.com", "https://docs.rs"]);
+}
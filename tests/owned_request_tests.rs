@@ -0,0 +1,49 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{
+    FileKind, InlineKeyboard, InlineKeyboardButton, ReplyMarkup, SendMessage, SendPhoto,
+};
+
+fn greeting(name: &str) -> SendMessage<'static> {
+    let text = format!("Hello, {}!", name);
+    SendMessage {
+        reply_markup: Some(ReplyMarkup::from(InlineKeyboard::new().button(
+            InlineKeyboardButton::CallbackData {
+                text: format!("Bye, {}", name).into(),
+                callback_data: name.to_string().into(),
+            },
+        ))),
+        ..SendMessage::new(format!("@{}", name), text)
+    }
+}
+
+#[tokio::test]
+async fn owned_request_is_moved_into_task() {
+    let request = greeting("bob");
+
+    let json = tokio::spawn(async move { serde_json::to_value(&request).unwrap() })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        json,
+        json!({
+            "chat_id": "@bob",
+            "text": "Hello, bob!",
+            "reply_markup": {"inline_keyboard": [[{"text": "Bye, bob", "callback_data": "bob"}]]}
+        })
+    );
+}
+
+#[test]
+fn borrowed_and_owned_fields_are_serialized_the_same() {
+    let file_id = String::from("file");
+    let borrowed = SendPhoto::new(1, FileKind::FileId(file_id.as_str().into()));
+    let owned: SendPhoto<'static> = SendPhoto::new(1, FileKind::FileId(file_id.clone().into()));
+
+    assert_eq!(
+        serde_json::to_value(&borrowed).unwrap(),
+        serde_json::to_value(&owned).unwrap()
+    );
+}
@@ -0,0 +1,131 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{PassportElementError, SetPassportDataErrors};
+use rutebot::responses::{EncryptedPassportElementType, PassportData};
+
+fn passport_data() -> PassportData {
+    serde_json::from_str(include_str!("passport_data.json")).unwrap()
+}
+
+#[test]
+fn passport_data_is_parsed() {
+    let data = passport_data();
+
+    assert_eq!(
+        data.data[0].typ,
+        EncryptedPassportElementType::PersonalDetails
+    );
+    assert_eq!(data.data[1].selfie.as_ref().unwrap().file_id, "f1");
+    assert_eq!(data.data[2].email.as_deref(), Some("bob@example.com"));
+}
+
+#[test]
+fn passport_errors_are_serialized_with_source() {
+    let hashes = vec!["a".into(), "b".into()];
+    let errors = [
+        PassportElementError::Data {
+            typ: EncryptedPassportElementType::PersonalDetails,
+            field_name: "first_name".into(),
+            data_hash: "h1".into(),
+            message: "Typo".into(),
+        },
+        PassportElementError::TranslationFiles {
+            typ: EncryptedPassportElementType::Passport,
+            file_hashes: hashes.into(),
+            message: "Unreadable".into(),
+        },
+    ];
+
+    assert_eq!(
+        serde_json::to_value(SetPassportDataErrors::new(7, &errors[..])).unwrap(),
+        json!({
+            "user_id": 7,
+            "errors": [
+                {
+                    "source": "data",
+                    "type": "personal_details",
+                    "field_name": "first_name",
+                    "data_hash": "h1",
+                    "message": "Typo"
+                },
+                {
+                    "source": "translation_files",
+                    "type": "passport",
+                    "file_hashes": ["a", "b"],
+                    "message": "Unreadable"
+                }
+            ]
+        })
+    );
+}
+
+#[cfg(feature = "passport")]
+mod decryption {
+    use pretty_assertions::assert_eq;
+
+    use rutebot::error::Error;
+    use rutebot::passport::{FileCredentials, PassportKey, PersonalDetails};
+    use rutebot::responses::EncryptedPassportElementType;
+
+    use super::passport_data;
+
+    #[test]
+    fn credentials_data_and_files_are_decrypted() {
+        let data = passport_data();
+        let key = PassportKey::from_pem(include_str!("passport_key.pem")).unwrap();
+
+        let credentials = key.decrypt_credentials(&data.credentials).unwrap();
+        let details_credentials = credentials
+            .secure_data
+            .get(EncryptedPassportElementType::PersonalDetails)
+            .and_then(|x| x.data.as_ref())
+            .unwrap();
+        let details: PersonalDetails = details_credentials
+            .decrypt(data.data[0].data.as_ref().unwrap())
+            .unwrap();
+        let selfie = credentials
+            .secure_data
+            .get(EncryptedPassportElementType::Passport)
+            .and_then(|x| x.selfie.as_ref())
+            .unwrap()
+            .decrypt(include_bytes!("passport_selfie.bin"))
+            .unwrap();
+
+        assert_eq!(credentials.nonce, "nonce-1");
+        assert_eq!(details.first_name, "Bob");
+        assert_eq!(details.middle_name, None);
+        assert_eq!(selfie, b"\xff\xd8\xff\xe0 fake jpeg");
+        assert!(credentials
+            .secure_data
+            .get(EncryptedPassportElementType::Email)
+            .is_none());
+    }
+
+    #[test]
+    fn tampered_data_is_rejected() {
+        let mut data = passport_data();
+        let key = PassportKey::from_pem(include_str!("passport_key.pem")).unwrap();
+        data.credentials.hash = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".into();
+
+        assert!(matches!(
+            key.decrypt_credentials(&data.credentials),
+            Err(Error::Passport(_))
+        ));
+        assert!(matches!(
+            PassportKey::from_pem("not a key"),
+            Err(Error::Passport(_))
+        ));
+    }
+
+    #[test]
+    fn empty_data_is_rejected() {
+        let credentials = FileCredentials {
+            // SHA-256 of empty data
+            file_hash: "47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU=".into(),
+            secret: "c2VjcmV0".into(),
+        };
+
+        assert!(matches!(credentials.decrypt(&[]), Err(Error::Passport(_))));
+    }
+}
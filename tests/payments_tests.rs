@@ -0,0 +1,169 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{
+    AnswerPreCheckoutQuery, AnswerShippingQuery, CreateInvoiceLink, InlineKeyboard,
+    InlineKeyboardButton, LabeledPrice, SendInvoice, ShippingOption,
+};
+use rutebot::responses::{Message, Update};
+
+#[test]
+fn invoice_is_serialized_with_prices_and_pay_button() {
+    let prices = [LabeledPrice::new("Cat picture", 50)];
+    let invoice = SendInvoice {
+        need_email: true,
+        reply_markup: Some(InlineKeyboard::new().button(InlineKeyboardButton::Pay {
+            text: "Pay 50 ⭐".into(),
+            pay: true,
+        })),
+        ..SendInvoice::new(1, "Cat", "A cat picture", "cat-1", "XTR", &prices[..])
+    };
+
+    assert_eq!(
+        serde_json::to_value(&invoice).unwrap(),
+        json!({
+            "chat_id": 1,
+            "title": "Cat",
+            "description": "A cat picture",
+            "payload": "cat-1",
+            "currency": "XTR",
+            "prices": [{"label": "Cat picture", "amount": 50}],
+            "need_email": true,
+            "reply_markup": {"inline_keyboard": [[{"text": "Pay 50 ⭐", "pay": true}]]}
+        })
+    );
+}
+
+#[test]
+fn invoice_link_is_serialized_with_provider_token() {
+    let link = CreateInvoiceLink {
+        provider_token: Some("token".into()),
+        is_flexible: true,
+        ..CreateInvoiceLink::new(
+            "Cat",
+            "A cat",
+            "cat-2",
+            "USD",
+            vec![LabeledPrice::new("Cat", 145)],
+        )
+    };
+
+    let json = serde_json::to_value(&link).unwrap();
+
+    assert_eq!(json["provider_token"], "token");
+    assert_eq!(json["is_flexible"], true);
+    assert_eq!(json["prices"][0]["amount"], 145);
+}
+
+#[test]
+fn shipping_and_pre_checkout_queries_are_answered() {
+    let updates: Vec<Update> = serde_json::from_value(json!([
+        {
+            "update_id": 1,
+            "shipping_query": {
+                "id": "s1",
+                "from": {"id": 7, "is_bot": false, "first_name": "Bob"},
+                "invoice_payload": "cat-2",
+                "shipping_address": {
+                    "country_code": "NL", "state": "", "city": "Amsterdam",
+                    "street_line1": "Dam 1", "street_line2": "", "post_code": "1012"
+                }
+            }
+        },
+        {
+            "update_id": 2,
+            "pre_checkout_query": {
+                "id": "p1",
+                "from": {"id": 7, "is_bot": false, "first_name": "Bob"},
+                "currency": "USD",
+                "total_amount": 645,
+                "invoice_payload": "cat-2",
+                "shipping_option_id": "fast",
+                "order_info": {"email": "bob@example.com"}
+            }
+        }
+    ]))
+    .unwrap();
+    let shipping = updates[0].shipping_query.as_ref().unwrap();
+    let pre_checkout = updates[1].pre_checkout_query.as_ref().unwrap();
+    let prices = [LabeledPrice::new("Courier", 500)];
+    let options = [ShippingOption::new("fast", "Courier", &prices[..])];
+
+    let shipping_answer = AnswerShippingQuery::new_ok(shipping.id.as_str(), &options[..]);
+    let pre_checkout_answer =
+        AnswerPreCheckoutQuery::new_error(pre_checkout.id.as_str(), "Out of cats");
+
+    assert_eq!(shipping.shipping_address.city, "Amsterdam");
+    assert_eq!(
+        pre_checkout.order_info.as_ref().unwrap().email.as_deref(),
+        Some("bob@example.com")
+    );
+    assert_eq!(
+        serde_json::to_value(&shipping_answer).unwrap(),
+        json!({
+            "shipping_query_id": "s1",
+            "ok": true,
+            "shipping_options": [
+                {"id": "fast", "title": "Courier", "prices": [{"label": "Courier", "amount": 500}]}
+            ]
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&pre_checkout_answer).unwrap(),
+        json!({"pre_checkout_query_id": "p1", "ok": false, "error_message": "Out of cats"})
+    );
+}
+
+#[test]
+fn payment_service_messages_are_parsed() {
+    let messages: Vec<Message> = serde_json::from_value(json!([
+        {
+            "message_id": 1,
+            "date": 0,
+            "chat": {"id": 7, "type": "private"},
+            "invoice": {
+                "title": "Cat", "description": "A cat", "start_parameter": "",
+                "currency": "XTR", "total_amount": 50
+            }
+        },
+        {
+            "message_id": 2,
+            "date": 0,
+            "chat": {"id": 7, "type": "private"},
+            "successful_payment": {
+                "currency": "XTR",
+                "total_amount": 50,
+                "invoice_payload": "cat-1",
+                "is_recurring": true,
+                "subscription_expiration_date": 1_700_000_000,
+                "telegram_payment_charge_id": "tg",
+                "provider_payment_charge_id": ""
+            }
+        },
+        {
+            "message_id": 3,
+            "date": 0,
+            "chat": {"id": 7, "type": "private"},
+            "refunded_payment": {
+                "currency": "XTR",
+                "total_amount": 50,
+                "invoice_payload": "cat-1",
+                "telegram_payment_charge_id": "tg"
+            }
+        }
+    ]))
+    .unwrap();
+
+    let payment = messages[1].successful_payment.as_ref().unwrap();
+    assert_eq!(messages[0].invoice.as_ref().unwrap().total_amount, 50);
+    assert_eq!(payment.is_recurring, Some(true));
+    assert_eq!(payment.telegram_payment_charge_id, "tg");
+    assert_eq!(
+        messages[2]
+            .refunded_payment
+            .as_ref()
+            .unwrap()
+            .provider_payment_charge_id,
+        None
+    );
+}
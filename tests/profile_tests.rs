@@ -0,0 +1,180 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::profile::{BotProfile, ProfileChanges, ScopedCommands};
+use rutebot::requests::{
+    BotCommand, BotCommandScope, GetMyDescription, MenuButton, SetChatMenuButton,
+    SetMyDefaultAdministratorRights, SetMyName, SetMyShortDescription,
+};
+use rutebot::responses::{BotDescription, ChatAdministratorRights, WebAppInfo};
+
+#[test]
+fn profile_texts_are_serialized_with_language() {
+    let removed = SetMyShortDescription {
+        short_description: Some("".into()),
+        ..SetMyShortDescription::default()
+    };
+    let description: BotDescription =
+        serde_json::from_value(json!({"description": "Helps with cats"})).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(SetMyName::new_localized("Katzenbot", "de")).unwrap(),
+        json!({"name": "Katzenbot", "language_code": "de"})
+    );
+    assert_eq!(
+        serde_json::to_value(removed).unwrap(),
+        json!({"short_description": ""})
+    );
+    assert_eq!(
+        serde_json::to_value(GetMyDescription::new()).unwrap(),
+        json!({})
+    );
+    assert_eq!(description.description, "Helps with cats");
+}
+
+#[test]
+fn menu_button_is_set_and_read() {
+    let web_app = MenuButton::WebApp {
+        text: "Shop".into(),
+        web_app: WebAppInfo {
+            url: "https://example.com".into(),
+        },
+    };
+    let button: MenuButton = serde_json::from_value(json!({"type": "commands"})).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(SetChatMenuButton::new_chat(7, web_app)).unwrap(),
+        json!({
+            "chat_id": 7,
+            "menu_button": {"type": "web_app", "text": "Shop", "web_app": {"url": "https://example.com"}}
+        })
+    );
+    assert_eq!(button, MenuButton::Commands);
+}
+
+#[test]
+fn default_administrator_rights_are_serialized() {
+    let rights = ChatAdministratorRights {
+        can_delete_messages: true,
+        can_restrict_members: true,
+        ..ChatAdministratorRights::default()
+    };
+
+    let json = serde_json::to_value(SetMyDefaultAdministratorRights::new(rights, true)).unwrap();
+
+    assert_eq!(json["for_channels"], true);
+    assert_eq!(json["rights"]["can_delete_messages"], true);
+    assert_eq!(json["rights"]["can_promote_members"], false);
+}
+
+#[test]
+fn profile_is_loaded_from_config() {
+    let profile: BotProfile = serde_json::from_value(json!({
+        "language_code": "de",
+        "description": "Ich verwalte deine Gruppe",
+        "commands": [
+            {"scope": {"type": "all_private_chats"}, "commands": [{"command": "start", "description": "Starten"}]},
+            {"scope": {"type": "chat_member", "chat_id": 1, "user_id": 7}, "commands": []}
+        ],
+        "menu_button": {"type": "default"}
+    }))
+    .unwrap();
+
+    assert_eq!(profile.name, None);
+    assert_eq!(
+        profile.description.as_deref(),
+        Some("Ich verwalte deine Gruppe")
+    );
+    assert!(matches!(
+        profile.commands[1].scope,
+        BotCommandScope::ChatMember { user_id: 7, .. }
+    ));
+    assert!(profile.commands[1].commands.is_empty());
+    assert_eq!(profile.menu_button, Some(MenuButton::Default));
+    assert!(profile.group_administrator_rights.is_none());
+}
+
+#[test]
+fn only_set_fields_which_differ_are_changed() {
+    let desired = BotProfile {
+        name: Some("Katzenbot".into()),
+        description: Some("Helps with cats".into()),
+        short_description: Some("".into()),
+        menu_button: Some(MenuButton::Commands),
+        ..BotProfile::default()
+    };
+    let current = BotProfile {
+        name: Some("Catbot".into()),
+        description: Some("Helps with cats".into()),
+        short_description: Some("".into()),
+        menu_button: Some(MenuButton::Default),
+        ..BotProfile::default()
+    };
+
+    assert_eq!(
+        desired.changes(&current),
+        ProfileChanges {
+            name: true,
+            menu_button: true,
+            ..ProfileChanges::default()
+        }
+    );
+    assert_eq!(
+        BotProfile::default().changes(&current),
+        ProfileChanges::default()
+    );
+}
+
+#[test]
+fn commands_are_compared_per_scope() {
+    let start = || vec![BotCommand::new("start", "Start")];
+    let desired = BotProfile {
+        commands: vec![
+            ScopedCommands::new(BotCommandScope::AllPrivateChats, start()),
+            ScopedCommands::new(BotCommandScope::AllGroupChats, start()),
+            ScopedCommands::new(BotCommandScope::Chat { chat_id: 1.into() }, start()),
+            ScopedCommands::new(BotCommandScope::Default, Vec::new()),
+        ],
+        ..BotProfile::default()
+    };
+    let current = BotProfile {
+        commands: vec![
+            ScopedCommands::new(BotCommandScope::AllGroupChats, Vec::new()),
+            ScopedCommands::new(BotCommandScope::Chat { chat_id: 1.into() }, start()),
+            ScopedCommands::new(BotCommandScope::AllPrivateChats, start()),
+        ],
+        ..BotProfile::default()
+    };
+
+    assert_eq!(desired.changes(&current).commands, 1);
+}
+
+#[test]
+fn absent_administrator_rights_are_not_granted() {
+    let desired = BotProfile {
+        group_administrator_rights: Some(ChatAdministratorRights {
+            can_delete_messages: true,
+            can_pin_messages: Some(false),
+            can_manage_topics: Some(false),
+            ..ChatAdministratorRights::default()
+        }),
+        channel_administrator_rights: Some(ChatAdministratorRights {
+            can_post_messages: Some(true),
+            ..ChatAdministratorRights::default()
+        }),
+        ..BotProfile::default()
+    };
+    let current = BotProfile {
+        group_administrator_rights: Some(ChatAdministratorRights {
+            can_delete_messages: true,
+            ..ChatAdministratorRights::default()
+        }),
+        channel_administrator_rights: None,
+        ..BotProfile::default()
+    };
+
+    let changes = desired.changes(&current);
+
+    assert!(!changes.group_administrator_rights);
+    assert!(changes.channel_administrator_rights);
+}
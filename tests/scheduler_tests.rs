@@ -0,0 +1,193 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::future::{BoxFuture, FutureExt};
+use pretty_assertions::assert_eq;
+
+use rutebot::{
+    client::Rutebot,
+    error::Error,
+    requests::{FileKind, SendMessage, SendPhoto},
+    scheduler::{InMemoryJobStorage, Job, JobStorage, JsonFileJobStorage, Scheduler, When},
+};
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+/// Storage in which every job is cancelled right after it is loaded
+struct CancellingStorage(InMemoryJobStorage);
+
+impl JobStorage for CancellingStorage {
+    fn save(&self, job: Job) -> BoxFuture<'_, Result<(), Error>> {
+        self.0.save(job)
+    }
+
+    fn replace(&self, job: Job) -> BoxFuture<'_, Result<bool, Error>> {
+        self.0.replace(job)
+    }
+
+    fn remove<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, Error>> {
+        self.0.remove(id)
+    }
+
+    fn load_all(&self) -> BoxFuture<'_, Result<Vec<Job>, Error>> {
+        async move {
+            let jobs = self.0.load_all().await?;
+            for job in &jobs {
+                self.0.remove(&job.id).await?;
+            }
+            Ok(jobs)
+        }
+        .boxed()
+    }
+}
+
+#[tokio::test]
+async fn delayed_job_is_stored() {
+    let scheduler = Scheduler::new(Rutebot::new("token"), InMemoryJobStorage::new());
+    let before = now_millis();
+
+    let id = scheduler
+        .schedule(
+            SendMessage::new(1, "reminder"),
+            When::After(Duration::from_secs(60)),
+        )
+        .await
+        .unwrap();
+
+    let jobs = scheduler.jobs().await.unwrap();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].id, id);
+    assert_eq!(jobs[0].method, "sendMessage");
+    assert_eq!(jobs[0].payload["text"], "reminder");
+    assert!(jobs[0].next_run >= before + 60_000);
+}
+
+#[tokio::test]
+async fn cancelled_job_is_removed() {
+    let scheduler = Scheduler::new(Rutebot::new("token"), InMemoryJobStorage::new());
+    let id = scheduler
+        .schedule(
+            SendMessage::new(1, "reminder"),
+            When::At(SystemTime::now() + Duration::from_secs(60)),
+        )
+        .await
+        .unwrap();
+
+    assert!(scheduler.cancel(&id).await.unwrap());
+    assert!(!scheduler.cancel(&id).await.unwrap());
+    assert!(scheduler.jobs().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn cron_job_runs_at_next_matching_time() {
+    let scheduler = Scheduler::new(Rutebot::new("token"), InMemoryJobStorage::new());
+
+    scheduler
+        .schedule(
+            SendMessage::new(1, "digest"),
+            When::Cron("0 0 * * * *".to_string()),
+        )
+        .await
+        .unwrap();
+
+    let job = scheduler.jobs().await.unwrap().remove(0);
+    assert_eq!(job.next_run % 3_600_000, 0);
+    assert!(job.next_run > now_millis());
+    assert_eq!(job.cron, Some("0 0 * * * *".to_string()));
+}
+
+#[tokio::test]
+async fn invalid_cron_is_rejected() {
+    let scheduler = Scheduler::new(Rutebot::new("token"), InMemoryJobStorage::new());
+
+    let result = scheduler
+        .schedule(
+            SendMessage::new(1, "digest"),
+            When::Cron("every day".to_string()),
+        )
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn uploads_are_rejected() {
+    let scheduler = Scheduler::new(Rutebot::new("token"), InMemoryJobStorage::new());
+    let photo = FileKind::InputFile {
+        name: "cat.png".into(),
+        content: vec![1, 2, 3],
+        thumb: None,
+    };
+
+    let result = scheduler
+        .schedule(
+            SendPhoto::new(1, photo),
+            When::After(Duration::from_secs(60)),
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::RequestBuilt(_))));
+    assert!(scheduler.jobs().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn jobs_survive_restart_with_json_file_storage() {
+    let path = std::env::temp_dir().join(format!("rutebot_scheduler_{}.json", std::process::id()));
+    let id = {
+        let storage = JsonFileJobStorage::open(&path).await.unwrap();
+        let scheduler = Scheduler::new(Rutebot::new("token"), storage);
+        scheduler
+            .schedule(
+                SendMessage::new(1, "reminder"),
+                When::After(Duration::from_secs(60)),
+            )
+            .await
+            .unwrap()
+    };
+
+    let storage = JsonFileJobStorage::open(&path).await.unwrap();
+    let jobs = Scheduler::new(Rutebot::new("token"), storage)
+        .jobs()
+        .await
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].id, id);
+}
+
+#[tokio::test]
+async fn job_cancelled_after_loading_is_neither_sent_nor_rescheduled() {
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let recorded = sent.clone();
+    let storage = CancellingStorage(InMemoryJobStorage::new());
+    // Sending fails without a valid token, so every send attempt reaches the error handler
+    let scheduler = Scheduler::new(Rutebot::new("token"), storage).error_handler(move |job, _| {
+        recorded.lock().unwrap().push(job.id);
+        async {}
+    });
+    scheduler
+        .schedule(
+            SendMessage::new(1, "digest"),
+            When::Cron("* * * * * *".to_string()),
+        )
+        .await
+        .unwrap();
+    scheduler
+        .schedule(SendMessage::new(1, "reminder"), When::After(Duration::ZERO))
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+
+    let _ = tokio::time::timeout(Duration::from_millis(300), scheduler.run()).await;
+
+    assert!(scheduler.jobs().await.unwrap().is_empty());
+    assert!(sent.lock().unwrap().is_empty());
+}
@@ -0,0 +1,169 @@
+use pretty_assertions::assert_eq;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+use rutebot::requests::{
+    EditMessageMedia, FileKind, InputMedia, ReplyMarkup, SendMessage, SendPhoto,
+};
+use rutebot::responses::{Message, MessageEntityKind, Update};
+
+fn round_trip<T: Serialize + DeserializeOwned>(json: Value) -> (T, Value) {
+    let value: T = serde_json::from_value(json).unwrap();
+    let serialized = serde_json::to_value(&value).unwrap();
+    (value, serialized)
+}
+
+#[test]
+fn update_with_message_is_serialized_back_to_telegram_format() {
+    let json = json!({
+        "update_id": 10,
+        "message": {
+            "message_id": 5,
+            "from": {"id": 1, "is_bot": false, "first_name": "Bob", "language_code": "en"},
+            "date": 1_600_000_000,
+            "chat": {"id": 1, "type": "private", "first_name": "Bob"},
+            "text": "/start now",
+            "entities": [{"type": "bot_command", "offset": 0, "length": 6}],
+            "reply_markup": {"inline_keyboard": [[
+                {"text": "site", "url": "https://example.com"},
+                {"text": "next", "callback_data": "page:2"}
+            ]]}
+        }
+    });
+
+    let (update, serialized) = round_trip::<Update>(json.clone());
+
+    assert_eq!(serialized, json);
+    assert!(matches!(
+        update.message.unwrap().entities.unwrap()[0].kind,
+        MessageEntityKind::BotCommand
+    ));
+}
+
+#[test]
+fn update_with_callback_query_is_serialized_back_to_telegram_format() {
+    let json = json!({
+        "update_id": 11,
+        "callback_query": {
+            "id": "42",
+            "from": {"id": 1, "is_bot": false, "first_name": "Bob"},
+            "message": {
+                "message_id": 6,
+                "date": 1_600_000_000,
+                "chat": {"id": -100, "type": "supergroup", "title": "Group"},
+                "photo": [{"file_id": "p", "width": 90, "height": 90}],
+                "caption": "cat"
+            },
+            "chat_instance": "instance",
+            "data": "like"
+        }
+    });
+
+    let (_, serialized) = round_trip::<Update>(json.clone());
+
+    assert_eq!(serialized, json);
+}
+
+#[test]
+fn message_is_restored_from_serialized_copy() {
+    let json = json!({
+        "message_id": 7,
+        "date": 1_600_000_000,
+        "chat": {"id": 1, "type": "private"},
+        "text": "hi #rust",
+        "entities": [{"type": "hashtag", "offset": 3, "length": 5}]
+    });
+    let message: Message = serde_json::from_value(json).unwrap();
+
+    let restored: Message =
+        serde_json::from_str(&serde_json::to_string(&message).unwrap()).unwrap();
+
+    assert_eq!(restored.hashtags(), ["#rust"]);
+}
+
+#[test]
+fn send_message_is_deserialized_from_its_json() {
+    let json = json!({
+        "chat_id": "@channel",
+        "text": "<b>hi</b>",
+        "parse_mode": "Html",
+        "disable_notification": true,
+        "reply_to_message_id": 3,
+        "reply_markup": {"keyboard": [["a", {"text": "b", "request_contact": true}]], "resize_keyboard": true}
+    });
+
+    let (request, serialized) = round_trip::<SendMessage>(json.clone());
+
+    assert_eq!(serialized, json);
+    assert!(request.disable_notification);
+    assert!(!request.disable_web_page_preview);
+    assert!(matches!(
+        request.reply_markup,
+        Some(ReplyMarkup::ReplyKeyboardMarkup(_))
+    ));
+}
+
+#[test]
+fn reply_markup_variants_are_recognized() {
+    let markups = [
+        json!({"inline_keyboard": [[{"text": "pay", "pay": true}]]}),
+        json!({"remove_keyboard": true, "selective": true}),
+        json!({"force_reply": true}),
+    ];
+
+    let parsed: Vec<ReplyMarkup> = markups
+        .iter()
+        .map(|x| serde_json::from_value(x.clone()).unwrap())
+        .collect();
+
+    assert!(matches!(parsed[0], ReplyMarkup::InlineKeyboard(_)));
+    assert!(matches!(parsed[1], ReplyMarkup::ReplyKeyboardRemove(_)));
+    assert!(matches!(parsed[2], ReplyMarkup::ForceReply(_)));
+    for (markup, json) in parsed.iter().zip(markups) {
+        assert_eq!(serde_json::to_value(markup).unwrap(), json);
+    }
+}
+
+#[test]
+fn file_kinds_are_told_apart() {
+    let (by_id, _) = round_trip::<SendPhoto>(json!({"chat_id": 1, "photo": "AgADBAAD"}));
+    let (by_url, _) =
+        round_trip::<SendPhoto>(json!({"chat_id": 1, "photo": "https://example.com/a.png"}));
+    let json = json!({
+        "chat_id": 1,
+        "message_id": 2,
+        "media": {"type": "photo", "media": "attach://cat.png", "caption": "cat"}
+    });
+    let (edit, serialized) = round_trip::<EditMessageMedia>(json.clone());
+
+    assert!(matches!(by_id.photo, FileKind::FileId(id) if id == "AgADBAAD"));
+    assert!(matches!(by_url.photo, FileKind::Url(url) if url == "https://example.com/a.png"));
+    assert!(matches!(
+        edit.media,
+        InputMedia::Photo(photo)
+            if matches!(&photo.media, FileKind::InputFile { name, content, .. } if name == "cat.png" && content.is_empty())
+    ));
+    assert_eq!(serialized, json);
+}
+
+#[test]
+fn upload_request_keeps_file_name() {
+    let request = SendPhoto::new(
+        1,
+        FileKind::InputFile {
+            name: "cat.png".into(),
+            content: vec![1, 2, 3],
+            thumb: None,
+        },
+    );
+
+    let json = serde_json::to_value(&request).unwrap();
+    let (restored, serialized) = round_trip::<SendPhoto>(json.clone());
+
+    assert_eq!(json, json!({"chat_id": 1, "photo": "attach://cat.png"}));
+    assert_eq!(serialized, json);
+    assert!(matches!(
+        restored.photo,
+        FileKind::InputFile { name, content, .. } if name == "cat.png" && content.is_empty()
+    ));
+}
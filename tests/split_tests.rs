@@ -0,0 +1,139 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::formatting::{split_html, FormattedText, MAX_CAPTION_LEN, MAX_MESSAGE_LEN};
+
+fn texts(parts: &[FormattedText]) -> Vec<&str> {
+    parts.iter().map(|x| x.text.as_str()).collect()
+}
+
+#[test]
+fn text_is_split_at_paragraph_before_line_and_word() {
+    let formatted = FormattedText::new().text("aaa bb\nccc\n\nddd eee");
+
+    let parts = formatted.split(14);
+
+    assert_eq!(texts(&parts), ["aaa bb\nccc\n\n", "ddd eee"]);
+}
+
+#[test]
+fn entities_are_not_cut_when_there_is_other_boundary() {
+    let formatted = FormattedText::new()
+        .text("one ")
+        .bold("two three")
+        .text(" four");
+
+    let parts = formatted.split(10);
+
+    assert_eq!(texts(&parts), ["one ", "two three ", "four"]);
+    assert_eq!(
+        serde_json::to_value(&parts[1].entities).unwrap(),
+        json!([{"type": "bold", "offset": 0, "length": 9}])
+    );
+}
+
+#[test]
+fn long_entity_is_continued_in_next_part() {
+    let formatted = FormattedText::new().italic("abcdef ghijkl");
+
+    let parts = formatted.split(8);
+
+    assert_eq!(texts(&parts), ["abcdef ", "ghijkl"]);
+    assert_eq!(
+        serde_json::to_value(&parts[1].entities).unwrap(),
+        json!([{"type": "italic", "offset": 0, "length": 6}])
+    );
+}
+
+#[test]
+fn surrogate_pair_is_not_cut() {
+    let formatted = FormattedText::new().text("a😀😀");
+
+    let parts = formatted.split(2);
+
+    assert_eq!(texts(&parts), ["a", "😀", "😀"]);
+}
+
+#[test]
+fn zero_max_len_is_treated_as_one() {
+    let formatted = FormattedText::new().text("abc");
+
+    let parts = formatted.split(0);
+
+    assert_eq!(texts(&parts), ["a", "b", "c"]);
+}
+
+#[test]
+fn surrogate_pair_is_kept_whole_when_max_len_is_one() {
+    let formatted = FormattedText::new().text("a😀b😀");
+
+    let parts = formatted.split(1);
+
+    assert_eq!(texts(&parts), ["a", "😀", "b", "😀"]);
+    assert_eq!(
+        parts.iter().map(|x| x.utf16_len()).collect::<Vec<_>>(),
+        [1, 2, 1, 2]
+    );
+}
+
+#[test]
+fn html_is_parsed_into_entities() {
+    let formatted = FormattedText::from_html(concat!(
+        "<b>1 &lt; 2 <i>&amp;&#33;</i></b> ",
+        "<a href=\"tg://user?id=7\">Bob</a> ",
+        "<pre><code class=\"language-rust\">x</code></pre>",
+        "<span class='tg-spoiler'>s</span>"
+    ))
+    .unwrap();
+
+    assert_eq!(formatted.text, "1 < 2 &! Bob xs");
+    assert_eq!(
+        serde_json::to_value(&formatted.entities).unwrap(),
+        json!([
+            {"type": "bold", "offset": 0, "length": 8},
+            {"type": "italic", "offset": 6, "length": 2},
+            {"type": "text_link", "offset": 9, "length": 3, "url": "tg://user?id=7"},
+            {"type": "pre", "offset": 13, "length": 1, "language": "rust"},
+            {"type": "spoiler", "offset": 14, "length": 1}
+        ])
+    );
+}
+
+#[test]
+fn invalid_html_is_rejected() {
+    assert!(FormattedText::from_html("<b>bold</i>").is_err());
+    assert!(FormattedText::from_html("<b>bold").is_err());
+    assert!(FormattedText::from_html("<script>x</script>").is_err());
+}
+
+#[test]
+fn html_formatting_is_reopened_in_next_part() {
+    let parts = split_html("<b>bold <i>and italic</i></b> tail", 12).unwrap();
+
+    let parts: Vec<_> = parts.iter().map(|x| x.text.as_str()).collect();
+    assert_eq!(
+        parts,
+        ["<b>bold <i>and </i></b>", "<b><i>italic</i></b> tail"]
+    );
+}
+
+#[test]
+fn caption_overflow_is_moved_to_messages() {
+    let long = "word ".repeat(1000);
+    let formatted = FormattedText::new().text(&long);
+
+    let (caption, rest) = formatted.split_caption();
+
+    assert!(caption.utf16_len() as usize <= MAX_CAPTION_LEN);
+    assert_eq!(rest.len(), 1);
+    assert!(rest[0].utf16_len() as usize <= MAX_MESSAGE_LEN);
+    assert_eq!(format!("{}{}", caption.text, rest[0].text), long);
+}
+
+#[test]
+fn short_caption_has_no_overflow() {
+    let (caption, rest) = FormattedText::new().bold("short").split_caption();
+
+    assert_eq!(caption.text, "short");
+    assert!(rest.is_empty());
+}
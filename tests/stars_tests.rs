@@ -0,0 +1,118 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{
+    CreateInvoiceLink, EditUserStarSubscription, GetStarTransactions, RefundStarPayment,
+    SendInvoice,
+};
+use rutebot::responses::{
+    RevenueWithdrawalState, StarAmount, StarTransactions, TransactionPartner,
+    TransactionPartnerFragment,
+};
+
+#[test]
+fn star_invoices_have_single_xtr_price() {
+    let invoice = SendInvoice::new_stars(1, "Cat", "A cat", "cat-1", 50);
+    let link = CreateInvoiceLink::new_stars_subscription("Club", "Cat club", "club", 100);
+
+    assert_eq!(
+        serde_json::to_value(&invoice).unwrap(),
+        json!({
+            "chat_id": 1,
+            "title": "Cat",
+            "description": "A cat",
+            "payload": "cat-1",
+            "currency": "XTR",
+            "prices": [{"label": "Cat", "amount": 50}]
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&link).unwrap(),
+        json!({
+            "title": "Club",
+            "description": "Cat club",
+            "payload": "club",
+            "currency": "XTR",
+            "prices": [{"label": "Club", "amount": 100}],
+            "subscription_period": 2_592_000
+        })
+    );
+}
+
+#[test]
+fn transactions_are_parsed_by_partner_type() {
+    let json = json!({
+        "transactions": [
+            {
+                "id": "charge-1",
+                "amount": 100,
+                "date": 1_700_000_000,
+                "source": {
+                    "type": "user",
+                    "transaction_type": "invoice_payment",
+                    "user": {"id": 7, "is_bot": false, "first_name": "Bob"},
+                    "invoice_payload": "club",
+                    "subscription_period": 2_592_000
+                }
+            },
+            {
+                "id": "w-1",
+                "amount": 1000,
+                "date": 1_700_000_100,
+                "receiver": {
+                    "type": "fragment",
+                    "withdrawal_state": {"type": "succeeded", "date": 1_700_000_200, "url": "https://fragment.com/tx"}
+                }
+            },
+            {"id": "ads-1", "amount": 5, "date": 1_700_000_300, "receiver": {"type": "telegram_ads"}},
+            {"id": "x-1", "amount": 1, "date": 1_700_000_400, "source": {"type": "something_new"}}
+        ]
+    });
+
+    let transactions: StarTransactions = serde_json::from_value(json).unwrap();
+    let partners: Vec<_> = transactions
+        .transactions
+        .iter()
+        .map(|x| x.source.as_ref().or(x.receiver.as_ref()).unwrap())
+        .collect();
+
+    assert!(matches!(
+        partners[0],
+        TransactionPartner::User(user)
+            if user.invoice_payload.as_deref() == Some("club") && user.user.id == 7
+    ));
+    assert!(matches!(
+        partners[1],
+        TransactionPartner::Fragment(TransactionPartnerFragment {
+            withdrawal_state: Some(RevenueWithdrawalState::Succeeded {
+                date: 1_700_000_200,
+                ..
+            })
+        })
+    ));
+    assert!(matches!(partners[2], TransactionPartner::TelegramAds));
+    assert!(matches!(partners[3], TransactionPartner::Other));
+    assert_eq!(
+        serde_json::to_value(partners[2]).unwrap(),
+        json!({"type": "telegram_ads"})
+    );
+}
+
+#[test]
+fn star_requests_are_serialized() {
+    let balance: StarAmount = serde_json::from_value(json!({"amount": 42})).unwrap();
+
+    assert_eq!(balance.amount, 42);
+    assert_eq!(
+        serde_json::to_value(GetStarTransactions::new_page(100, 50)).unwrap(),
+        json!({"offset": 100, "limit": 50})
+    );
+    assert_eq!(
+        serde_json::to_value(RefundStarPayment::new(7, "charge-1")).unwrap(),
+        json!({"user_id": 7, "telegram_payment_charge_id": "charge-1"})
+    );
+    assert_eq!(
+        serde_json::to_value(EditUserStarSubscription::new(7, "charge-1", false)).unwrap(),
+        json!({"user_id": 7, "telegram_payment_charge_id": "charge-1", "is_canceled": false})
+    );
+}
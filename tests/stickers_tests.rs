@@ -0,0 +1,116 @@
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use rutebot::requests::{
+    CreateNewStickerSet, FileKind, GetCustomEmojiStickers, InputSticker, SendSticker,
+    SetStickerKeywords, SetStickerSetThumbnail, StickerFormat,
+};
+use rutebot::responses::{StickerSet, StickerType};
+
+#[test]
+fn sticker_set_is_parsed() {
+    let set: StickerSet = serde_json::from_value(json!({
+        "name": "cats_by_bot",
+        "title": "Cats",
+        "sticker_type": "custom_emoji",
+        "stickers": [{
+            "file_id": "s1",
+            "type": "custom_emoji",
+            "width": 100,
+            "height": 100,
+            "is_animated": false,
+            "is_video": true,
+            "thumbnail": {"file_id": "t1", "width": 32, "height": 32},
+            "emoji": "🐱",
+            "custom_emoji_id": "42",
+            "needs_repainting": true
+        }]
+    }))
+    .unwrap();
+
+    let sticker = &set.stickers[0];
+    assert_eq!(set.sticker_type, StickerType::CustomEmoji);
+    assert!(sticker.is_video);
+    assert_eq!(sticker.thumb.as_ref().unwrap().file_id, "t1");
+    assert_eq!(sticker.custom_emoji_id.as_deref(), Some("42"));
+}
+
+#[test]
+fn new_sticker_set_attaches_uploaded_files() {
+    let stickers = vec![
+        InputSticker::new(
+            FileKind::InputFile {
+                name: "cat.webm".into(),
+                content: vec![1, 2, 3],
+                thumb: None,
+            },
+            StickerFormat::Video,
+            ["🐱"],
+        ),
+        InputSticker {
+            keywords: Some(vec!["dog".into()].into()),
+            ..InputSticker::new(FileKind::FileId("d1".into()), StickerFormat::Static, ["🐶"])
+        },
+    ];
+    let set = CreateNewStickerSet {
+        sticker_type: Some(StickerType::Regular),
+        ..CreateNewStickerSet::new(7, "pets_by_bot", "Pets", stickers)
+    };
+
+    assert_eq!(
+        serde_json::to_value(&set).unwrap(),
+        json!({
+            "user_id": 7,
+            "name": "pets_by_bot",
+            "title": "Pets",
+            "stickers": [
+                {"sticker": "attach://cat.webm", "format": "video", "emoji_list": ["🐱"]},
+                {"sticker": "d1", "format": "static", "emoji_list": ["🐶"], "keywords": ["dog"]}
+            ],
+            "sticker_type": "regular"
+        })
+    );
+}
+
+#[test]
+fn sticker_requests_are_serialized() {
+    let send = SendSticker {
+        emoji: Some("🐱".into()),
+        ..SendSticker::new(1, FileKind::FileId("s1".into()))
+    };
+    let thumbnail = SetStickerSetThumbnail::new(
+        "cats_by_bot",
+        7,
+        FileKind::Url("https://example.com/t.webp".into()),
+        StickerFormat::Static,
+    );
+    let removed_thumbnail = SetStickerSetThumbnail {
+        thumbnail: None,
+        ..thumbnail.clone()
+    };
+
+    assert_eq!(
+        serde_json::to_value(&send).unwrap(),
+        json!({"chat_id": 1, "sticker": "s1", "emoji": "🐱"})
+    );
+    assert_eq!(
+        serde_json::to_value(GetCustomEmojiStickers::new(vec![
+            "1".to_string(),
+            "2".to_string()
+        ]))
+        .unwrap(),
+        json!({"custom_emoji_ids": ["1", "2"]})
+    );
+    assert_eq!(
+        serde_json::to_value(SetStickerKeywords::new("s1", ["cat", "cute"])).unwrap(),
+        json!({"sticker": "s1", "keywords": ["cat", "cute"]})
+    );
+    assert_eq!(
+        serde_json::to_value(&thumbnail).unwrap(),
+        json!({"name": "cats_by_bot", "user_id": 7, "thumbnail": "https://example.com/t.webp", "format": "static"})
+    );
+    assert_eq!(
+        serde_json::to_value(&removed_thumbnail).unwrap(),
+        json!({"name": "cats_by_bot", "user_id": 7, "format": "static"})
+    );
+}